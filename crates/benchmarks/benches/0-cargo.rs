@@ -81,7 +81,7 @@ mod toml_parser {
 }
 
 mod toml_edit {
-    use toml_benchmarks::{Data, MANIFESTS, manifest};
+    use toml_benchmarks::{Data, MANIFESTS, borrowed_manifest, manifest};
 
     #[divan::bench(args=MANIFESTS)]
     fn document(sample: &Data<'static>) -> ::toml_edit::DocumentMut {
@@ -92,10 +92,18 @@ mod toml_edit {
     fn manifest(sample: &Data<'static>) -> manifest::Manifest {
         ::toml_edit::de::from_str(sample.content()).unwrap()
     }
+
+    #[divan::bench(args=MANIFESTS)]
+    fn manifest_borrowed(sample: &Data<'static>) {
+        let document = ::toml_edit::Document::parse(sample.content()).unwrap();
+        let manifest: borrowed_manifest::Manifest<'_> =
+            ::toml_edit::de::from_document_ref(&document).unwrap();
+        std::hint::black_box(manifest);
+    }
 }
 
 mod toml {
-    use toml_benchmarks::{Data, MANIFESTS, manifest};
+    use toml_benchmarks::{Data, MANIFESTS, borrowed_manifest, manifest};
 
     #[divan::bench(args=MANIFESTS)]
     fn detable(sample: &Data<'static>) -> serde_spanned::Spanned<::toml::de::DeTable<'static>> {
@@ -121,6 +129,11 @@ mod toml {
     fn manifest(sample: &Data<'static>) -> manifest::Manifest {
         ::toml::de::from_str(sample.content()).unwrap()
     }
+
+    #[divan::bench(args=MANIFESTS)]
+    fn manifest_borrowed(sample: &Data<'static>) -> borrowed_manifest::Manifest<'static> {
+        ::toml::de::from_str(sample.content()).unwrap()
+    }
 }
 
 mod toml_v05 {
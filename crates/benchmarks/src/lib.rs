@@ -133,3 +133,109 @@ pub mod manifest {
         dev_dependencies: HashMap<String, Dependency>,
     }
 }
+
+/// [`manifest`] with borrowed strings
+pub mod borrowed_manifest {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub struct Manifest<'a> {
+        #[serde(borrow)]
+        package: Package<'a>,
+        #[serde(default, borrow)]
+        lib: Option<Lib<'a>>,
+        #[serde(default, borrow)]
+        bin: Vec<Bin<'a>>,
+        #[serde(default, borrow)]
+        features: HashMap<Cow<'a, str>, Vec<Cow<'a, str>>>,
+        #[serde(default, borrow)]
+        dependencies: HashMap<Cow<'a, str>, Dependency<'a>>,
+        #[serde(default, borrow)]
+        build_dependencies: HashMap<Cow<'a, str>, Dependency<'a>>,
+        #[serde(default, borrow)]
+        dev_dependencies: HashMap<Cow<'a, str>, Dependency<'a>>,
+        #[serde(default, borrow)]
+        target: HashMap<Cow<'a, str>, Target<'a>>,
+    }
+
+    #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub(crate) struct Package<'a> {
+        #[serde(borrow)]
+        name: Cow<'a, str>,
+        #[serde(borrow)]
+        version: Cow<'a, str>,
+        #[serde(default, borrow)]
+        edition: Option<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        authors: Vec<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        license: Option<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        homepage: Option<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        repository: Option<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        documentation: Option<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        readme: Option<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        description: Option<Cow<'a, str>>,
+    }
+
+    #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub(crate) struct Lib<'a> {
+        #[serde(borrow)]
+        name: Option<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        path: Option<Cow<'a, str>>,
+    }
+
+    #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub(crate) struct Bin<'a> {
+        #[serde(borrow)]
+        name: Cow<'a, str>,
+        #[serde(default)]
+        test: bool,
+        #[serde(default)]
+        doc: bool,
+    }
+
+    #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    #[serde(untagged)]
+    pub(crate) enum Dependency<'a> {
+        Version(#[serde(borrow)] Cow<'a, str>),
+        Full(#[serde(borrow)] DependencyFull<'a>),
+    }
+
+    #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub(crate) struct DependencyFull<'a> {
+        #[serde(default, borrow)]
+        version: Option<Cow<'a, str>>,
+        #[serde(default, borrow)]
+        path: Option<Cow<'a, str>>,
+        #[serde(default)]
+        default_features: bool,
+        #[serde(default)]
+        optional: bool,
+        #[serde(default, borrow)]
+        features: Vec<Cow<'a, str>>,
+    }
+
+    #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub(crate) struct Target<'a> {
+        #[serde(default, borrow)]
+        dependencies: HashMap<Cow<'a, str>, Dependency<'a>>,
+        #[serde(default, borrow)]
+        build_dependencies: HashMap<Cow<'a, str>, Dependency<'a>>,
+        #[serde(default, borrow)]
+        dev_dependencies: HashMap<Cow<'a, str>, Dependency<'a>>,
+    }
+}
@@ -0,0 +1,166 @@
+use alloc::string::String;
+
+// Like `Spanned`, `Commented` maps to a special struct in the serde data model that supported
+// serializers catch to write the comments.
+#[cfg(feature = "serde")]
+pub(crate) const NAME: &str = "$__serde_spanned_private_Commented";
+#[cfg(feature = "serde")]
+pub(crate) const COMMENT_FIELD: &str = "$__serde_spanned_private_comment";
+#[cfg(feature = "serde")]
+pub(crate) const TRAILING_COMMENT_FIELD: &str = "$__serde_spanned_private_trailing_comment";
+#[cfg(feature = "serde")]
+pub(crate) const VALUE_FIELD: &str = "$__serde_spanned_private_value";
+#[cfg(feature = "serde")]
+pub(crate) fn is_commented(name: &'static str) -> bool {
+    name == NAME
+}
+
+/// A value with comments, for documenting generated TOML
+///
+/// The comment is written as `#` lines above the key-value pair, table header, or array of tables
+/// header for the value and the trailing comment after it, on the same line.  A table written as
+/// dotted keys gets its comments above its first key.  Comments are dropped where they can't be
+/// written, like within inline tables and arrays.
+///
+/// Like [`Spanned`][crate::Spanned], this requires support from the serializer.  Other serializers
+/// see a struct with the comments and the value.
+///
+/// # Example
+///
+/// ```
+/// use serde_derive::Serialize;
+/// use serde_spanned::Commented;
+///
+/// #[derive(Serialize)]
+/// struct Package {
+///     name: Commented<&'static str>,
+///     version: &'static str,
+/// }
+///
+/// let package = Package {
+///     name: Commented::new("toml")
+///         .with_comment("The name of the package")
+///         .with_trailing_comment("not yet published"),
+///     version: "1.0.0",
+/// };
+/// assert_eq!(
+///     toml::to_string(&package).unwrap(),
+///     "\
+/// ## The name of the package
+/// name = \"toml\" # not yet published
+/// version = \"1.0.0\"
+/// "
+/// );
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Commented<T> {
+    value: T,
+    comment: Option<String>,
+    trailing_comment: Option<String>,
+}
+
+impl<T> Commented<T> {
+    /// Wrap `value`, without any comments
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            comment: None,
+            trailing_comment: None,
+        }
+    }
+
+    /// Set the comment written above the value
+    ///
+    /// Each line is written as a `#` line.
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Set the comment written after the value, on the same line
+    ///
+    /// This may not contain newlines.
+    pub fn with_trailing_comment(mut self, comment: impl Into<String>) -> Self {
+        self.trailing_comment = Some(comment.into());
+        self
+    }
+
+    /// The comment written above the value
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// The comment written after the value, on the same line
+    pub fn trailing_comment(&self) -> Option<&str> {
+        self.trailing_comment.as_deref()
+    }
+
+    /// Access the value
+    pub fn get_ref(&self) -> &T {
+        &self.value
+    }
+
+    /// Mutably access the value
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Consumes the comments, returning the value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde_core::ser::Serialize for Commented<T>
+where
+    T: serde_core::ser::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::ser::Serializer,
+    {
+        serializer.serialize_newtype_struct(NAME, &CommentedFields(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde_core::de::Deserialize<'de> for Commented<T>
+where
+    T: serde_core::de::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde_core::de::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct CommentedFields<'c, T>(&'c Commented<T>);
+
+#[cfg(feature = "serde")]
+impl<T> serde_core::ser::Serialize for CommentedFields<'_, T>
+where
+    T: serde_core::ser::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::ser::Serializer,
+    {
+        use serde_core::ser::SerializeStruct as _;
+
+        let mut s = serializer.serialize_struct(NAME, 3)?;
+        match &self.0.comment {
+            Some(comment) => s.serialize_field(COMMENT_FIELD, comment)?,
+            None => s.skip_field(COMMENT_FIELD)?,
+        }
+        match &self.0.trailing_comment {
+            Some(comment) => s.serialize_field(TRAILING_COMMENT_FIELD, comment)?,
+            None => s.skip_field(TRAILING_COMMENT_FIELD)?,
+        }
+        s.serialize_field(VALUE_FIELD, &self.0.value)?;
+        s.end()
+    }
+}
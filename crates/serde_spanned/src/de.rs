@@ -4,12 +4,18 @@ use serde_core::de::IntoDeserializer as _;
 use serde_core::de::value::BorrowedStrDeserializer;
 
 use crate::Spanned;
+use crate::SpannedEntry;
 
 /// Check if deserializing a [`Spanned`]
 pub fn is_spanned(name: &'static str) -> bool {
     crate::spanned::is_spanned(name)
 }
 
+/// Check if deserializing a [`SpannedEntry`]
+pub fn is_spanned_entry(name: &'static str) -> bool {
+    crate::entry::is_spanned_entry(name)
+}
+
 /// Deserializer / format support for emitting [`Spanned`]
 pub struct SpannedDeserializer<'de, T, E>
 where
@@ -18,6 +24,7 @@ where
 {
     start: Option<usize>,
     end: Option<usize>,
+    source_id: Option<usize>,
     value: Option<T>,
     _lifetime: core::marker::PhantomData<&'de ()>,
     _error: core::marker::PhantomData<E>,
@@ -33,11 +40,18 @@ where
         Self {
             start: Some(span.start),
             end: Some(span.end),
+            source_id: None,
             value: Some(value),
             _lifetime: Default::default(),
             _error: Default::default(),
         }
     }
+
+    /// Identify the source the span is in, see [`Spanned::source_id`]
+    pub fn with_source_id(mut self, source_id: Option<usize>) -> Self {
+        self.source_id = source_id;
+        self
+    }
 }
 
 impl<'de, T, E> serde_core::de::MapAccess<'de> for SpannedDeserializer<'de, T, E>
@@ -56,6 +70,9 @@ where
         } else if self.end.is_some() {
             seed.deserialize(BorrowedStrDeserializer::new(Spanned::<T>::END_FIELD))
                 .map(Some)
+        } else if self.source_id.is_some() {
+            seed.deserialize(BorrowedStrDeserializer::new(Spanned::<T>::SOURCE_ID_FIELD))
+                .map(Some)
         } else if self.value.is_some() {
             seed.deserialize(BorrowedStrDeserializer::new(Spanned::<T>::VALUE_FIELD))
                 .map(Some)
@@ -72,6 +89,80 @@ where
             seed.deserialize(start.into_deserializer())
         } else if let Some(end) = self.end.take() {
             seed.deserialize(end.into_deserializer())
+        } else if let Some(source_id) = self.source_id.take() {
+            seed.deserialize(source_id.into_deserializer())
+        } else if let Some(value) = self.value.take() {
+            seed.deserialize(value.into_deserializer())
+        } else {
+            panic!("next_value_seed called before next_key_seed")
+        }
+    }
+}
+
+/// Deserializer / format support for emitting [`SpannedEntry`]
+///
+/// The key and value deserializers must support [`Spanned`].
+pub struct SpannedEntryDeserializer<'de, K, V, E>
+where
+    K: serde_core::de::IntoDeserializer<'de, E>,
+    V: serde_core::de::IntoDeserializer<'de, E>,
+    E: serde_core::de::Error,
+{
+    key: Option<K>,
+    value: Option<V>,
+    _lifetime: core::marker::PhantomData<&'de ()>,
+    _error: core::marker::PhantomData<E>,
+}
+
+impl<'de, K, V, E> SpannedEntryDeserializer<'de, K, V, E>
+where
+    K: serde_core::de::IntoDeserializer<'de, E>,
+    V: serde_core::de::IntoDeserializer<'de, E>,
+    E: serde_core::de::Error,
+{
+    /// Create a deserializer to emit [`SpannedEntry`]
+    pub fn new(key: K, value: V) -> Self {
+        Self {
+            key: Some(key),
+            value: Some(value),
+            _lifetime: Default::default(),
+            _error: Default::default(),
+        }
+    }
+}
+
+impl<'de, K, V, E> serde_core::de::MapAccess<'de> for SpannedEntryDeserializer<'de, K, V, E>
+where
+    K: serde_core::de::IntoDeserializer<'de, E>,
+    V: serde_core::de::IntoDeserializer<'de, E>,
+    E: serde_core::de::Error,
+{
+    type Error = E;
+    fn next_key_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: serde_core::de::DeserializeSeed<'de>,
+    {
+        if self.key.is_some() {
+            seed.deserialize(BorrowedStrDeserializer::new(
+                SpannedEntry::<K, V>::KEY_FIELD,
+            ))
+            .map(Some)
+        } else if self.value.is_some() {
+            seed.deserialize(BorrowedStrDeserializer::new(
+                SpannedEntry::<K, V>::VALUE_FIELD,
+            ))
+            .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: serde_core::de::DeserializeSeed<'de>,
+    {
+        if let Some(key) = self.key.take() {
+            seed.deserialize(key.into_deserializer())
         } else if let Some(value) = self.value.take() {
             seed.deserialize(value.into_deserializer())
         } else {
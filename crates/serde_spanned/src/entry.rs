@@ -0,0 +1,166 @@
+use crate::Spanned;
+
+// Like `Spanned`, `SpannedEntry` maps to a special struct in the serde data model that supported
+// deserializers catch when deserializing the value of a table entry.
+#[cfg(feature = "serde")]
+pub(crate) const NAME: &str = "$__serde_spanned_private_SpannedEntry";
+#[cfg(feature = "serde")]
+pub(crate) const KEY_FIELD: &str = "$__serde_spanned_private_key";
+#[cfg(feature = "serde")]
+pub(crate) const VALUE_FIELD: &str = "$__serde_spanned_private_value";
+#[cfg(feature = "serde")]
+pub(crate) fn is_spanned_entry(name: &'static str) -> bool {
+    name == NAME
+}
+
+/// A spanned key and value, indicating the ranges at which a table entry is defined in the source.
+///
+/// This is deserialized from the value of a table entry, e.g. a struct field or a map value, and
+/// captures the entry's key along with it.
+///
+/// # Example
+///
+/// ```
+/// use serde_derive::Deserialize;
+/// use toml::SpannedEntry;
+///
+/// #[derive(Deserialize)]
+/// struct Package {
+///     name: SpannedEntry<String, String>,
+/// }
+///
+/// let input = "name = 'toml'\n";
+/// let package: Package = toml::from_str(input).unwrap();
+/// assert_eq!(&input[package.name.key().span()], "name");
+/// assert_eq!(&input[package.name.value().span()], "'toml'");
+/// ```
+///
+/// For the keys of a map, use [`Spanned`] as the key, e.g. `BTreeMap<Spanned<String>, T>`.
+///
+/// Like [`Spanned`], this requires support from the deserializer, so the spans are lost when
+/// serde buffers the value, e.g. for `#[serde(flatten)]` or untagged enums.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SpannedEntry<K, V> {
+    key: Spanned<K>,
+    value: Spanned<V>,
+}
+
+impl<K, V> SpannedEntry<K, V> {
+    /// Create an entry from its spanned key and value
+    pub fn new(key: Spanned<K>, value: Spanned<V>) -> Self {
+        Self { key, value }
+    }
+
+    /// The spanned key of the entry
+    pub fn key(&self) -> &Spanned<K> {
+        &self.key
+    }
+
+    /// The spanned value of the entry
+    pub fn value(&self) -> &Spanned<V> {
+        &self.value
+    }
+
+    /// Returns a reference to the contained value.
+    pub fn get_ref(&self) -> &V {
+        self.value.get_ref()
+    }
+
+    /// Returns a mutable reference to the contained value.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.value.get_mut()
+    }
+
+    /// Consumes the entry and returns the contained value.
+    pub fn into_inner(self) -> V {
+        self.value.into_inner()
+    }
+
+    /// Consumes the entry and returns the spanned key and value.
+    pub fn into_parts(self) -> (Spanned<K>, Spanned<V>) {
+        (self.key, self.value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> SpannedEntry<K, V> {
+    pub(crate) const KEY_FIELD: &str = KEY_FIELD;
+    pub(crate) const VALUE_FIELD: &str = VALUE_FIELD;
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde_core::de::Deserialize<'de> for SpannedEntry<K, V>
+where
+    K: serde_core::de::Deserialize<'de>,
+    V: serde_core::de::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde_core::de::Deserializer<'de>,
+    {
+        struct SpannedEntryVisitor<K, V>(::core::marker::PhantomData<(K, V)>);
+
+        impl<'de, K, V> serde_core::de::Visitor<'de> for SpannedEntryVisitor<K, V>
+        where
+            K: serde_core::de::Deserialize<'de>,
+            V: serde_core::de::Deserialize<'de>,
+        {
+            type Value = SpannedEntry<K, V>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("a spanned table entry")
+            }
+
+            fn visit_map<A>(self, mut visitor: A) -> Result<SpannedEntry<K, V>, A::Error>
+            where
+                A: serde_core::de::MapAccess<'de>,
+            {
+                let mut key: Option<Spanned<K>> = None;
+                let mut value: Option<Spanned<V>> = None;
+                while let Some(field) = visitor.next_key()? {
+                    match field {
+                        KEY_FIELD => {
+                            if key.is_some() {
+                                return Err(serde_core::de::Error::duplicate_field(KEY_FIELD));
+                            }
+                            key = Some(visitor.next_value()?);
+                        }
+                        VALUE_FIELD => {
+                            if value.is_some() {
+                                return Err(serde_core::de::Error::duplicate_field(VALUE_FIELD));
+                            }
+                            value = Some(visitor.next_value()?);
+                        }
+                        field => {
+                            return Err(serde_core::de::Error::unknown_field(
+                                field,
+                                &[KEY_FIELD, VALUE_FIELD],
+                            ));
+                        }
+                    }
+                }
+                match (key, value) {
+                    (Some(key), Some(value)) => Ok(SpannedEntry { key, value }),
+                    (None, _) => Err(serde_core::de::Error::missing_field(KEY_FIELD)),
+                    (_, None) => Err(serde_core::de::Error::missing_field(VALUE_FIELD)),
+                }
+            }
+        }
+
+        static FIELDS: [&str; 2] = [KEY_FIELD, VALUE_FIELD];
+
+        let visitor = SpannedEntryVisitor(::core::marker::PhantomData);
+
+        deserializer.deserialize_struct(NAME, &FIELDS, visitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K, V: serde_core::ser::Serialize> serde_core::ser::Serialize for SpannedEntry<K, V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::ser::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
@@ -3,6 +3,8 @@
 //! This allows capturing the location, in bytes, for a value in the original parsed document for
 //! compatible deserializers.
 //!
+//! Likewise, `Commented` allows writing comments alongside a value for compatible serializers.
+//!
 //! [serde]: https://serde.rs/
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -22,10 +24,18 @@
 #[allow(unused_extern_crates)]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+mod commented;
+mod entry;
 mod spanned;
+#[cfg(feature = "alloc")]
+pub use crate::commented::Commented;
+pub use crate::entry::SpannedEntry;
 pub use crate::spanned::Spanned;
 #[cfg(feature = "serde")]
 pub mod de;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod ser;
 
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
@@ -0,0 +1,499 @@
+//! Serialization support for [`Commented`][crate::Commented]
+
+use alloc::string::String;
+
+use crate::commented;
+use crate::commented::COMMENT_FIELD;
+use crate::commented::TRAILING_COMMENT_FIELD;
+use crate::commented::VALUE_FIELD;
+
+/// Check if serializing a [`Commented`][crate::Commented]
+pub fn is_commented(name: &'static str) -> bool {
+    commented::is_commented(name)
+}
+
+/// The comments of a [`Commented`][crate::Commented]
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Comments {
+    comment: Option<String>,
+    trailing_comment: Option<String>,
+}
+
+impl Comments {
+    /// The comment written above the value
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// The comment written after the value, on the same line
+    pub fn trailing_comment(&self) -> Option<&str> {
+        self.trailing_comment.as_deref()
+    }
+
+    /// Whether there are no comments
+    pub fn is_empty(&self) -> bool {
+        self.comment.is_none() && self.trailing_comment.is_none()
+    }
+
+    /// Add `other`, e.g. the comments of an array's first element to those of the array
+    pub fn merge(&mut self, other: Self) {
+        self.comment = match (self.comment.take(), other.comment) {
+            (Some(comment), Some(other)) => Some(alloc::format!("{comment}\n{other}")),
+            (comment, other) => other.or(comment),
+        };
+        if other.trailing_comment.is_some() {
+            self.trailing_comment = other.trailing_comment;
+        }
+    }
+
+    /// Move the trailing comment to the end of the comment, for when there is no line to trail
+    pub fn trailing_as_comment(mut self) -> Self {
+        if let Some(trailing_comment) = self.trailing_comment.take() {
+            self.merge(Self {
+                comment: Some(trailing_comment),
+                trailing_comment: None,
+            });
+        }
+        self
+    }
+}
+
+/// Serialize the value of a [`Commented`][crate::Commented] with the serializer created from its
+/// comments
+///
+/// `Commented` is serialized as a newtype struct so serializers can recognize it by name.  The
+/// newtype's value should be serialized with this.
+pub struct CommentedSerializer<F> {
+    serializer: F,
+}
+
+impl<F> CommentedSerializer<F> {
+    /// Create a serializer for the value of a [`Commented`][crate::Commented], calling
+    /// `serializer` with its comments
+    pub fn new(serializer: F) -> Self {
+        Self { serializer }
+    }
+}
+
+fn unexpected<E>() -> E
+where
+    E: serde_core::ser::Error,
+{
+    E::custom("expected the fields of `Commented`")
+}
+
+impl<F, S> serde_core::ser::Serializer for CommentedSerializer<F>
+where
+    F: FnOnce(Comments) -> Result<S, S::Error>,
+    S: serde_core::ser::Serializer,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = SerializeCommented<F, S>;
+    type SerializeStructVariant = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_some<T>(self, _v: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        Err(unexpected())
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        _v: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        Err(unexpected())
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        Err(unexpected())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        if name != commented::NAME {
+            return Err(unexpected());
+        }
+        Ok(SerializeCommented {
+            serializer: Some(self.serializer),
+            comments: Comments::default(),
+            ok: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unexpected())
+    }
+}
+
+/// Serialize the fields of a [`Commented`][crate::Commented], see [`CommentedSerializer`]
+pub struct SerializeCommented<F, S>
+where
+    S: serde_core::ser::Serializer,
+{
+    serializer: Option<F>,
+    comments: Comments,
+    ok: Option<S::Ok>,
+}
+
+impl<F, S> serde_core::ser::SerializeStruct for SerializeCommented<F, S>
+where
+    F: FnOnce(Comments) -> Result<S, S::Error>,
+    S: serde_core::ser::Serializer,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        match key {
+            COMMENT_FIELD => self.comments.comment = Some(comment(value, true)?),
+            TRAILING_COMMENT_FIELD => self.comments.trailing_comment = Some(comment(value, false)?),
+            VALUE_FIELD => {
+                let serializer = self.serializer.take().ok_or_else(unexpected)?;
+                let serializer = serializer(core::mem::take(&mut self.comments))?;
+                self.ok = Some(value.serialize(serializer)?);
+            }
+            _ => return Err(unexpected()),
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.ok.ok_or_else(unexpected)
+    }
+}
+
+/// Check a comment is written on its own lines, or on one line when trailing a value
+fn comment<T, E>(value: &T, allow_newlines: bool) -> Result<String, E>
+where
+    T: serde_core::ser::Serialize + ?Sized,
+    E: serde_core::ser::Error,
+{
+    let comment = value.serialize(CommentSerializer(core::marker::PhantomData))?;
+    let is_allowed = |c: char| c == '\t' || allow_newlines && c == '\n';
+    if comment
+        .replace("\r\n", "\n")
+        .chars()
+        .any(|c| c.is_control() && !is_allowed(c))
+    {
+        let message = if allow_newlines {
+            "comment may not contain control characters other than tabs and newlines"
+        } else {
+            "trailing comment may not contain control characters other than tabs"
+        };
+        return Err(E::custom(message));
+    }
+    Ok(comment)
+}
+
+/// Serialize a comment, which must be a string
+struct CommentSerializer<E>(core::marker::PhantomData<E>);
+
+impl<E> serde_core::ser::Serializer for CommentSerializer<E>
+where
+    E: serde_core::ser::Error,
+{
+    type Ok = String;
+    type Error = E;
+    type SerializeSeq = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeStructVariant = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_some<T>(self, _v: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        Err(unexpected())
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        _v: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        Err(unexpected())
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        Err(unexpected())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(unexpected())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unexpected())
+    }
+}
@@ -14,6 +14,8 @@ pub(crate) const START_FIELD: &str = "$__serde_spanned_private_start";
 #[cfg(feature = "serde")]
 pub(crate) const END_FIELD: &str = "$__serde_spanned_private_end";
 #[cfg(feature = "serde")]
+pub(crate) const SOURCE_ID_FIELD: &str = "$__serde_spanned_private_source_id";
+#[cfg(feature = "serde")]
 pub(crate) const VALUE_FIELD: &str = "$__serde_spanned_private_value";
 #[cfg(feature = "serde")]
 pub(crate) fn is_spanned(name: &'static str) -> bool {
@@ -21,10 +23,12 @@ pub(crate) fn is_spanned(name: &'static str) -> bool {
 }
 
 /// A spanned value, indicating the range at which it is defined in the source.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Spanned<T> {
     /// Byte range
     span: core::ops::Range<usize>,
+    /// Which source the span is in, when deserializing from several
+    source_id: Option<usize>,
     /// The spanned value.
     value: T,
 }
@@ -84,7 +88,17 @@ impl<T> Spanned<T> {
     /// # type DetailedDependency = std::collections::BTreeMap<String, String>;
     /// ```
     pub fn new(range: core::ops::Range<usize>, value: T) -> Self {
-        Self { span: range, value }
+        Self {
+            span: range,
+            source_id: None,
+            value,
+        }
+    }
+
+    /// Set which source the span is in, see [`Spanned::source_id`]
+    pub fn with_source_id(mut self, source_id: usize) -> Self {
+        self.source_id = Some(source_id);
+        self
     }
 
     /// Byte range
@@ -92,6 +106,15 @@ impl<T> Spanned<T> {
         self.span.clone()
     }
 
+    /// Which source [`Spanned::span`] is in
+    ///
+    /// Deserializers may identify their input, e.g. to tell apart values merged from several
+    /// files.
+    /// This is `None` when they don't.
+    pub fn source_id(&self) -> Option<usize> {
+        self.source_id
+    }
+
     /// Consumes the spanned value and returns the contained value.
     pub fn into_inner(self) -> T {
         self.value
@@ -112,9 +135,21 @@ impl<T> Spanned<T> {
 impl<T> Spanned<T> {
     pub(crate) const START_FIELD: &str = START_FIELD;
     pub(crate) const END_FIELD: &str = END_FIELD;
+    pub(crate) const SOURCE_ID_FIELD: &str = SOURCE_ID_FIELD;
     pub(crate) const VALUE_FIELD: &str = VALUE_FIELD;
 }
 
+impl<T: core::fmt::Debug> core::fmt::Debug for Spanned<T> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug = fmt.debug_struct("Spanned");
+        debug.field("span", &self.span);
+        if let Some(source_id) = self.source_id {
+            debug.field("source_id", &source_id);
+        }
+        debug.field("value", &self.value).finish()
+    }
+}
+
 impl<T: core::fmt::Display> core::fmt::Display for Spanned<T> {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.get_ref().fmt(fmt)
@@ -201,6 +236,7 @@ where
             {
                 let mut start: Option<usize> = None;
                 let mut end: Option<usize> = None;
+                let mut source_id: Option<usize> = None;
                 let mut value: Option<T> = None;
                 while let Some(key) = visitor.next_key()? {
                     match key {
@@ -216,6 +252,14 @@ where
                             }
                             end = Some(visitor.next_value()?);
                         }
+                        SOURCE_ID_FIELD => {
+                            if source_id.is_some() {
+                                return Err(serde_core::de::Error::duplicate_field(
+                                    SOURCE_ID_FIELD,
+                                ));
+                            }
+                            source_id = Some(visitor.next_value()?);
+                        }
                         VALUE_FIELD => {
                             if value.is_some() {
                                 return Err(serde_core::de::Error::duplicate_field(VALUE_FIELD));
@@ -225,7 +269,7 @@ where
                         field => {
                             return Err(serde_core::de::Error::unknown_field(
                                 field,
-                                &[START_FIELD, END_FIELD, VALUE_FIELD],
+                                &[START_FIELD, END_FIELD, SOURCE_ID_FIELD, VALUE_FIELD],
                             ));
                         }
                     }
@@ -233,6 +277,7 @@ where
                 match (start, end, value) {
                     (Some(start), Some(end), Some(value)) => Ok(Spanned {
                         span: start..end,
+                        source_id,
                         value,
                     }),
                     (None, _, _) => Err(serde_core::de::Error::missing_field(START_FIELD)),
@@ -242,7 +287,7 @@ where
             }
         }
 
-        static FIELDS: [&str; 3] = [START_FIELD, END_FIELD, VALUE_FIELD];
+        static FIELDS: [&str; 4] = [START_FIELD, END_FIELD, SOURCE_ID_FIELD, VALUE_FIELD];
 
         let visitor = SpannedVisitor(::core::marker::PhantomData);
 
@@ -31,3 +31,20 @@ fn operators() {
     assert_eq!(f.bar.partial_cmp(&g.bar), Some(Ordering::Equal));
     assert_eq!(f.baz.partial_cmp(&g.baz), Some(Ordering::Equal));
 }
+
+#[test]
+fn source_id() {
+    let a = Spanned::new(0..4, true).with_source_id(1);
+    let b = Spanned::new(0..4, true);
+    assert_eq!(a.source_id(), Some(1));
+    assert_eq!(b.source_id(), None);
+
+    // test that eq ignores the source, like the span
+    assert_eq!(a, b);
+
+    assert_eq!(
+        format!("{a:?}"),
+        "Spanned { span: 0..4, source_id: 1, value: true }"
+    );
+    assert_eq!(format!("{b:?}"), "Spanned { span: 0..4, value: true }");
+}
@@ -1,5 +1,6 @@
 use serde_spanned::Spanned;
 
+use super::Raw;
 use crate::de::DeArray;
 use crate::de::DeValue;
 use crate::de::Error;
@@ -7,11 +8,23 @@ use crate::de::Error;
 pub(crate) struct ArrayDeserializer<'i> {
     input: DeArray<'i>,
     span: core::ops::Range<usize>,
+    raw: Option<Raw<'i>>,
+    source_id: Option<usize>,
 }
 
 impl<'i> ArrayDeserializer<'i> {
-    pub(crate) fn new(input: DeArray<'i>, span: core::ops::Range<usize>) -> Self {
-        Self { input, span }
+    pub(crate) fn new(
+        input: DeArray<'i>,
+        span: core::ops::Range<usize>,
+        raw: Option<Raw<'i>>,
+        source_id: Option<usize>,
+    ) -> Self {
+        Self {
+            input,
+            span,
+            raw,
+            source_id,
+        }
     }
 }
 
@@ -22,7 +35,7 @@ impl<'de> serde_core::Deserializer<'de> for ArrayDeserializer<'de> {
     where
         V: serde_core::de::Visitor<'de>,
     {
-        visitor.visit_seq(ArraySeqAccess::new(self.input))
+        visitor.visit_seq(ArraySeqAccess::new(self.input, self.raw, self.source_id))
     }
 
     fn deserialize_struct<V>(
@@ -36,7 +49,9 @@ impl<'de> serde_core::Deserializer<'de> for ArrayDeserializer<'de> {
     {
         if serde_spanned::de::is_spanned(name) {
             let span = self.span.clone();
-            return visitor.visit_map(super::SpannedDeserializer::new(self, span));
+            let source_id = self.source_id;
+            return visitor
+                .visit_map(super::SpannedDeserializer::new(self, span).with_source_id(source_id));
         }
 
         self.deserialize_any(visitor)
@@ -58,13 +73,17 @@ impl<'de> serde_core::de::IntoDeserializer<'de, Error> for ArrayDeserializer<'de
 }
 
 pub(crate) struct ArraySeqAccess<'i> {
-    iter: alloc::vec::IntoIter<Spanned<DeValue<'i>>>,
+    iter: core::iter::Enumerate<alloc::vec::IntoIter<Spanned<DeValue<'i>>>>,
+    raw: Option<Raw<'i>>,
+    source_id: Option<usize>,
 }
 
 impl<'i> ArraySeqAccess<'i> {
-    pub(crate) fn new(input: DeArray<'i>) -> Self {
+    pub(crate) fn new(input: DeArray<'i>, raw: Option<Raw<'i>>, source_id: Option<usize>) -> Self {
         Self {
-            iter: input.into_iter(),
+            iter: input.into_iter().enumerate(),
+            raw,
+            source_id,
         }
     }
 }
@@ -77,11 +96,23 @@ impl<'de> serde_core::de::SeqAccess<'de> for ArraySeqAccess<'de> {
         T: serde_core::de::DeserializeSeed<'de>,
     {
         match self.iter.next() {
-            Some(v) => {
+            Some((i, v)) => {
                 let span = v.span();
                 let v = v.into_inner();
-                seed.deserialize(crate::de::ValueDeserializer::with_parts(v, span))
-                    .map(Some)
+                seed.deserialize(crate::de::ValueDeserializer::with_parts(
+                    v,
+                    span.clone(),
+                    self.raw.clone(),
+                    self.source_id,
+                ))
+                .map(Some)
+                .map_err(|mut e: Self::Error| {
+                    if e.span().is_none() {
+                        e.set_span(Some(span));
+                    }
+                    e.add_index(i);
+                    e
+                })
             }
             None => Ok(None),
         }
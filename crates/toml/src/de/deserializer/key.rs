@@ -5,12 +5,21 @@ use crate::de::Error;
 
 pub(crate) struct KeyDeserializer<'i> {
     span: Option<core::ops::Range<usize>>,
+    source_id: Option<usize>,
     key: DeString<'i>,
 }
 
 impl<'i> KeyDeserializer<'i> {
-    pub(crate) fn new(key: DeString<'i>, span: Option<core::ops::Range<usize>>) -> Self {
-        KeyDeserializer { span, key }
+    pub(crate) fn new(
+        key: DeString<'i>,
+        span: Option<core::ops::Range<usize>>,
+        source_id: Option<usize>,
+    ) -> Self {
+        KeyDeserializer {
+            span,
+            source_id,
+            key,
+        }
     }
 }
 
@@ -163,7 +172,9 @@ impl<'de> serde_core::de::Deserializer<'de> for KeyDeserializer<'de> {
     {
         if serde_spanned::de::is_spanned(name) {
             if let Some(span) = self.span.clone() {
-                return visitor.visit_map(super::SpannedDeserializer::new(self.key, span));
+                return visitor.visit_map(
+                    super::SpannedDeserializer::new(self.key, span).with_source_id(self.source_id),
+                );
             } else {
                 return Err(Error::custom("value is missing a span", None));
             }
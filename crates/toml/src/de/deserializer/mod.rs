@@ -19,6 +19,7 @@ use array::ArrayDeserializer;
 use key::KeyDeserializer;
 use serde_spanned::Spanned;
 use serde_spanned::de::SpannedDeserializer;
+use serde_spanned::de::SpannedEntryDeserializer;
 use table::TableDeserializer;
 use table_enum::TableEnumDeserializer;
 use toml_datetime::de::DatetimeDeserializer;
@@ -29,7 +30,8 @@ use toml_datetime::de::DatetimeDeserializer;
 pub struct Deserializer<'i> {
     span: core::ops::Range<usize>,
     root: DeTable<'i>,
-    raw: Option<&'i str>,
+    raw: Option<Raw<'i>>,
+    source_id: Option<usize>,
 }
 
 impl<'i> Deserializer<'i> {
@@ -41,7 +43,28 @@ impl<'i> Deserializer<'i> {
         Ok(Self {
             span,
             root,
-            raw: Some(raw),
+            raw: Some(Raw::Borrowed(raw)),
+            source_id: None,
+        })
+    }
+
+    /// Parse a TOML document embedded at `span` within `host`, like front matter
+    ///
+    /// Spans, including those in errors, are relative to `host`.
+    /// For locating embedded TOML, see [`embed`][crate::de::embed].
+    ///
+    /// # Panics
+    ///
+    /// If `span` is out of bounds or not on `char` boundaries
+    pub fn parse_embedded(host: &'i str, span: core::ops::Range<usize>) -> Result<Self, Error> {
+        let root = DeTable::parse_embedded(host, span)?;
+        let span = root.span();
+        let root = root.into_inner();
+        Ok(Self {
+            span,
+            root,
+            raw: Some(Raw::Borrowed(host)),
+            source_id: None,
         })
     }
 
@@ -51,8 +74,81 @@ impl<'i> Deserializer<'i> {
         Self::parse(raw)
     }
 
+    /// Identify the input, e.g. when deserializing several files
+    ///
+    /// This is reported by [`Spanned::source_id`] and [`Error::source_id`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Deserialize as _;
+    /// use toml::Spanned;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     port: Spanned<u16>,
+    /// }
+    ///
+    /// let files = ["port = 80", "port = 8080"];
+    /// let configs = files
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(id, input)| {
+    ///         let deserializer = toml::de::Deserializer::parse(input)?.with_source_id(id);
+    ///         Config::deserialize(deserializer)
+    ///     })
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// let port = &configs[1].port;
+    /// assert_eq!(port.source_id(), Some(1));
+    /// assert_eq!(&files[port.source_id().unwrap()][port.span()], "8080");
+    /// ```
+    pub fn with_source_id(mut self, source_id: usize) -> Self {
+        self.source_id = Some(source_id);
+        self
+    }
+
+    /// Copy any borrowed data, releasing the lifetime of the input
+    pub(crate) fn into_owned(self) -> Deserializer<'static> {
+        Deserializer {
+            span: self.span,
+            root: self.root.into_static(),
+            raw: self.raw.map(Raw::into_owned),
+            source_id: self.source_id,
+        }
+    }
+
     fn into_table_de(self) -> ValueDeserializer<'i> {
-        ValueDeserializer::with_parts(DeValue::Table(self.root), self.span)
+        ValueDeserializer::with_parts(
+            DeValue::Table(self.root),
+            self.span,
+            self.raw,
+            self.source_id,
+        )
+    }
+}
+
+/// The source text of the document, for [`RawValue`][crate::de::RawValue]
+#[derive(Clone)]
+pub(crate) enum Raw<'i> {
+    Borrowed(&'i str),
+    /// Copied when releasing the lifetime of the input, see [`Deserializer::into_owned`]
+    Shared(alloc::sync::Arc<str>),
+}
+
+impl Raw<'_> {
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Self::Borrowed(raw) => raw,
+            Self::Shared(raw) => raw,
+        }
+    }
+
+    fn into_owned(self) -> Raw<'static> {
+        match self {
+            Self::Borrowed(raw) => Raw::Shared(raw.into()),
+            Self::Shared(raw) => Raw::Shared(raw),
+        }
     }
 }
 
@@ -64,6 +160,7 @@ impl<'i> From<Spanned<DeTable<'i>>> for Deserializer<'i> {
             span,
             root,
             raw: None,
+            source_id: None,
         }
     }
 }
@@ -75,11 +172,13 @@ impl<'de> serde_core::Deserializer<'de> for Deserializer<'de> {
     where
         V: serde_core::de::Visitor<'de>,
     {
-        let raw = self.raw;
+        let raw = self.raw.clone();
+        let source_id = self.source_id;
         self.into_table_de()
             .deserialize_any(visitor)
             .map_err(|mut e: Self::Error| {
-                e.set_input(raw);
+                e.set_input(raw.as_ref().map(Raw::as_str));
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -90,11 +189,13 @@ impl<'de> serde_core::Deserializer<'de> for Deserializer<'de> {
     where
         V: serde_core::de::Visitor<'de>,
     {
-        let raw = self.raw;
+        let raw = self.raw.clone();
+        let source_id = self.source_id;
         self.into_table_de()
             .deserialize_option(visitor)
             .map_err(|mut e: Self::Error| {
-                e.set_input(raw);
+                e.set_input(raw.as_ref().map(Raw::as_str));
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -107,11 +208,13 @@ impl<'de> serde_core::Deserializer<'de> for Deserializer<'de> {
     where
         V: serde_core::de::Visitor<'de>,
     {
-        let raw = self.raw;
+        let raw = self.raw.clone();
+        let source_id = self.source_id;
         self.into_table_de()
             .deserialize_newtype_struct(name, visitor)
             .map_err(|mut e: Self::Error| {
-                e.set_input(raw);
+                e.set_input(raw.as_ref().map(Raw::as_str));
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -125,11 +228,13 @@ impl<'de> serde_core::Deserializer<'de> for Deserializer<'de> {
     where
         V: serde_core::de::Visitor<'de>,
     {
-        let raw = self.raw;
+        let raw = self.raw.clone();
+        let source_id = self.source_id;
         self.into_table_de()
             .deserialize_struct(name, fields, visitor)
             .map_err(|mut e: Self::Error| {
-                e.set_input(raw);
+                e.set_input(raw.as_ref().map(Raw::as_str));
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -144,11 +249,13 @@ impl<'de> serde_core::Deserializer<'de> for Deserializer<'de> {
     where
         V: serde_core::de::Visitor<'de>,
     {
-        let raw = self.raw;
+        let raw = self.raw.clone();
+        let source_id = self.source_id;
         self.into_table_de()
             .deserialize_enum(name, variants, visitor)
             .map_err(|mut e: Self::Error| {
-                e.set_input(raw);
+                e.set_input(raw.as_ref().map(Raw::as_str));
+                e.set_source_id(source_id);
                 e
             })
     }
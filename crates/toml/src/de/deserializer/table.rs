@@ -1,6 +1,7 @@
 use serde_core::de::IntoDeserializer;
 use serde_spanned::Spanned;
 
+use super::Raw;
 use crate::de::DeString;
 use crate::de::DeTable;
 use crate::de::DeValue;
@@ -10,11 +11,23 @@ use crate::map::IntoIter;
 pub(crate) struct TableDeserializer<'i> {
     span: core::ops::Range<usize>,
     items: DeTable<'i>,
+    raw: Option<Raw<'i>>,
+    source_id: Option<usize>,
 }
 
 impl<'i> TableDeserializer<'i> {
-    pub(crate) fn new(items: DeTable<'i>, span: core::ops::Range<usize>) -> Self {
-        Self { span, items }
+    pub(crate) fn new(
+        items: DeTable<'i>,
+        span: core::ops::Range<usize>,
+        raw: Option<Raw<'i>>,
+        source_id: Option<usize>,
+    ) -> Self {
+        Self {
+            span,
+            items,
+            raw,
+            source_id,
+        }
     }
 }
 
@@ -59,7 +72,9 @@ impl<'de> serde_core::Deserializer<'de> for TableDeserializer<'de> {
     {
         if serde_spanned::de::is_spanned(name) {
             let span = self.span.clone();
-            return visitor.visit_map(super::SpannedDeserializer::new(self, span));
+            let source_id = self.source_id;
+            return visitor
+                .visit_map(super::SpannedDeserializer::new(self, span).with_source_id(source_id));
         }
 
         self.deserialize_any(visitor)
@@ -108,6 +123,8 @@ impl<'de> IntoDeserializer<'de, Error> for TableDeserializer<'de> {
 pub(crate) struct TableMapAccess<'i> {
     iter: IntoIter<Spanned<DeString<'i>>, Spanned<DeValue<'i>>>,
     span: core::ops::Range<usize>,
+    raw: Option<Raw<'i>>,
+    source_id: Option<usize>,
     value: Option<(Spanned<DeString<'i>>, Spanned<DeValue<'i>>)>,
 }
 
@@ -116,6 +133,8 @@ impl<'i> TableMapAccess<'i> {
         Self {
             iter: input.items.into_iter(),
             span: input.span,
+            raw: input.raw,
+            source_id: input.source_id,
             value: None,
         }
     }
@@ -135,6 +154,7 @@ impl<'de> serde_core::de::MapAccess<'de> for TableMapAccess<'de> {
                     .deserialize(super::KeyDeserializer::new(
                         k.clone().into_inner(),
                         Some(key_span.clone()),
+                        self.source_id,
                     ))
                     .map(Some)
                     .map_err(|mut e: Self::Error| {
@@ -157,10 +177,15 @@ impl<'de> serde_core::de::MapAccess<'de> for TableMapAccess<'de> {
         match self.value.take() {
             Some((k, v)) => {
                 let span = v.span();
-                seed.deserialize(crate::de::ValueDeserializer::with_parts(
-                    v.into_inner(),
-                    span.clone(),
-                ))
+                seed.deserialize(
+                    crate::de::ValueDeserializer::with_parts(
+                        v.into_inner(),
+                        span.clone(),
+                        self.raw.clone(),
+                        self.source_id,
+                    )
+                    .with_key(k.clone()),
+                )
                 .map_err(|mut e: Self::Error| {
                     if e.span().is_none() {
                         e.set_span(Some(span));
@@ -199,6 +224,7 @@ impl<'de> serde_core::de::EnumAccess<'de> for TableMapAccess<'de> {
             .deserialize(super::KeyDeserializer::new(
                 key.into_inner(),
                 Some(key_span.clone()),
+                self.source_id,
             ))
             .map_err(|mut e: Self::Error| {
                 if e.span().is_none() {
@@ -209,7 +235,8 @@ impl<'de> serde_core::de::EnumAccess<'de> for TableMapAccess<'de> {
 
         let value_span = value.span();
         let value = value.into_inner();
-        let variant = super::TableEnumDeserializer::new(value, value_span);
+        let variant =
+            super::TableEnumDeserializer::new(value, value_span, self.raw, self.source_id);
 
         Ok((val, variant))
     }
@@ -1,3 +1,4 @@
+use super::Raw;
 use crate::alloc_prelude::*;
 use crate::de::DeArray;
 use crate::de::DeValue;
@@ -7,11 +8,23 @@ use crate::de::Error;
 pub(crate) struct TableEnumDeserializer<'i> {
     value: DeValue<'i>,
     span: core::ops::Range<usize>,
+    raw: Option<Raw<'i>>,
+    source_id: Option<usize>,
 }
 
 impl<'i> TableEnumDeserializer<'i> {
-    pub(crate) fn new(value: DeValue<'i>, span: core::ops::Range<usize>) -> Self {
-        TableEnumDeserializer { value, span }
+    pub(crate) fn new(
+        value: DeValue<'i>,
+        span: core::ops::Range<usize>,
+        raw: Option<Raw<'i>>,
+        source_id: Option<usize>,
+    ) -> Self {
+        TableEnumDeserializer {
+            value,
+            span,
+            raw,
+            source_id,
+        }
     }
 }
 
@@ -45,7 +58,12 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer<'de> {
     where
         T: serde_core::de::DeserializeSeed<'de>,
     {
-        seed.deserialize(super::ValueDeserializer::with_parts(self.value, self.span))
+        seed.deserialize(super::ValueDeserializer::with_parts(
+            self.value,
+            self.span,
+            self.raw,
+            self.source_id,
+        ))
     }
 
     fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
@@ -59,7 +77,12 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer<'de> {
 
                 if tuple_values.len() == len {
                     serde_core::de::Deserializer::deserialize_seq(
-                        super::ArrayDeserializer::new(tuple_values, values_span),
+                        super::ArrayDeserializer::new(
+                            tuple_values,
+                            values_span,
+                            self.raw,
+                            self.source_id,
+                        ),
                         visitor,
                     )
                 } else {
@@ -88,7 +111,12 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer<'de> {
 
                 if tuple_values.len() == len {
                     serde_core::de::Deserializer::deserialize_seq(
-                        super::ArrayDeserializer::new(tuple_values, values_span),
+                        super::ArrayDeserializer::new(
+                            tuple_values,
+                            values_span,
+                            self.raw,
+                            self.source_id,
+                        ),
                         visitor,
                     )
                 } else {
@@ -114,7 +142,7 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer<'de> {
         V: serde_core::de::Visitor<'de>,
     {
         serde_core::de::Deserializer::deserialize_struct(
-            super::ValueDeserializer::with_parts(self.value, self.span)
+            super::ValueDeserializer::with_parts(self.value, self.span, self.raw, self.source_id)
                 .with_struct_key_validation(),
             "", // TODO: this should be the variant name
             fields,
@@ -1,8 +1,11 @@
+use alloc::borrow::Cow;
+
 use serde_core::de::IntoDeserializer as _;
 use serde_spanned::Spanned;
 
 use super::ArrayDeserializer;
 use super::DatetimeDeserializer;
+use super::Raw;
 use super::TableDeserializer;
 use crate::alloc_prelude::*;
 use crate::de::DeString;
@@ -41,6 +44,9 @@ use crate::de::Error;
 pub struct ValueDeserializer<'i> {
     span: core::ops::Range<usize>,
     input: DeValue<'i>,
+    raw: Option<Raw<'i>>,
+    source_id: Option<usize>,
+    key: Option<Spanned<DeString<'i>>>,
     validate_struct_keys: bool,
 }
 
@@ -50,7 +56,12 @@ impl<'i> ValueDeserializer<'i> {
         let input = DeValue::parse(raw)?;
         let span = input.span();
         let input = input.into_inner();
-        Ok(Self::with_parts(input, span))
+        Ok(Self::with_parts(
+            input,
+            span,
+            Some(Raw::Borrowed(raw)),
+            None,
+        ))
     }
 
     /// Deprecated, replaced with [`ValueDeserializer::parse`]
@@ -59,14 +70,28 @@ impl<'i> ValueDeserializer<'i> {
         Self::parse(raw)
     }
 
-    pub(crate) fn with_parts(input: DeValue<'i>, span: core::ops::Range<usize>) -> Self {
+    pub(crate) fn with_parts(
+        input: DeValue<'i>,
+        span: core::ops::Range<usize>,
+        raw: Option<Raw<'i>>,
+        source_id: Option<usize>,
+    ) -> Self {
         Self {
             input,
             span,
+            raw,
+            source_id,
+            key: None,
             validate_struct_keys: false,
         }
     }
 
+    /// The key of the table entry this value is for, see [`serde_spanned::SpannedEntry`]
+    pub(crate) fn with_key(mut self, key: Spanned<DeString<'i>>) -> Self {
+        self.key = Some(key);
+        self
+    }
+
     pub(crate) fn with_struct_key_validation(mut self) -> Self {
         self.validate_struct_keys = true;
         self
@@ -77,7 +102,7 @@ impl<'i> From<Spanned<DeValue<'i>>> for ValueDeserializer<'i> {
     fn from(root: Spanned<DeValue<'i>>) -> Self {
         let span = root.span();
         let root = root.into_inner();
-        Self::with_parts(root, span)
+        Self::with_parts(root, span, None, None)
     }
 }
 
@@ -113,9 +138,11 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer<'de> {
                 }
             }
             DeValue::Boolean(v) => visitor.visit_bool(v),
-            DeValue::Datetime(v) => visitor.visit_map(DatetimeDeserializer::new(v)),
-            DeValue::Array(v) => ArrayDeserializer::new(v, span.clone()).deserialize_any(visitor),
-            DeValue::Table(v) => TableDeserializer::new(v, span.clone()).deserialize_any(visitor),
+            DeValue::Datetime(v) => visitor.visit_map(DatetimeDeserializer::new_lossless(v)),
+            DeValue::Array(v) => ArrayDeserializer::new(v, span.clone(), self.raw, self.source_id)
+                .deserialize_any(visitor),
+            DeValue::Table(v) => TableDeserializer::new(v, span.clone(), self.raw, self.source_id)
+                .deserialize_any(visitor),
         }
         .map_err(|mut e: Self::Error| {
             if e.span().is_none() {
@@ -156,13 +183,34 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer<'de> {
 
     fn deserialize_newtype_struct<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> Result<V::Value, Error>
     where
         V: serde_core::de::Visitor<'de>,
     {
         let span = self.span.clone();
+        if crate::de::is_raw_value(name) {
+            let Some(raw) = &self.raw else {
+                return Err(Error::custom(
+                    "`RawValue` requires the source text of the value",
+                    Some(span),
+                ));
+            };
+            return match raw_text(&self.input, span.clone(), raw) {
+                Cow::Borrowed(text) => visitor.visit_map(
+                    super::SpannedDeserializer::new(
+                        serde_core::de::value::BorrowedStrDeserializer::new(text),
+                        span,
+                    )
+                    .with_source_id(self.source_id),
+                ),
+                Cow::Owned(text) => visitor.visit_map(
+                    super::SpannedDeserializer::new(text, span).with_source_id(self.source_id),
+                ),
+            };
+        }
+
         visitor
             .visit_newtype_struct(self)
             .map_err(|mut e: Self::Error| {
@@ -174,7 +222,7 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer<'de> {
     }
 
     fn deserialize_struct<V>(
-        self,
+        mut self,
         name: &'static str,
         fields: &'static [&'static str],
         visitor: V,
@@ -184,20 +232,36 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer<'de> {
     {
         if serde_spanned::de::is_spanned(name) {
             let span = self.span.clone();
-            return visitor.visit_map(super::SpannedDeserializer::new(self, span));
+            let source_id = self.source_id;
+            return visitor
+                .visit_map(super::SpannedDeserializer::new(self, span).with_source_id(source_id));
+        }
+
+        if serde_spanned::de::is_spanned_entry(name) {
+            if let Some(key) = self.key.take() {
+                let key_span = key.span();
+                let key =
+                    super::KeyDeserializer::new(key.into_inner(), Some(key_span), self.source_id);
+                return visitor.visit_map(super::SpannedEntryDeserializer::new(key, self));
+            } else {
+                return Err(Error::custom(
+                    "value is not in a table entry",
+                    Some(self.span),
+                ));
+            }
         }
 
         if toml_datetime::de::is_datetime(name) {
             let span = self.span.clone();
             if let DeValue::Datetime(d) = self.input {
-                return visitor.visit_map(DatetimeDeserializer::new(d)).map_err(
-                    |mut e: Self::Error| {
+                return visitor
+                    .visit_map(DatetimeDeserializer::new_lossless(d))
+                    .map_err(|mut e: Self::Error| {
                         if e.span().is_none() {
                             e.set_span(Some(span));
                         }
                         e
-                    },
-                );
+                    });
             }
         }
 
@@ -231,9 +295,8 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer<'de> {
         let span = self.span;
         match self.input {
             DeValue::String(v) => visitor.visit_enum(v.into_deserializer()),
-            DeValue::Table(v) => {
-                TableDeserializer::new(v, span.clone()).deserialize_enum(name, variants, visitor)
-            }
+            DeValue::Table(v) => TableDeserializer::new(v, span.clone(), self.raw, self.source_id)
+                .deserialize_enum(name, variants, visitor),
             _ => Err(Error::custom("wanted string or table", Some(span.clone()))),
         }
         .map_err(|mut e: Self::Error| {
@@ -267,6 +330,61 @@ impl<'de> serde_core::de::IntoDeserializer<'de, Error> for Spanned<DeValue<'de>>
     }
 }
 
+/// The source text of `value`, rewriting standard tables and arrays of tables as inline values
+fn raw_text<'i>(value: &DeValue<'i>, span: core::ops::Range<usize>, raw: &Raw<'i>) -> Cow<'i, str> {
+    match raw {
+        Raw::Borrowed(raw) if is_inline(value) => Cow::Borrowed(&raw[span]),
+        _ => {
+            let mut dst = String::new();
+            write_raw_text(&mut dst, value, span, raw.as_str());
+            Cow::Owned(dst)
+        }
+    }
+}
+
+fn write_raw_text(dst: &mut String, value: &DeValue<'_>, span: core::ops::Range<usize>, raw: &str) {
+    match value {
+        _ if is_inline(value) => dst.push_str(&raw[span]),
+        DeValue::Table(table) if table.is_empty() => dst.push_str("{}"),
+        DeValue::Table(table) => {
+            // Keep the source order, even when the table is sorted
+            let mut entries = table.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| key.span().start);
+
+            dst.push_str("{ ");
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i != 0 {
+                    dst.push_str(", ");
+                }
+                dst.push_str(&raw[key.span()]);
+                dst.push_str(" = ");
+                write_raw_text(dst, value.get_ref(), value.span(), raw);
+            }
+            dst.push_str(" }");
+        }
+        DeValue::Array(array) => {
+            dst.push('[');
+            for (i, value) in array.iter().enumerate() {
+                if i != 0 {
+                    dst.push_str(", ");
+                }
+                write_raw_text(dst, value.get_ref(), value.span(), raw);
+            }
+            dst.push(']');
+        }
+        _ => dst.push_str(&raw[span]),
+    }
+}
+
+/// Whether the span of `value` covers all of its source text
+fn is_inline(value: &DeValue<'_>) -> bool {
+    match value {
+        DeValue::Table(table) => table.is_inline() && !table.is_implicit(),
+        DeValue::Array(array) => !array.is_array_of_tables(),
+        _ => true,
+    }
+}
+
 pub(crate) fn validate_struct_keys(
     table: &DeTable<'_>,
     fields: &'static [&'static str],
@@ -1,12 +1,17 @@
+use crate::ErrorKind;
 use crate::alloc_prelude::*;
 
 /// Errors that can occur when deserializing a type.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Error {
     message: String,
+    kind: ErrorKind,
     input: Option<alloc::sync::Arc<str>>,
-    keys: Vec<String>,
+    source_id: Option<usize>,
+    path: Vec<PathSegment>,
     span: Option<core::ops::Range<usize>>,
+    #[cfg(feature = "parse")]
+    parse: Option<toml_parser::ParseError>,
 }
 
 impl Error {
@@ -35,29 +40,59 @@ impl Error {
         }
 
         let span = error.unexpected().map(|span| span.start()..span.end());
+        let kind = parse_error_kind(&input, &error);
 
         Self {
             message,
+            kind,
             input: Some(input),
-            keys: Vec::new(),
+            source_id: None,
+            path: Vec::new(),
             span,
+            parse: Some(error),
         }
     }
 
+    /// Report a failure from [`toml_parser::decode_bytes`]
+    #[cfg(feature = "parse")]
+    pub(crate) fn from_bytes(input: &[u8], error: toml_parser::ParseError) -> Self {
+        let is_utf16 = input.starts_with(b"\xFF\xFE") || input.starts_with(b"\xFE\xFF");
+        let mut error = Self::new(String::from_utf8_lossy(input).into(), error);
+        if is_utf16 {
+            // Byte offsets do not line up with any text to render
+            error.input = None;
+        }
+        error
+    }
+
     pub(crate) fn custom<T>(msg: T, span: Option<core::ops::Range<usize>>) -> Self
     where
         T: core::fmt::Display,
     {
         Self {
             message: msg.to_string(),
+            kind: ErrorKind::Custom,
             input: None,
-            keys: Vec::new(),
+            source_id: None,
+            path: Vec::new(),
             span,
+            #[cfg(feature = "parse")]
+            parse: None,
         }
     }
 
     pub(crate) fn add_key(&mut self, key: String) {
-        self.keys.insert(0, key);
+        self.path.insert(0, PathSegment::Key(key));
+    }
+
+    pub(crate) fn add_index(&mut self, index: usize) {
+        self.path.insert(0, PathSegment::Index(index));
+    }
+
+    #[cfg(feature = "serde")]
+    fn with_kind(mut self, kind: ErrorKind) -> Self {
+        self.kind = kind;
+        self
     }
 
     /// What went wrong
@@ -65,6 +100,62 @@ impl Error {
         &self.message
     }
 
+    /// What went wrong, for handling the error without matching on [`Error::message`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "parse")] {
+    /// use toml::ErrorKind;
+    ///
+    /// let err = "a = 1\na = 2".parse::<toml::Table>().unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     ErrorKind::DuplicateKey {
+    ///         key: "a".to_owned(),
+    ///         first_span: Some(0..1),
+    ///     }
+    /// );
+    /// # }
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        self.kind.clone()
+    }
+
+    /// The keys and array indices leading to the value the error occurred in
+    ///
+    /// This is available even when [`Error::span`] is not, like for [`Value::try_into`].
+    ///
+    /// [`Value::try_into`]: crate::Value::try_into
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "parse")] {
+    /// use toml::de::PathSegment;
+    ///
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Config {
+    ///     #[allow(dead_code)]
+    ///     ports: Vec<u16>,
+    /// }
+    ///
+    /// let value: toml::Value = toml::from_str("ports = [80, 'http']").unwrap();
+    /// let err = value.try_into::<Config>().unwrap_err();
+    /// assert_eq!(
+    ///     err.path(),
+    ///     [PathSegment::Key("ports".to_owned()), PathSegment::Index(1)]
+    /// );
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid type: string \"http\", expected u16\nin `ports[1]`\n"
+    /// );
+    /// # }
+    /// ```
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+
     /// The start/end index into the original document where the error occurred
     pub fn span(&self) -> Option<core::ops::Range<usize>> {
         self.span.clone()
@@ -78,6 +169,108 @@ impl Error {
     pub fn set_input(&mut self, input: Option<&str>) {
         self.input = input.map(|s| s.into());
     }
+
+    /// The encoded TOML the error applies to, if known
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+
+    /// Identify which source [`Error::input`] is, see [`Error::source_id`]
+    pub fn set_source_id(&mut self, source_id: Option<usize>) {
+        self.source_id = source_id;
+    }
+
+    /// Which source the error applies to, if known
+    ///
+    /// This is set by [`Deserializer::with_source_id`][crate::de::Deserializer::with_source_id],
+    /// like for [`Spanned::source_id`][crate::Spanned::source_id].
+    /// Parse errors happen before a source id can be set, so use [`Error::set_source_id`] for
+    /// those.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "parse")] {
+    /// use serde::Deserialize as _;
+    ///
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Config {
+    ///     #[allow(dead_code)]
+    ///     port: u16,
+    /// }
+    ///
+    /// let files = ["port = 80", "port = 'http'"];
+    /// let mut errors = Vec::new();
+    /// for (id, input) in files.iter().enumerate() {
+    ///     let deserializer = toml::de::Deserializer::parse(input)
+    ///         .unwrap()
+    ///         .with_source_id(id);
+    ///     if let Err(err) = Config::deserialize(deserializer) {
+    ///         errors.push(err);
+    ///     }
+    /// }
+    /// assert_eq!(errors[0].source_id(), Some(1));
+    /// # }
+    /// ```
+    pub fn source_id(&self) -> Option<usize> {
+        self.source_id
+    }
+
+    /// Index [`Error::input`] for converting [`Error::span`] to line / column positions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let err = "a = 1\nb = ".parse::<toml::Table>().unwrap_err();
+    /// let index = err.line_index().unwrap();
+    /// let position = index.line_col(err.span().unwrap().start).unwrap();
+    /// assert_eq!(position, toml::de::LineCol::new(1, 4));
+    /// ```
+    #[cfg(feature = "parse")]
+    pub fn line_index(&self) -> Option<toml_parser::LineIndex<'_>> {
+        self.input().map(toml_parser::LineIndex::new)
+    }
+
+    /// Point to the parts of [`Error::input`] the error applies to, for rendering
+    ///
+    /// This is what [`Error`] displays when the [`Error::input`] and [`Error::span`] are known.
+    /// Related locations, like where a duplicate key was first defined, are secondary labels.
+    /// The [`Error::path`] is a note and a suggested fix is help.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let err = "a = 1\na = 2".parse::<toml::Table>().unwrap_err();
+    /// let diagnostic = err.diagnostic().unwrap();
+    /// assert_eq!(
+    ///     diagnostic.to_string(),
+    ///     "\
+    /// TOML parse error at line 2, column 1
+    ///   |
+    /// 1 | a = 1
+    ///   | - first defined here
+    /// 2 | a = 2
+    ///   | ^
+    /// duplicate key
+    /// "
+    /// );
+    /// ```
+    #[cfg(feature = "parse")]
+    pub fn diagnostic(&self) -> Option<toml_parser::Diagnostic<'_>> {
+        let input = self.input()?;
+        let span = self.span()?;
+        let span = toml_parser::Span::new_unchecked(span.start, span.end);
+
+        let mut diagnostic = toml_parser::Diagnostic::new(input, self.message.clone())
+            .with_label(toml_parser::Label::primary(span));
+        if let Some(error) = &self.parse {
+            diagnostic = diagnostic.with_parse_error(error);
+        }
+        if !self.path.is_empty() {
+            diagnostic = diagnostic.with_note(format!("in `{}`", DisplayPath(&self.path)));
+        }
+        Some(diagnostic)
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -88,6 +281,106 @@ impl serde_core::de::Error for Error {
     {
         Self::custom(msg.to_string(), None)
     }
+
+    fn invalid_type(
+        unexp: serde_core::de::Unexpected<'_>,
+        exp: &dyn serde_core::de::Expected,
+    ) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::invalid_type(unexp, exp);
+        Self::custom(message, None).with_kind(ErrorKind::InvalidType {
+            expected: exp.to_string(),
+            found: unexp.to_string(),
+        })
+    }
+
+    fn invalid_value(
+        unexp: serde_core::de::Unexpected<'_>,
+        exp: &dyn serde_core::de::Expected,
+    ) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::invalid_value(unexp, exp);
+        Self::custom(message, None).with_kind(ErrorKind::InvalidValue {
+            expected: exp.to_string(),
+            found: unexp.to_string(),
+        })
+    }
+
+    fn invalid_length(len: usize, exp: &dyn serde_core::de::Expected) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::invalid_length(len, exp);
+        Self::custom(message, None).with_kind(ErrorKind::InvalidLength {
+            len,
+            expected: exp.to_string(),
+        })
+    }
+
+    fn unknown_variant(variant: &str, expected: &'static [&'static str]) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::unknown_variant(variant, expected);
+        Self::custom(message, None).with_kind(ErrorKind::UnknownVariant {
+            variant: variant.to_owned(),
+            expected,
+        })
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::unknown_field(field, expected);
+        Self::custom(message, None).with_kind(ErrorKind::UnknownField {
+            field: field.to_owned(),
+            expected,
+        })
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::missing_field(field);
+        Self::custom(message, None).with_kind(ErrorKind::MissingField { field })
+    }
+
+    fn duplicate_field(field: &'static str) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::duplicate_field(field);
+        Self::custom(message, None).with_kind(ErrorKind::DuplicateField { field })
+    }
+}
+
+/// Renders the messages of the provided methods of [`serde_core::de::Error`]
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+struct DefaultMessage(String);
+
+#[cfg(feature = "serde")]
+impl serde_core::de::Error for DefaultMessage {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: core::fmt::Display,
+    {
+        Self(msg.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl core::fmt::Display for DefaultMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl core::error::Error for DefaultMessage {}
+
+#[cfg(feature = "parse")]
+fn parse_error_kind(input: &str, error: &toml_parser::ParseError) -> ErrorKind {
+    let span = |span: toml_parser::Span| span.start()..span.end();
+    let key = || error.key(input).unwrap_or_default();
+    match error.kind() {
+        toml_parser::ParseErrorKind::InvalidEscape => ErrorKind::InvalidEscape,
+        toml_parser::ParseErrorKind::RecursionLimit => ErrorKind::RecursionLimit,
+        toml_parser::ParseErrorKind::DuplicateKey => ErrorKind::DuplicateKey {
+            key: key(),
+            first_span: error.context().map(span),
+        },
+        toml_parser::ParseErrorKind::TableRedefinition => ErrorKind::TableRedefinition {
+            key: key(),
+            original_span: error.context().map(span),
+        },
+        _ => ErrorKind::Syntax,
+    }
 }
 
 fn render_literal(literal: &str) -> String {
@@ -103,6 +396,8 @@ fn render_literal(literal: &str) -> String {
 
 /// Displays a TOML parse error
 ///
+/// When the [`Error::input`] and [`Error::span`] are known, this renders the [`Error::diagnostic`].
+///
 /// # Example
 ///
 /// TOML parse error at line 1, column 10
@@ -115,98 +410,97 @@ fn render_literal(literal: &str) -> String {
 /// While parsing a Date-Time
 impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut context = false;
-        if let (Some(input), Some(span)) = (&self.input, self.span()) {
-            context = true;
-
-            let (line, column) = translate_position(input.as_bytes(), span.start);
-            let line_num = line + 1;
-            let col_num = column + 1;
-            let gutter = line_num.to_string().len();
-            let content = input.split('\n').nth(line).expect("valid line number");
-            let highlight_len = span.end - span.start;
-            // Allow highlight to go one past the line
-            let highlight_len = highlight_len.min(content.len().saturating_sub(column));
-
-            writeln!(f, "TOML parse error at line {line_num}, column {col_num}")?;
-            //   |
-            for _ in 0..=gutter {
-                write!(f, " ")?;
-            }
-            writeln!(f, "|")?;
-
-            // 1 | 00:32:00.a999999
-            write!(f, "{line_num} | ")?;
-            writeln!(f, "{content}")?;
-
-            //   |          ^
-            for _ in 0..=gutter {
-                write!(f, " ")?;
-            }
-            write!(f, "|")?;
-            for _ in 0..=column {
-                write!(f, " ")?;
-            }
-            // The span will be empty at eof, so we need to make sure we always print at least
-            // one `^`
-            write!(f, "^")?;
-            for _ in 1..highlight_len {
-                write!(f, "^")?;
-            }
-            writeln!(f)?;
+        #[cfg(feature = "parse")]
+        if let Some(diagnostic) = self.diagnostic() {
+            return diagnostic.fmt(f);
         }
         writeln!(f, "{}", self.message)?;
-        if !context && !self.keys.is_empty() {
-            writeln!(f, "in `{}`", self.keys.join("."))?;
+        if !self.path.is_empty() {
+            writeln!(f, "in `{}`", DisplayPath(&self.path))?;
         }
 
         Ok(())
     }
 }
 
+/// Displays the path like a TOML key, like `ports[1]`
+struct DisplayPath<'p>(&'p [PathSegment]);
+
+impl core::fmt::Display for DisplayPath<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            if i != 0 && matches!(segment, PathSegment::Key(_)) {
+                write!(f, ".")?;
+            }
+            write!(f, "{segment}")?;
+        }
+        Ok(())
+    }
+}
+
 impl core::error::Error for Error {}
 
-fn translate_position(input: &[u8], index: usize) -> (usize, usize) {
-    if input.is_empty() {
-        return (0, index);
-    }
-
-    let safe_index = index.min(input.len() - 1);
-    let column_offset = index - safe_index;
-    let index = safe_index;
-
-    let nl = input[0..index]
-        .iter()
-        .rev()
-        .enumerate()
-        .find(|(_, b)| **b == b'\n')
-        .map(|(nl, _)| index - nl - 1);
-    let line_start = match nl {
-        Some(nl) => nl + 1,
-        None => 0,
-    };
-    let line = input[0..line_start].iter().filter(|b| **b == b'\n').count();
-
-    let column = core::str::from_utf8(&input[line_start..=index])
-        .map(|s| s.chars().count() - 1)
-        .unwrap_or_else(|_| index - line_start);
-    let column = column + column_offset;
-
-    (line, column)
+/// A step in the [path][Error::path] to a value
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum PathSegment {
+    /// An entry of a table
+    Key(String),
+    /// An element of an array
+    Index(usize),
+}
+
+/// Displays the segment like in a TOML key, with indices in brackets
+///
+/// # Example
+///
+/// ```
+/// use toml::de::PathSegment;
+///
+/// assert_eq!(PathSegment::Key("name".to_owned()).to_string(), "name");
+/// assert_eq!(PathSegment::Key("a.b".to_owned()).to_string(), r#""a.b""#);
+/// assert_eq!(PathSegment::Index(0).to_string(), "[0]");
+/// ```
+impl core::fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Key(key) => {
+                let is_bare = !key.is_empty()
+                    && key
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+                if is_bare {
+                    write!(f, "{key}")
+                } else {
+                    write!(f, "\"")?;
+                    for c in key.chars() {
+                        match c {
+                            '"' => write!(f, "\\\"")?,
+                            '\\' => write!(f, "\\\\")?,
+                            c => write!(f, "{c}")?,
+                        }
+                    }
+                    write!(f, "\"")
+                }
+            }
+            Self::Index(index) => write!(f, "[{index}]"),
+        }
+    }
 }
 
 #[cfg(feature = "parse")]
 pub(crate) struct TomlSink<'i, S> {
-    source: toml_parser::Source<'i>,
+    host: &'i str,
     input: Option<alloc::sync::Arc<str>>,
     sink: S,
 }
 
 #[cfg(feature = "parse")]
 impl<'i, S: Default> TomlSink<'i, S> {
-    pub(crate) fn new(source: toml_parser::Source<'i>) -> Self {
+    /// `host` is the document that reported spans are relative to
+    pub(crate) fn new(host: &'i str) -> Self {
         Self {
-            source,
+            host,
             input: None,
             sink: Default::default(),
         }
@@ -223,7 +517,7 @@ impl<'i> toml_parser::ErrorSink for TomlSink<'i, Option<Error>> {
         if self.sink.is_none() {
             let input = self
                 .input
-                .get_or_insert_with(|| alloc::sync::Arc::from(self.source.input()));
+                .get_or_insert_with(|| alloc::sync::Arc::from(self.host));
             let error = Error::new(input.clone(), error);
             self.sink = Some(error);
         }
@@ -235,77 +529,8 @@ impl<'i> toml_parser::ErrorSink for TomlSink<'i, Vec<Error>> {
     fn report_error(&mut self, error: toml_parser::ParseError) {
         let input = self
             .input
-            .get_or_insert_with(|| alloc::sync::Arc::from(self.source.input()));
+            .get_or_insert_with(|| alloc::sync::Arc::from(self.host));
         let error = Error::new(input.clone(), error);
         self.sink.push(error);
     }
 }
-
-#[cfg(test)]
-mod test_translate_position {
-    use super::*;
-
-    #[test]
-    fn empty() {
-        let input = b"";
-        let index = 0;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, 0));
-    }
-
-    #[test]
-    fn start() {
-        let input = b"Hello";
-        let index = 0;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, 0));
-    }
-
-    #[test]
-    fn end() {
-        let input = b"Hello";
-        let index = input.len() - 1;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, input.len() - 1));
-    }
-
-    #[test]
-    fn after() {
-        let input = b"Hello";
-        let index = input.len();
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, input.len()));
-    }
-
-    #[test]
-    fn first_line() {
-        let input = b"Hello\nWorld\n";
-        let index = 2;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, 2));
-    }
-
-    #[test]
-    fn end_of_line() {
-        let input = b"Hello\nWorld\n";
-        let index = 5;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, 5));
-    }
-
-    #[test]
-    fn start_of_second_line() {
-        let input = b"Hello\nWorld\n";
-        let index = 6;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (1, 0));
-    }
-
-    #[test]
-    fn second_line() {
-        let input = b"Hello\nWorld\n";
-        let index = 8;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (1, 2));
-    }
-}
@@ -10,6 +10,8 @@ mod deserializer;
 mod error;
 #[cfg(feature = "parse")]
 mod parser;
+#[cfg(feature = "serde")]
+mod raw;
 
 #[cfg(feature = "parse")]
 #[cfg(feature = "serde")]
@@ -29,10 +31,24 @@ pub use parser::DeString;
 pub use parser::DeTable;
 #[cfg(feature = "parse")]
 pub use parser::DeValue;
+#[cfg(feature = "serde")]
+pub use raw::RawValue;
+#[cfg(feature = "serde")]
+#[cfg(any(feature = "parse", feature = "display"))]
+pub(crate) use raw::is_raw_value;
 
 pub use error::Error;
-
-use crate::alloc_prelude::*;
+pub use error::PathSegment;
+#[cfg(feature = "parse")]
+pub use toml_parser::Diagnostic;
+#[cfg(feature = "parse")]
+pub use toml_parser::Label;
+#[cfg(feature = "parse")]
+pub use toml_parser::LineCol;
+#[cfg(feature = "parse")]
+pub use toml_parser::LineIndex;
+#[cfg(feature = "parse")]
+pub use toml_parser::embed;
 
 /// Deserializes a string into a type.
 ///
@@ -81,6 +97,9 @@ where
 /// This function will attempt to interpret `s` as a TOML document and
 /// deserialize `T` from the document.
 ///
+/// UTF-16 is transcoded when a byte order mark says so, see [`toml_parser::decode_bytes`].
+/// Spans are then relative to the transcoded UTF-8 document.
+///
 /// To deserializes TOML values, instead of documents, see [`ValueDeserializer`].
 #[cfg(feature = "parse")]
 #[cfg(feature = "serde")]
@@ -88,6 +107,14 @@ pub fn from_slice<'de, T>(s: &'de [u8]) -> Result<T, Error>
 where
     T: serde_core::de::Deserialize<'de>,
 {
-    let s = core::str::from_utf8(s).map_err(|e| Error::custom(e.to_string(), None))?;
-    from_str(s)
+    let s = toml_parser::decode_bytes(s).map_err(|e| Error::from_bytes(s, e))?;
+    match s {
+        alloc::borrow::Cow::Borrowed(s) => from_str(s),
+        // `T` may not borrow from the transcoded document
+        alloc::borrow::Cow::Owned(s) => T::deserialize(Deserializer::parse(&s)?.into_owned())
+            .map_err(|mut e| {
+                e.set_input(Some(&s));
+                e
+            }),
+    }
 }
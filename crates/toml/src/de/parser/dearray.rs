@@ -32,6 +32,22 @@ impl<'i> DeArray<'i> {
 }
 
 impl DeArray<'_> {
+    /// Copy any borrowed data, releasing the lifetime
+    pub(crate) fn into_static(self) -> DeArray<'static> {
+        let items = self
+            .items
+            .into_iter()
+            .map(|v| {
+                let span = v.span();
+                Spanned::new(span, v.into_inner().into_static())
+            })
+            .collect();
+        DeArray {
+            items,
+            array_of_tables: self.array_of_tables,
+        }
+    }
+
     pub(crate) fn is_array_of_tables(&self) -> bool {
         self.array_of_tables
     }
@@ -18,8 +18,39 @@ pub type DeTable<'i> = Map<Spanned<DeString<'i>>, Spanned<DeValue<'i>>>;
 impl<'i> DeTable<'i> {
     /// Parse a TOML document
     pub fn parse(input: &'i str) -> Result<Spanned<Self>, crate::de::Error> {
-        let source = toml_parser::Source::new(input);
-        let mut errors = crate::de::error::TomlSink::<Option<_>>::new(source);
+        Self::parse_embedded(input, 0..input.len())
+    }
+
+    /// Parse a TOML document, with best effort recovery on error
+    pub fn parse_recoverable(input: &'i str) -> (Spanned<Self>, Vec<crate::de::Error>) {
+        Self::parse_embedded_recoverable(input, 0..input.len())
+    }
+
+    /// Parse a TOML document embedded at `span` within `host`, like front matter
+    ///
+    /// Spans, including those in errors, are relative to `host`.
+    /// For locating embedded TOML, see [`embed`][crate::de::embed].
+    ///
+    /// # Panics
+    ///
+    /// If `span` is out of bounds or not on `char` boundaries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use toml::de::DeTable;
+    /// let host = "+++\ntitle = 'Hello'\n+++\n# Hello\n";
+    /// let table = DeTable::parse_embedded(host, 4..20).unwrap();
+    /// let (key, value) = table.get_ref().iter().next().unwrap();
+    /// assert_eq!(&host[key.span()], "title");
+    /// assert_eq!(&host[value.span()], "'Hello'");
+    /// ```
+    pub fn parse_embedded(
+        host: &'i str,
+        span: core::ops::Range<usize>,
+    ) -> Result<Spanned<Self>, crate::de::Error> {
+        let source = toml_parser::Source::new(&host[span.clone()]).with_offset(span.start);
+        let mut errors = crate::de::error::TomlSink::<Option<_>>::new(host);
         let value = crate::de::parser::parse_document(source, &mut errors);
         if let Some(err) = errors.into_inner() {
             Err(err)
@@ -28,14 +59,36 @@ impl<'i> DeTable<'i> {
         }
     }
 
-    /// Parse a TOML document, with best effort recovery on error
-    pub fn parse_recoverable(input: &'i str) -> (Spanned<Self>, Vec<crate::de::Error>) {
-        let source = toml_parser::Source::new(input);
-        let mut errors = crate::de::error::TomlSink::<Vec<_>>::new(source);
+    /// Parse a TOML document embedded at `span` within `host`, with best effort recovery on error
+    ///
+    /// See [`DeTable::parse_embedded`]
+    ///
+    /// # Panics
+    ///
+    /// If `span` is out of bounds or not on `char` boundaries
+    pub fn parse_embedded_recoverable(
+        host: &'i str,
+        span: core::ops::Range<usize>,
+    ) -> (Spanned<Self>, Vec<crate::de::Error>) {
+        let source = toml_parser::Source::new(&host[span.clone()]).with_offset(span.start);
+        let mut errors = crate::de::error::TomlSink::<Vec<_>>::new(host);
         let value = crate::de::parser::parse_document(source, &mut errors);
         (value, errors.into_inner())
     }
 
+    /// Copy any borrowed data, releasing the lifetime
+    pub(crate) fn into_static(self) -> DeTable<'static> {
+        self.into_iter()
+            .map(|(k, v)| {
+                let k_span = k.span();
+                let k = Cow::Owned(k.into_inner().into_owned());
+                let v_span = v.span();
+                let v = v.into_inner().into_static();
+                (Spanned::new(k_span, k), Spanned::new(v_span, v))
+            })
+            .collect()
+    }
+
     /// Ensure no data is borrowed
     pub fn make_owned(&mut self) {
         self.mut_entries(|k, v| {
@@ -6,6 +6,7 @@ use core::ops;
 
 use serde_spanned::Spanned;
 use toml_datetime::Datetime;
+use toml_datetime::LosslessDatetime;
 
 use crate::alloc_prelude::*;
 use crate::de::DeArray;
@@ -126,8 +127,8 @@ pub enum DeValue<'i> {
     Float(DeFloat<'i>),
     /// Represents a TOML boolean
     Boolean(bool),
-    /// Represents a TOML datetime
-    Datetime(Datetime),
+    /// Represents a TOML datetime, keeping fractional seconds as written
+    Datetime(LosslessDatetime),
     /// Represents a TOML array
     Array(DeArray<'i>),
     /// Represents a TOML table
@@ -138,7 +139,7 @@ impl<'i> DeValue<'i> {
     /// Parse a TOML value
     pub fn parse(input: &'i str) -> Result<Spanned<Self>, crate::de::Error> {
         let source = toml_parser::Source::new(input);
-        let mut errors = crate::de::error::TomlSink::<Option<_>>::new(source);
+        let mut errors = crate::de::error::TomlSink::<Option<_>>::new(input);
         let value = crate::de::parser::parse_value(source, &mut errors);
         if let Some(err) = errors.into_inner() {
             Err(err)
@@ -150,11 +151,29 @@ impl<'i> DeValue<'i> {
     /// Parse a TOML value, with best effort recovery on error
     pub fn parse_recoverable(input: &'i str) -> (Spanned<Self>, Vec<crate::de::Error>) {
         let source = toml_parser::Source::new(input);
-        let mut errors = crate::de::error::TomlSink::<Vec<_>>::new(source);
+        let mut errors = crate::de::error::TomlSink::<Vec<_>>::new(input);
         let value = crate::de::parser::parse_value(source, &mut errors);
         (value, errors.into_inner())
     }
 
+    /// Copy any borrowed data, releasing the lifetime
+    pub(crate) fn into_static(self) -> DeValue<'static> {
+        match self {
+            DeValue::String(v) => DeValue::String(Cow::Owned(v.into_owned())),
+            DeValue::Integer(v) => DeValue::Integer(DeInteger {
+                inner: Cow::Owned(v.inner.into_owned()),
+                radix: v.radix,
+            }),
+            DeValue::Float(v) => DeValue::Float(DeFloat {
+                inner: Cow::Owned(v.inner.into_owned()),
+            }),
+            DeValue::Boolean(v) => DeValue::Boolean(v),
+            DeValue::Datetime(v) => DeValue::Datetime(v),
+            DeValue::Array(v) => DeValue::Array(v.into_static()),
+            DeValue::Table(v) => DeValue::Table(v.into_static()),
+        }
+    }
+
     /// Ensure no data is borrowed
     pub fn make_owned(&mut self) {
         match self {
@@ -249,7 +268,7 @@ impl<'i> DeValue<'i> {
     /// ```
     pub fn as_datetime(&self) -> Option<&Datetime> {
         match *self {
-            DeValue::Datetime(ref s) => Some(s),
+            DeValue::Datetime(ref s) => Some(s.datetime()),
             _ => None,
         }
     }
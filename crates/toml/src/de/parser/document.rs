@@ -237,7 +237,11 @@ impl<'i> State<'i> {
                 anstyle::AnsiColor::Red.on_default(),
             );
             let key_span = get_key_span(&key);
-            errors.report_error(ParseError::new("duplicate key").with_unexpected(key_span));
+            errors.report_error(
+                ParseError::new("duplicate key")
+                    .with_unexpected(key_span)
+                    .with_kind(ParseErrorKind::TableRedefinition),
+            );
             return;
         }
         let key_span = get_key_span(&key);
@@ -251,7 +255,8 @@ impl<'i> State<'i> {
                 errors.report_error(
                     ParseError::new("duplicate key")
                         .with_unexpected(key_span)
-                        .with_context(old_span),
+                        .with_context(old_span)
+                        .with_kind(ParseErrorKind::DuplicateKey),
                 );
             }
         }
@@ -301,7 +306,8 @@ impl<'i> State<'i> {
                     errors.report_error(
                         ParseError::new("duplicate key")
                             .with_unexpected(key_span)
-                            .with_context(old_span),
+                            .with_context(old_span)
+                            .with_kind(ParseErrorKind::TableRedefinition),
                     );
                     return;
                 };
@@ -351,7 +357,8 @@ impl<'i> State<'i> {
                             errors.report_error(
                                 ParseError::new("duplicate key")
                                     .with_unexpected(key_span)
-                                    .with_context(old_span),
+                                    .with_context(old_span)
+                                    .with_kind(ParseErrorKind::TableRedefinition),
                             );
 
                             if let DeValue::Table(t) = old_value {
@@ -417,7 +424,8 @@ fn descend_path<'t, 'i>(
                                     "cannot extend value of type array with a dotted key",
                                 )
                                 .with_unexpected(key_span)
-                                .with_context(old_span),
+                                .with_context(old_span)
+                                .with_kind(ParseErrorKind::TableRedefinition),
                             );
                             return None;
                         }
@@ -439,7 +447,8 @@ fn descend_path<'t, 'i>(
                                         existing.type_str()
                                     ))
                                     .with_unexpected(key_span)
-                                    .with_context(old_span),
+                                    .with_context(old_span)
+                                    .with_kind(ParseErrorKind::TableRedefinition),
                                 );
                                 return None;
                             }
@@ -452,7 +461,8 @@ fn descend_path<'t, 'i>(
                                 ParseError::new(
                                     "cannot extend value of type inline table with a dotted key",
                                 )
-                                .with_unexpected(key_span),
+                                .with_unexpected(key_span)
+                                .with_kind(ParseErrorKind::TableRedefinition),
                             );
                             return None;
                         }
@@ -482,7 +492,9 @@ fn descend_path<'t, 'i>(
                             );
                             let key_span = get_key_span(key);
                             errors.report_error(
-                                ParseError::new("duplicate key").with_unexpected(key_span),
+                                ParseError::new("duplicate key")
+                                    .with_unexpected(key_span)
+                                    .with_kind(ParseErrorKind::TableRedefinition),
                             );
                             return None;
                         }
@@ -498,7 +510,8 @@ fn descend_path<'t, 'i>(
                                 existing.type_str()
                             ))
                             .with_unexpected(key_span)
-                            .with_context(old_span),
+                            .with_context(old_span)
+                            .with_kind(ParseErrorKind::TableRedefinition),
                         );
                         return None;
                     }
@@ -155,7 +155,11 @@ impl<'i> State<'i> {
                     anstyle::AnsiColor::Red.on_default(),
                 );
                 let key_span = get_key_span(&key);
-                errors.report_error(ParseError::new("duplicate key").with_unexpected(key_span));
+                errors.report_error(
+                    ParseError::new("duplicate key")
+                        .with_unexpected(key_span)
+                        .with_kind(ParseErrorKind::TableRedefinition),
+                );
             } else {
                 let key_span = get_key_span(&key);
                 match table.entry(key) {
@@ -167,7 +171,8 @@ impl<'i> State<'i> {
                         errors.report_error(
                             ParseError::new("duplicate key")
                                 .with_unexpected(key_span)
-                                .with_context(old_span),
+                                .with_context(old_span)
+                                .with_kind(ParseErrorKind::DuplicateKey),
                         );
                     }
                 }
@@ -244,7 +249,9 @@ fn descend_path<'a, 'i>(
                             );
                             let key_span = get_key_span(key);
                             errors.report_error(
-                                ParseError::new("duplicate key").with_unexpected(key_span),
+                                ParseError::new("duplicate key")
+                                    .with_unexpected(key_span)
+                                    .with_kind(ParseErrorKind::TableRedefinition),
                             );
                             return None;
                         }
@@ -260,7 +267,8 @@ fn descend_path<'a, 'i>(
                                 existing.type_str()
                             ))
                             .with_unexpected(key_span)
-                            .with_context(old_span),
+                            .with_context(old_span)
+                            .with_kind(ParseErrorKind::TableRedefinition),
                         );
                         return None;
                     }
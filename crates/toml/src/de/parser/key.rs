@@ -65,7 +65,9 @@ pub(crate) fn on_key<'i>(
 
     #[cfg(not(feature = "unbounded"))]
     if super::LIMIT <= result_path.len() as u32 {
-        errors.report_error(ParseError::new("recursion limit"));
+        errors.report_error(
+            ParseError::new("recursion limit").with_kind(ParseErrorKind::RecursionLimit),
+        );
         return (Vec::new(), None);
     }
 
@@ -73,6 +73,7 @@ const LIMIT: u32 = 80;
 pub(crate) mod prelude {
     pub(crate) use toml_parser::ErrorSink;
     pub(crate) use toml_parser::ParseError;
+    pub(crate) use toml_parser::ParseErrorKind;
     pub(crate) use toml_parser::parser::EventKind;
     pub(crate) use winnow::stream::Stream as _;
 
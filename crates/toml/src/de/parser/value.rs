@@ -82,17 +82,20 @@ pub(crate) fn on_scalar<'i>(
             Spanned::new(value_span, DeValue::Boolean(value))
         }
         toml_parser::decoder::ScalarKind::DateTime => {
-            let value = match decoded.parse::<toml_datetime::Datetime>() {
+            let value = match decoded.parse::<toml_datetime::LosslessDatetime>() {
                 Ok(value) => value,
                 Err(err) => {
-                    errors.report_error(
-                        ParseError::new(err.to_string()).with_unexpected(event.span()),
-                    );
+                    let mut error = ParseError::new(err.to_string()).with_unexpected(event.span());
+                    if let Some(fix) = raw.datetime_padding_fix() {
+                        error = error.with_fix(fix);
+                    }
+                    errors.report_error(error);
                     toml_datetime::Datetime {
                         date: None,
                         time: None,
                         offset: None,
                     }
+                    .into()
                 }
             };
             Spanned::new(value_span, DeValue::Datetime(value))
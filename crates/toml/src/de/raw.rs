@@ -0,0 +1,224 @@
+use alloc::borrow::Cow;
+
+use serde_spanned::Spanned;
+
+use crate::alloc_prelude::*;
+
+const NAME: &str = "$__toml_private_RawValue";
+
+#[cfg(any(feature = "parse", feature = "display"))]
+pub(crate) fn is_raw_value(name: &'static str) -> bool {
+    name == NAME
+}
+
+/// The unparsed source text of a TOML value
+///
+/// When deserialized, this captures the value's text from the document without interpreting it,
+/// so it can be deserialized later with [`RawValue::deserialize_into`], e.g. to hand a plugin its
+/// own configuration.
+/// When serialized with [`toml::to_string`][crate::to_string], the text is written unchanged.
+///
+/// Inline values, like strings, arrays, and inline tables, are captured verbatim.
+/// Standard tables and arrays of tables are captured as the equivalent inline table or array,
+/// keeping the text of their keys and values.
+///
+/// This borrows from the source when possible.
+/// For a value that outlives the source, see [`RawValue::deserialize_owned`].
+///
+/// This requires the source text, so it is only supported by
+/// [`Deserializer`][crate::de::Deserializer] and [`ValueDeserializer`][crate::de::ValueDeserializer].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "parse")] {
+/// # #[cfg(feature = "display")] {
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Plugin<'a> {
+///     name: String,
+///     #[serde(borrow)]
+///     config: toml::de::RawValue<'a>,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     verbose: bool,
+/// }
+///
+/// let input = r#"
+/// name = "lint"
+/// config = { verbose = true }
+/// "#;
+/// let plugin: Plugin<'_> = toml::from_str(input).unwrap();
+/// assert_eq!(plugin.config.get(), "{ verbose = true }");
+///
+/// let config: Config = plugin.config.deserialize_into().unwrap();
+/// assert!(config.verbose);
+/// # }
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RawValue<'i> {
+    raw: Cow<'i, str>,
+    span: core::ops::Range<usize>,
+}
+
+impl<'i> RawValue<'i> {
+    /// Parse the source text of a TOML value
+    ///
+    /// This validates `raw` without keeping the parsed value.
+    #[cfg(feature = "parse")]
+    pub fn parse(raw: &'i str) -> Result<Self, crate::de::Error> {
+        use serde_core::Deserialize as _;
+
+        Self::deserialize(crate::de::ValueDeserializer::parse(raw)?)
+    }
+
+    /// The source text of the value
+    pub fn get(&self) -> &str {
+        &self.raw
+    }
+
+    /// The byte range of the value in the source
+    ///
+    /// For standard tables and arrays of tables, this is the span of their header.
+    pub fn span(&self) -> core::ops::Range<usize> {
+        self.span.clone()
+    }
+
+    /// Copy any borrowed text, releasing the lifetime
+    pub fn into_owned(self) -> RawValue<'static> {
+        RawValue {
+            raw: Cow::Owned(self.raw.into_owned()),
+            span: self.span,
+        }
+    }
+
+    /// Deserialize the value into `T`
+    ///
+    /// Spans in errors are relative to [`RawValue::get`].
+    #[cfg(feature = "parse")]
+    pub fn deserialize_into<'de, T>(&'de self) -> Result<T, crate::de::Error>
+    where
+        T: serde_core::Deserialize<'de>,
+    {
+        T::deserialize(crate::de::ValueDeserializer::parse(self.get())?)
+    }
+
+    /// Deserialize a `RawValue` that does not borrow from the source
+    ///
+    /// This is intended for `#[serde(deserialize_with = "toml::de::RawValue::deserialize_owned")]`.
+    pub fn deserialize_owned<'de, D>(deserializer: D) -> Result<RawValue<'static>, D::Error>
+    where
+        D: serde_core::Deserializer<'de>,
+    {
+        use serde_core::Deserialize as _;
+
+        RawValue::deserialize(deserializer).map(RawValue::into_owned)
+    }
+}
+
+impl core::fmt::Display for RawValue<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.raw.fmt(f)
+    }
+}
+
+impl<'de: 'i, 'i> serde_core::Deserialize<'de> for RawValue<'i> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde_core::Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(
+            NAME,
+            RawValueVisitor {
+                marker: core::marker::PhantomData,
+            },
+        )
+    }
+}
+
+impl serde_core::Serialize for RawValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::Serializer,
+    {
+        serializer.serialize_newtype_struct(NAME, self.get())
+    }
+}
+
+struct RawValueVisitor<'i> {
+    marker: core::marker::PhantomData<RawValue<'i>>,
+}
+
+impl<'de: 'i, 'i> serde_core::de::Visitor<'de> for RawValueVisitor<'i> {
+    type Value = RawValue<'i>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a TOML value with its source text")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde_core::de::MapAccess<'de>,
+    {
+        use serde_core::Deserialize as _;
+
+        let raw = Spanned::<RawText<'i>>::deserialize(
+            serde_core::de::value::MapAccessDeserializer::new(map),
+        )?;
+        let span = raw.span();
+        Ok(RawValue {
+            raw: raw.into_inner().0,
+            span,
+        })
+    }
+}
+
+struct RawText<'i>(Cow<'i, str>);
+
+impl<'de: 'i, 'i> serde_core::Deserialize<'de> for RawText<'i> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde_core::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(RawTextVisitor {
+            marker: core::marker::PhantomData,
+        })
+    }
+}
+
+struct RawTextVisitor<'i> {
+    marker: core::marker::PhantomData<RawText<'i>>,
+}
+
+impl<'de: 'i, 'i> serde_core::de::Visitor<'de> for RawTextVisitor<'i> {
+    type Value = RawText<'i>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        Ok(RawText(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        Ok(RawText(Cow::Owned(v.to_owned())))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        Ok(RawText(Cow::Owned(v)))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::alloc_prelude::*;
+
+/// What went wrong, for handling [`de::Error`][crate::de::Error]s and
+/// [`ser::Error`][crate::ser::Error]s without matching on their messages
+///
+/// Spans are byte ranges into [`de::Error::input`][crate::de::Error::input].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The TOML is malformed
+    Syntax,
+    /// A string has an invalid escape sequence
+    InvalidEscape,
+    /// Values or keys are nested deeper than allowed
+    RecursionLimit,
+    /// A key is defined more than once
+    DuplicateKey {
+        /// The key being redefined
+        key: String,
+        /// Where the key was first defined, if known
+        first_span: Option<core::ops::Range<usize>>,
+    },
+    /// A table is defined more than once, or is extended after being defined as another value
+    TableRedefinition {
+        /// The key of the table
+        key: String,
+        /// Where the key was first defined, if known
+        original_span: Option<core::ops::Range<usize>>,
+    },
+    /// A value has a different type than expected
+    InvalidType {
+        /// What was expected
+        expected: String,
+        /// The type that was found
+        found: String,
+    },
+    /// A value has the expected type but not an expected value
+    InvalidValue {
+        /// What was expected
+        expected: String,
+        /// The value that was found
+        found: String,
+    },
+    /// An array or table has the wrong number of elements
+    InvalidLength {
+        /// The number of elements found
+        len: usize,
+        /// What was expected
+        expected: String,
+    },
+    /// An enum variant is not recognized
+    UnknownVariant {
+        /// The variant that was found
+        variant: String,
+        /// The variants that are recognized
+        expected: &'static [&'static str],
+    },
+    /// A struct field is not recognized
+    UnknownField {
+        /// The field that was found
+        field: String,
+        /// The fields that are recognized
+        expected: &'static [&'static str],
+    },
+    /// A struct field is missing
+    MissingField {
+        /// The missing field
+        field: &'static str,
+    },
+    /// A struct field is present more than once
+    DuplicateField {
+        /// The repeated field
+        field: &'static str,
+    },
+    /// A Rust type cannot be represented in TOML
+    UnsupportedType(Option<&'static str>),
+    /// `None` cannot be represented in TOML
+    UnsupportedNone,
+    /// A map key is not a string
+    KeyNotString,
+    /// A value is out of range for TOML
+    OutOfRange(Option<&'static str>),
+    /// A serialized datetime is invalid
+    DateInvalid,
+    /// Any other error, see its message
+    Custom,
+}
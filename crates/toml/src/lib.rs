@@ -163,6 +163,7 @@ pub(crate) mod alloc_prelude {
     pub(crate) use alloc::vec::Vec;
 }
 
+mod error;
 pub mod map;
 #[cfg(feature = "serde")]
 pub mod value;
@@ -189,7 +190,9 @@ pub use crate::ser::{Serializer, to_string, to_string_pretty};
 #[doc(inline)]
 #[cfg(feature = "serde")]
 pub use crate::value::Value;
+pub use error::ErrorKind;
 pub use serde_spanned::Spanned;
+pub use serde_spanned::SpannedEntry;
 #[cfg(feature = "serde")]
 pub use table::Table;
 
@@ -0,0 +1,248 @@
+//! Comments written alongside values
+
+use toml_writer::TomlWrite as _;
+
+use super::CommentedSerializer;
+use super::Comments;
+use super::is_commented;
+
+/// Write the comment lines that go above a value
+pub(crate) fn write_comment<W>(comments: &Comments, dst: &mut W) -> core::fmt::Result
+where
+    W: core::fmt::Write,
+{
+    let Some(comment) = comments.comment() else {
+        return Ok(());
+    };
+    for line in comment.lines() {
+        dst.open_comment()?;
+        if !line.is_empty() {
+            dst.space()?;
+            write!(dst, "{line}")?;
+        }
+        dst.newline()?;
+    }
+    Ok(())
+}
+
+/// Write the comment that goes after a value, on the same line
+pub(crate) fn write_trailing_comment<W>(comments: &Comments, dst: &mut W) -> core::fmt::Result
+where
+    W: core::fmt::Write,
+{
+    let Some(comment) = comments.trailing_comment() else {
+        return Ok(());
+    };
+    dst.space()?;
+    dst.open_comment()?;
+    if !comment.is_empty() {
+        dst.space()?;
+        write!(dst, "{comment}")?;
+    }
+    Ok(())
+}
+
+/// Find the comments of a top-level [`Commented`][super::Commented], looking through other
+/// newtype structs
+pub(crate) fn comments<T>(value: &T) -> Comments
+where
+    T: serde_core::ser::Serialize + ?Sized,
+{
+    match value.serialize(WalkComments) {
+        Ok(never) => match never {},
+        Err(FoundComments(comments)) => comments,
+    }
+}
+
+/// Find the comments of a value, see [`comments`]
+struct WalkComments;
+
+/// Stops [`WalkComments`] with the comments it found
+#[derive(Default, Debug)]
+struct FoundComments(Comments);
+
+impl serde_core::ser::Error for FoundComments {
+    fn custom<T>(_msg: T) -> Self
+    where
+        T: core::fmt::Display,
+    {
+        Self::default()
+    }
+}
+
+impl core::fmt::Display for FoundComments {
+    fn fmt(&self, _f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl core::error::Error for FoundComments {}
+
+impl serde_core::ser::Serializer for WalkComments {
+    type Ok = core::convert::Infallible;
+    type Error = FoundComments;
+    type SerializeSeq = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeStructVariant = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_some<T>(self, v: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        v.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, v: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        if is_commented(name) {
+            return v.serialize(CommentedSerializer::new(|comments| {
+                Err::<Self, _>(FoundComments(comments))
+            }));
+        }
+        v.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(FoundComments::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(FoundComments::default())
+    }
+}
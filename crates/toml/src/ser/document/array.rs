@@ -6,13 +6,17 @@ use super::Buffer;
 use super::Error;
 use super::Table;
 use super::style::Style;
+use super::value::SerializeLayoutArray;
 use super::value::ValueSerializer;
+use super::value::escaped_key;
+use super::value::write_layout;
 
 #[doc(hidden)]
 pub struct SerializeDocumentTupleVariant<'d> {
     buf: &'d mut Buffer,
     table: Table,
     seen_value: bool,
+    layout: Option<SerializeLayoutArray>,
     style: Style,
 }
 
@@ -21,19 +25,25 @@ impl<'d> SerializeDocumentTupleVariant<'d> {
         buf: &'d mut Buffer,
         mut table: Table,
         variant: &'static str,
-        _len: usize,
+        len: usize,
         style: Style,
     ) -> Result<Self, Error> {
         let dst = table.body_mut();
-        dst.key(variant)?;
+        dst.key(escaped_key(variant, style.escapes))?;
         dst.space()?;
         dst.keyval_sep()?;
         dst.space()?;
-        dst.open_array()?;
+        let layout = style
+            .layout
+            .map(|_| SerializeLayoutArray::new(style, Some(len), None));
+        if layout.is_none() {
+            dst.open_array()?;
+        }
         Ok(Self {
             buf,
             table,
             seen_value: false,
+            layout,
             style,
         })
     }
@@ -47,6 +57,10 @@ impl<'d> serde_core::ser::SerializeTupleVariant for SerializeDocumentTupleVarian
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
+        if let Some(layout) = &mut self.layout {
+            return layout.push(value);
+        }
+
         let dst = self.table.body_mut();
 
         if self.style.multiline_array {
@@ -68,10 +82,14 @@ impl<'d> serde_core::ser::SerializeTupleVariant for SerializeDocumentTupleVarian
 
     fn end(mut self) -> Result<Self::Ok, Self::Error> {
         let dst = self.table.body_mut();
-        if self.style.multiline_array && self.seen_value {
-            dst.newline()?;
+        if let (Some(array), Some(layout)) = (self.layout, self.style.layout) {
+            write_layout(dst, &array.end(), layout)?;
+        } else {
+            if self.style.multiline_array && self.seen_value {
+                dst.newline()?;
+            }
+            dst.close_array()?;
         }
-        dst.close_array()?;
         dst.newline()?;
         self.buf.push(self.table);
         Ok(self.buf)
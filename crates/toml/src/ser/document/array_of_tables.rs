@@ -1,15 +1,20 @@
 use super::Buffer;
+use super::CommentedSerializer;
 use super::Error;
 use super::Serializer;
 use super::Table;
+use super::hint;
+use super::is_commented;
 use super::style::Style;
 use crate::alloc_prelude::*;
+use crate::ser::Comments;
 
 pub(crate) struct ArrayOfTablesSerializer<'d> {
     buf: &'d mut Buffer,
     parent: Table,
     key: String,
     style: Style,
+    comments: Comments,
 }
 
 impl<'d> ArrayOfTablesSerializer<'d> {
@@ -23,6 +28,7 @@ impl<'d> ArrayOfTablesSerializer<'d> {
             parent,
             key,
             style,
+            comments: Comments::default(),
         }
     }
 }
@@ -123,13 +129,23 @@ impl<'d> serde_core::ser::Serializer for ArrayOfTablesSerializer<'d> {
     }
 
     fn serialize_newtype_struct<T>(
-        self,
-        _name: &'static str,
+        mut self,
+        name: &'static str,
         v: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
+        if let Some(hint) = hint(name) {
+            self.style = self.style.with_hint(hint);
+        }
+        if is_commented(name) {
+            // Written with the header of the first table
+            return v.serialize(CommentedSerializer::new(move |comments| {
+                self.comments.merge(comments);
+                Ok(self)
+            }));
+        }
         v.serialize(self)
     }
 
@@ -147,12 +163,10 @@ impl<'d> serde_core::ser::Serializer for ArrayOfTablesSerializer<'d> {
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        Ok(SerializeArrayOfTablesSerializer::seq(
-            self.buf,
-            self.parent,
-            self.key,
-            self.style,
-        ))
+        let mut seq =
+            SerializeArrayOfTablesSerializer::seq(self.buf, self.parent, self.key, self.style);
+        seq.comments = self.comments;
+        Ok(seq)
     }
 
     fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
@@ -206,6 +220,7 @@ pub(crate) struct SerializeArrayOfTablesSerializer<'d> {
     parent: Table,
     key: String,
     style: Style,
+    comments: Comments,
 }
 
 impl<'d> SerializeArrayOfTablesSerializer<'d> {
@@ -215,6 +230,7 @@ impl<'d> SerializeArrayOfTablesSerializer<'d> {
             parent,
             key,
             style,
+            comments: Comments::default(),
         }
     }
 
@@ -231,7 +247,8 @@ impl<'d> serde_core::ser::SerializeSeq for SerializeArrayOfTablesSerializer<'d>
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
-        let child = self.buf.element_table(&mut self.parent, self.key.clone());
+        let mut child = self.buf.element_table(&mut self.parent, self.key.clone());
+        child.add_comments(core::mem::take(&mut self.comments));
         let value_serializer = Serializer::with_table(self.buf, child, self.style);
         value.serialize(value_serializer)?;
         Ok(())
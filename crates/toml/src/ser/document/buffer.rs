@@ -1,6 +1,9 @@
 use toml_writer::TomlWrite as _;
 
 use crate::alloc_prelude::*;
+use crate::ser::Comments;
+use crate::ser::comment::write_comment;
+use crate::ser::comment::write_trailing_comment;
 
 /// TOML Document serialization buffer
 #[derive(Debug, Default)]
@@ -41,6 +44,7 @@ impl Buffer {
         let table = Table {
             key,
             body: String::new(),
+            comments: Comments::default(),
             has_children: false,
             pos,
             array: false,
@@ -75,9 +79,9 @@ impl core::fmt::Display for Buffer {
 
 fn required_table(table: &Table) -> bool {
     if table.key.is_none() {
-        !table.body.is_empty()
+        !table.body.is_empty() || !table.comments.is_empty()
     } else {
-        table.array || !table.body.is_empty() || !table.has_children
+        table.array || !table.body.is_empty() || !table.comments.is_empty() || !table.has_children
     }
 }
 
@@ -85,6 +89,7 @@ fn required_table(table: &Table) -> bool {
 pub(crate) struct Table {
     key: Option<Vec<String>>,
     body: String,
+    comments: Comments,
     has_children: bool,
     array: bool,
     pos: usize,
@@ -98,10 +103,20 @@ impl Table {
     pub(crate) fn has_children(&mut self, yes: bool) {
         self.has_children = yes;
     }
+
+    /// Add comments for the table header, or the top of the document for the root table
+    pub(crate) fn add_comments(&mut self, mut comments: Comments) {
+        if self.key.is_none() {
+            // Without a header, the trailing comment gets its own line
+            comments = comments.trailing_as_comment();
+        }
+        self.comments.merge(comments);
+    }
 }
 
 impl core::fmt::Display for Table {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_comment(&self.comments, f)?;
         if let Some(key) = &self.key {
             if self.array {
                 f.open_array_of_tables_header()?;
@@ -121,6 +136,7 @@ impl core::fmt::Display for Table {
             } else {
                 f.close_table_header()?;
             }
+            write_trailing_comment(&self.comments, f)?;
             f.newline()?;
         }
 
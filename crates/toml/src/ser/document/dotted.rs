@@ -0,0 +1,341 @@
+use core::fmt::Write as _;
+
+use toml_writer::TomlWrite as _;
+
+use super::CommentedSerializer;
+use super::Error;
+use super::SerializationStrategy;
+use super::comments;
+use super::hint;
+use super::is_commented;
+use super::style::Style;
+use super::value::KeySerializer;
+use super::value::write_value;
+use super::write_comment;
+use super::write_trailing_comment;
+use crate::alloc_prelude::*;
+use crate::ser::Comments;
+
+/// Serialize a table as dotted keys into the body of its parent table
+pub(crate) struct DottedSerializer<'d> {
+    dst: &'d mut String,
+    prefix: String,
+    style: Style,
+}
+
+impl<'d> DottedSerializer<'d> {
+    /// `prefix` is the encoded key of the table within its parent
+    pub(crate) fn new(dst: &'d mut String, prefix: String, style: Style) -> Self {
+        Self { dst, prefix, style }
+    }
+
+    fn table(self) -> SerializeDottedTable<'d> {
+        SerializeDottedTable {
+            dst: self.dst,
+            prefix: self.prefix,
+            key: None,
+            style: self.style,
+            is_empty: true,
+        }
+    }
+}
+
+impl<'d> serde_core::ser::Serializer for DottedSerializer<'d> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = serde_core::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = SerializeDottedTable<'d>;
+    type SerializeStruct = SerializeDottedTable<'d>;
+    type SerializeStructVariant = SerializeDottedTable<'d>;
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("bool")))
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("i8")))
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("i16")))
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("i32")))
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("i64")))
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("u8")))
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("u16")))
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("u32")))
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("u64")))
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("f32")))
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("f64")))
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("char")))
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("str")))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("bytes")))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_none())
+    }
+
+    fn serialize_some<T>(self, v: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        v.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some("unit")))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some(name)))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported_type(Some(name)))
+    }
+
+    fn serialize_newtype_struct<T>(
+        mut self,
+        name: &'static str,
+        v: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        if let Some(hint) = hint(name) {
+            self.style = self.style.with_hint(hint);
+        }
+        if is_commented(name) {
+            // There is no line for a trailing comment
+            return v.serialize(CommentedSerializer::new(|comments: Comments| {
+                write_comment(&comments.trailing_as_comment(), self.dst)?;
+                Ok(self)
+            }));
+        }
+        v.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        let mut table = self.table();
+        serde_core::ser::SerializeMap::serialize_entry(&mut table, variant, value)?;
+        serde_core::ser::SerializeMap::end(table)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(Error::unsupported_type(Some("array")))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(Error::unsupported_type(Some(variant)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(self.table())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(self.table())
+    }
+
+    fn serialize_struct_variant(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.prefix.key_sep()?;
+        serde_core::ser::Serialize::serialize(
+            variant,
+            KeySerializer {
+                dst: &mut self.prefix,
+                escapes: self.style.escapes,
+            },
+        )?;
+        Ok(self.table())
+    }
+}
+
+#[doc(hidden)]
+pub(crate) struct SerializeDottedTable<'d> {
+    dst: &'d mut String,
+    prefix: String,
+    key: Option<String>,
+    style: Style,
+    is_empty: bool,
+}
+
+impl serde_core::ser::SerializeMap for SerializeDottedTable<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, input: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        let mut encoded_key = self.prefix.clone();
+        encoded_key.key_sep()?;
+        input.serialize(KeySerializer {
+            dst: &mut encoded_key,
+            escapes: self.style.escapes,
+        })?;
+        self.key = Some(encoded_key);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        let encoded_key = self
+            .key
+            .take()
+            .expect("always called after `serialize_key`");
+        match SerializationStrategy::from(value) {
+            SerializationStrategy::Value | SerializationStrategy::ArrayOfTables => {
+                let comments = comments(value);
+                let dst = &mut *self.dst;
+                write_comment(&comments, dst)?;
+                write!(dst, "{encoded_key}")?;
+                dst.space()?;
+                dst.keyval_sep()?;
+                dst.space()?;
+                write_value(dst, value, self.style)?;
+                write_trailing_comment(&comments, dst)?;
+                dst.newline()?;
+            }
+            SerializationStrategy::Table
+            | SerializationStrategy::Dotted
+            | SerializationStrategy::Unknown => {
+                value.serialize(DottedSerializer::new(self.dst, encoded_key, self.style))?;
+            }
+            SerializationStrategy::Skip => {
+                // silently drop these key-value pairs
+                return Ok(());
+            }
+        }
+        self.is_empty = false;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        if self.is_empty {
+            // Without any keys, the table would be dropped
+            let dst = self.dst;
+            write!(dst, "{}", self.prefix)?;
+            dst.space()?;
+            dst.keyval_sep()?;
+            dst.space()?;
+            dst.open_inline_table()?;
+            dst.close_inline_table()?;
+            dst.newline()?;
+        }
+        Ok(())
+    }
+}
+
+impl serde_core::ser::SerializeStruct for SerializeDottedTable<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        serde_core::ser::SerializeMap::serialize_entry(self, key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        serde_core::ser::SerializeMap::end(self)
+    }
+}
+
+impl serde_core::ser::SerializeStructVariant for SerializeDottedTable<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        serde_core::ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        serde_core::ser::SerializeStruct::end(self)
+    }
+}
@@ -8,9 +8,13 @@ use super::SerializationStrategy;
 use super::Serializer;
 use super::Table;
 use super::array_of_tables::ArrayOfTablesSerializer;
+use super::comments;
+use super::dotted::DottedSerializer;
 use super::style::Style;
 use super::value::KeySerializer;
-use super::value::ValueSerializer;
+use super::value::write_value;
+use super::write_comment;
+use super::write_trailing_comment;
 use crate::alloc_prelude::*;
 
 #[doc(hidden)]
@@ -48,6 +52,7 @@ impl<'d> serde_core::ser::SerializeMap for SerializeDocumentTable<'d> {
         let mut encoded_key = String::new();
         input.serialize(KeySerializer {
             dst: &mut encoded_key,
+            escapes: self.style.escapes,
         })?;
         self.key = Some(encoded_key);
         Ok(())
@@ -63,14 +68,16 @@ impl<'d> serde_core::ser::SerializeMap for SerializeDocumentTable<'d> {
             .expect("always called after `serialize_key`");
         match SerializationStrategy::from(value) {
             SerializationStrategy::Value => {
+                let comments = comments(value);
                 let dst = self.table.body_mut();
 
+                write_comment(&comments, dst)?;
                 write!(dst, "{encoded_key}")?;
                 dst.space()?;
                 dst.keyval_sep()?;
                 dst.space()?;
-                let value_serializer = ValueSerializer::with_style(dst, self.style);
-                let dst = value.serialize(value_serializer)?;
+                write_value(dst, value, self.style)?;
+                write_trailing_comment(&comments, dst)?;
                 dst.newline()?;
             }
             SerializationStrategy::ArrayOfTables => {
@@ -83,6 +90,10 @@ impl<'d> serde_core::ser::SerializeMap for SerializeDocumentTable<'d> {
                 );
                 value.serialize(value_serializer)?;
             }
+            SerializationStrategy::Dotted => {
+                let dst = self.table.body_mut();
+                value.serialize(DottedSerializer::new(dst, encoded_key, self.style))?;
+            }
             SerializationStrategy::Table | SerializationStrategy::Unknown => {
                 let child = self.buf.child_table(&mut self.table, encoded_key);
                 let value_serializer = Serializer::with_table(self.buf, child, self.style);
@@ -108,39 +119,8 @@ impl<'d> serde_core::ser::SerializeStruct for SerializeDocumentTable<'d> {
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
-        match SerializationStrategy::from(value) {
-            SerializationStrategy::Value => {
-                let dst = self.table.body_mut();
-
-                dst.key(key)?;
-                dst.space()?;
-                dst.keyval_sep()?;
-                dst.space()?;
-                let value_serializer = ValueSerializer::with_style(dst, self.style);
-                let dst = value.serialize(value_serializer)?;
-                dst.newline()?;
-            }
-            SerializationStrategy::ArrayOfTables => {
-                self.table.has_children(true);
-                let value_serializer = ArrayOfTablesSerializer::new(
-                    self.buf,
-                    self.table.clone(),
-                    key.to_owned(),
-                    self.style,
-                );
-                value.serialize(value_serializer)?;
-            }
-            SerializationStrategy::Table | SerializationStrategy::Unknown => {
-                let child = self.buf.child_table(&mut self.table, key.to_owned());
-                let value_serializer = Serializer::with_table(self.buf, child, self.style);
-                value.serialize(value_serializer)?;
-            }
-            SerializationStrategy::Skip => {
-                // silently drop these key-value pairs
-            }
-        }
-
-        Ok(())
+        // Struct fields are encoded like map keys, so they are escaped and quoted in headers
+        serde_core::ser::SerializeMap::serialize_entry(self, key, value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
@@ -7,12 +7,19 @@
 mod array;
 mod array_of_tables;
 mod buffer;
+mod dotted;
 mod map;
 mod strategy;
 
 use toml_writer::TomlWrite as _;
 
+use super::CommentedSerializer;
 use super::Error;
+use super::comment::comments;
+use super::comment::write_comment;
+use super::comment::write_trailing_comment;
+use super::hint::hint;
+use super::is_commented;
 use super::style;
 use super::value;
 use crate::alloc_prelude::*;
@@ -63,6 +70,168 @@ impl<'d> Serializer<'d> {
         ser
     }
 
+    /// Format datetimes according to `format`
+    ///
+    /// ```
+    /// # use toml::value::{Datetime, DatetimeFormat};
+    /// #[derive(serde::Serialize)]
+    /// struct Release {
+    ///     published: Datetime,
+    /// }
+    ///
+    /// let release = Release {
+    ///     published: "1979-05-27T07:32:00.5Z".parse().unwrap(),
+    /// };
+    /// let format = DatetimeFormat::new().with_space_separator().with_precision(3);
+    /// let mut buffer = toml::ser::Buffer::new();
+    /// let serializer = toml::Serializer::new(&mut buffer).datetime_format(format);
+    /// serde::Serialize::serialize(&release, serializer).unwrap();
+    /// assert_eq!(buffer.to_string(), "published = 1979-05-27 07:32:00.500Z\n");
+    /// ```
+    pub fn datetime_format(mut self, format: toml_datetime::DatetimeFormat) -> Self {
+        self.style.datetime_format = format;
+        self
+    }
+
+    /// Format integers according to `format`
+    ///
+    /// Negative integers are always written in decimal.
+    ///
+    /// ```
+    /// #[derive(serde::Serialize)]
+    /// struct Limits {
+    ///     max_size: u64,
+    ///     mask: u32,
+    /// }
+    ///
+    /// let limits = Limits {
+    ///     max_size: 10_000_000,
+    ///     mask: 0xFFFF_0000,
+    /// };
+    /// let format = toml::ser::TomlIntegerFormat::new().with_grouping(true);
+    /// let mut buffer = toml::ser::Buffer::new();
+    /// let serializer = toml::Serializer::new(&mut buffer).integer_format(format);
+    /// serde::Serialize::serialize(&limits, serializer).unwrap();
+    /// assert_eq!(buffer.to_string(), "max_size = 10_000_000\nmask = 4_294_901_760\n");
+    /// ```
+    pub fn integer_format(mut self, format: toml_writer::TomlIntegerFormat) -> Self {
+        self.style.integer_format = format;
+        self
+    }
+
+    /// Format floats according to `format`
+    ///
+    /// ```
+    /// #[derive(serde::Serialize)]
+    /// struct Measurement {
+    ///     mass: f64,
+    ///     distance: f64,
+    /// }
+    ///
+    /// let measurement = Measurement {
+    ///     mass: 2.5,
+    ///     distance: 149_597_870_700.0,
+    /// };
+    /// let format = toml::ser::TomlFloatFormat::new()
+    ///     .as_fixed(2)
+    ///     .with_exponent_above(1e6);
+    /// let mut buffer = toml::ser::Buffer::new();
+    /// let serializer = toml::Serializer::new(&mut buffer).float_format(format);
+    /// serde::Serialize::serialize(&measurement, serializer).unwrap();
+    /// assert_eq!(buffer.to_string(), "mass = 2.50\ndistance = 1.50e11\n");
+    /// ```
+    pub fn float_format(mut self, format: toml_writer::TomlFloatFormat) -> Self {
+        self.style.float_format = format;
+        self
+    }
+
+    /// Fold strings with lines longer than `width` into multi-line strings
+    ///
+    /// Lines are broken between words with a line-ending backslash, leaving the string's value
+    /// unchanged.
+    ///
+    /// ```
+    /// #[derive(serde::Serialize)]
+    /// struct Package {
+    ///     description: &'static str,
+    /// }
+    ///
+    /// let package = Package {
+    ///     description: "A native Rust encoder and decoder of TOML-formatted files and streams.",
+    /// };
+    /// let mut buffer = toml::ser::Buffer::new();
+    /// let serializer = toml::Serializer::new(&mut buffer).fold_strings(40);
+    /// serde::Serialize::serialize(&package, serializer).unwrap();
+    /// assert_eq!(buffer.to_string(), r#"description = """
+    /// A native Rust encoder and decoder of \
+    /// TOML-formatted files and streams."""
+    /// "#);
+    /// ```
+    pub fn fold_strings(mut self, width: usize) -> Self {
+        self.style.fold_strings = Some(width);
+        self
+    }
+
+    /// Escape strings and keys according to `escapes`
+    ///
+    /// ```
+    /// #[derive(serde::Serialize)]
+    /// struct Prompt {
+    ///     greeting: &'static str,
+    /// }
+    ///
+    /// let prompt = Prompt {
+    ///     greeting: "\u{1b}[1mHéllo\u{1b}[0m",
+    /// };
+    /// let escapes = toml::ser::TomlEscapes::new()
+    ///     .with_version(toml::ser::TomlVersion::V1_1)
+    ///     .with_ascii_only(true);
+    /// let mut buffer = toml::ser::Buffer::new();
+    /// let serializer = toml::Serializer::new(&mut buffer).escapes(escapes);
+    /// serde::Serialize::serialize(&prompt, serializer).unwrap();
+    /// assert_eq!(buffer.to_string(), r#"greeting = "\e[1mH\xE9llo\e[0m"
+    /// "#);
+    /// ```
+    pub fn escapes(mut self, escapes: toml_writer::TomlEscapes) -> Self {
+        self.style.escapes = escapes;
+        self
+    }
+
+    /// Fit arrays and inline tables within a line width, breaking them across lines as needed
+    ///
+    /// Inline tables are only broken when targeting TOML 1.1, see
+    /// [`TomlLayout::with_version`][crate::ser::TomlLayout::with_version].
+    ///
+    /// This takes precedence over the one-element-per-line arrays of [`Serializer::pretty`].
+    ///
+    /// ```
+    /// #[derive(serde::Serialize)]
+    /// struct Package {
+    ///     authors: Vec<&'static str>,
+    ///     keywords: Vec<&'static str>,
+    /// }
+    ///
+    /// let package = Package {
+    ///     authors: vec!["Alex Crichton", "Ed Page"],
+    ///     keywords: vec!["encoding", "toml", "no_std"],
+    /// };
+    /// let layout = toml::ser::TomlLayout::new().with_max_width(40);
+    /// let mut buffer = toml::ser::Buffer::new();
+    /// let serializer = toml::Serializer::new(&mut buffer).layout(layout);
+    /// serde::Serialize::serialize(&package, serializer).unwrap();
+    /// assert_eq!(buffer.to_string(), r#"authors = ["Alex Crichton", "Ed Page"]
+    /// keywords = [
+    ///     "encoding",
+    ///     "toml",
+    ///     "no_std",
+    /// ]
+    /// "#);
+    /// ```
+    pub fn layout(mut self, layout: toml_writer::TomlLayout) -> Self {
+        self.style.layout = Some(layout);
+        self
+    }
+
     pub(crate) fn with_table(buf: &'d mut Buffer, table: Table, style: style::Style) -> Self {
         Self { buf, style, table }
     }
@@ -169,13 +338,22 @@ impl<'d> serde_core::ser::Serializer for Serializer<'d> {
     }
 
     fn serialize_newtype_struct<T>(
-        self,
-        _name: &'static str,
+        mut self,
+        name: &'static str,
         v: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
+        if let Some(hint) = hint(name) {
+            self.style = self.style.with_hint(hint);
+        }
+        if is_commented(name) {
+            return v.serialize(CommentedSerializer::new(move |comments| {
+                self.table.add_comments(comments);
+                Ok(self)
+            }));
+        }
         v.serialize(self)
     }
 
@@ -191,17 +369,21 @@ impl<'d> serde_core::ser::Serializer for Serializer<'d> {
     {
         match SerializationStrategy::from(value) {
             SerializationStrategy::Value | SerializationStrategy::ArrayOfTables => {
+                let comments = comments(value);
                 let dst = self.table.body_mut();
 
-                dst.key(variant)?;
+                write_comment(&comments, dst)?;
+                dst.key(value::escaped_key(variant, self.style.escapes))?;
                 dst.space()?;
                 dst.keyval_sep()?;
                 dst.space()?;
-                let value_serializer = value::ValueSerializer::with_style(dst, self.style);
-                let dst = value.serialize(value_serializer)?;
+                value::write_value(dst, value, self.style)?;
+                write_trailing_comment(&comments, dst)?;
                 dst.newline()?;
             }
-            SerializationStrategy::Table | SerializationStrategy::Unknown => {
+            SerializationStrategy::Table
+            | SerializationStrategy::Dotted
+            | SerializationStrategy::Unknown => {
                 let child = self.buf.child_table(&mut self.table, variant.to_owned());
                 let value_serializer = Serializer::with_table(self.buf, child, self.style);
                 value.serialize(value_serializer)?;
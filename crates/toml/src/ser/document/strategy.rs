@@ -1,7 +1,14 @@
+use crate::ser::CommentedSerializer;
+use crate::ser::hint::Hint;
+use crate::ser::hint::hint;
+use crate::ser::is_commented;
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub(crate) enum SerializationStrategy {
     Value,
     Table,
+    /// A table written as dotted keys in its parent table
+    Dotted,
     ArrayOfTables,
     Skip,
     Unknown,
@@ -138,15 +145,19 @@ impl serde_core::ser::Serializer for WalkValue {
         Err(SerializationStrategy::Value)
     }
 
-    fn serialize_newtype_struct<T>(
-        self,
-        _name: &'static str,
-        v: &T,
-    ) -> Result<Self::Ok, Self::Error>
+    fn serialize_newtype_struct<T>(self, name: &'static str, v: &T) -> Result<Self::Ok, Self::Error>
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
-        v.serialize(self)
+        match hint(name) {
+            Some(Hint::Inline) => Err(SerializationStrategy::Value),
+            Some(Hint::Dotted) => match SerializationStrategy::from(v) {
+                SerializationStrategy::Table => Err(SerializationStrategy::Dotted),
+                strategy => Err(strategy),
+            },
+            _ if is_commented(name) => v.serialize(CommentedSerializer::new(|_| Ok(self))),
+            _ => v.serialize(self),
+        }
     }
 
     fn serialize_newtype_variant<T>(
@@ -235,7 +246,7 @@ impl ArrayWalkValue {
             | SerializationStrategy::ArrayOfTables
             | SerializationStrategy::Unknown
             | SerializationStrategy::Skip => Err(SerializationStrategy::Value),
-            SerializationStrategy::Table => Ok(()),
+            SerializationStrategy::Table | SerializationStrategy::Dotted => Ok(()),
         }
     }
 
@@ -1,3 +1,4 @@
+use crate::ErrorKind;
 use crate::alloc_prelude::*;
 
 /// Errors that can occur when serializing a type.
@@ -37,6 +38,26 @@ impl Error {
             inner: ErrorInner::DateInvalid,
         }
     }
+
+    /// What went wrong, for handling the error without matching on its message
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "display")] {
+    /// let err = toml::to_string(&Some(())).unwrap_err();
+    /// assert_eq!(err.kind(), toml::ErrorKind::UnsupportedType(Some("unit")));
+    /// # }
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match &self.inner {
+            ErrorInner::UnsupportedType(t) => ErrorKind::UnsupportedType(*t),
+            ErrorInner::UnsupportedNone => ErrorKind::UnsupportedNone,
+            ErrorInner::KeyNotString => ErrorKind::KeyNotString,
+            ErrorInner::DateInvalid => ErrorKind::DateInvalid,
+            ErrorInner::Custom(_) => ErrorKind::Custom,
+        }
+    }
 }
 
 impl From<core::fmt::Error> for Error {
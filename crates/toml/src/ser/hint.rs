@@ -0,0 +1,268 @@
+//! Wrappers that control how a value is formatted as TOML
+//!
+//! Other serializers see the wrapped value unchanged.
+
+const INLINE: &str = "$__toml_private_Inline";
+const MULTILINE: &str = "$__toml_private_Multiline";
+const LITERAL_STRING: &str = "$__toml_private_LiteralString";
+const MULTILINE_STRING: &str = "$__toml_private_MultilineString";
+const HEX: &str = "$__toml_private_Hex";
+const DOTTED: &str = "$__toml_private_Dotted";
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg(feature = "display")]
+pub(crate) enum Hint {
+    Inline,
+    Multiline,
+    LiteralString,
+    MultilineString,
+    Hex,
+    Dotted,
+}
+
+/// The formatting hint for a newtype struct `name`, if any
+#[cfg(feature = "display")]
+pub(crate) fn hint(name: &'static str) -> Option<Hint> {
+    match name {
+        INLINE => Some(Hint::Inline),
+        MULTILINE => Some(Hint::Multiline),
+        LITERAL_STRING => Some(Hint::LiteralString),
+        MULTILINE_STRING => Some(Hint::MultilineString),
+        HEX => Some(Hint::Hex),
+        DOTTED => Some(Hint::Dotted),
+        _ => None,
+    }
+}
+
+macro_rules! hint_type {
+    ($(#[$attr:meta])* $ty:ident, $name:ident) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub struct $ty<T>(pub T);
+
+        impl<T> $ty<T>
+        where
+            T: serde_core::ser::Serialize,
+        {
+            /// Serialize `value` with this hint
+            ///
+            /// This is intended for `#[serde(serialize_with = "...")]`.
+            pub fn serialize<S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde_core::ser::Serializer,
+            {
+                serializer.serialize_newtype_struct($name, value)
+            }
+        }
+
+        impl<T> serde_core::ser::Serialize for $ty<T>
+        where
+            T: serde_core::ser::Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde_core::ser::Serializer,
+            {
+                Self::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de, T> serde_core::de::Deserialize<'de> for $ty<T>
+        where
+            T: serde_core::de::Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde_core::de::Deserializer<'de>,
+            {
+                T::deserialize(deserializer).map(Self)
+            }
+        }
+    };
+}
+
+hint_type!(
+    /// Write a table, or an array of tables, inline
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use toml::ser::Inline;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Package {
+    ///     name: &'static str,
+    ///     metadata: Inline<Metadata>,
+    /// }
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Metadata {
+    ///     msrv: &'static str,
+    /// }
+    ///
+    /// let package = Package {
+    ///     name: "toml",
+    ///     metadata: Inline(Metadata { msrv: "1.85" }),
+    /// };
+    /// assert_eq!(
+    ///     toml::to_string(&package).unwrap(),
+    ///     "name = \"toml\"\nmetadata = { msrv = \"1.85\" }\n"
+    /// );
+    /// ```
+    Inline,
+    INLINE
+);
+
+hint_type!(
+    /// Write arrays with each element on its own line, like [`Serializer::pretty`][crate::Serializer::pretty]
+    ///
+    /// This applies to arrays of more than one element, including those nested in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use toml::ser::Multiline;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Package {
+    ///     #[serde(serialize_with = "Multiline::serialize")]
+    ///     authors: Vec<&'static str>,
+    /// }
+    ///
+    /// let package = Package {
+    ///     authors: vec!["Alex Crichton", "Ed Page"],
+    /// };
+    /// assert_eq!(
+    ///     toml::to_string(&package).unwrap(),
+    ///     "authors = [\n    \"Alex Crichton\",\n    \"Ed Page\",\n]\n"
+    /// );
+    /// ```
+    Multiline,
+    MULTILINE
+);
+
+hint_type!(
+    /// Write strings as literal strings, like `'C:\Users'`, when they can be
+    ///
+    /// Strings with newlines are written as multi-line literal strings and strings that can't be
+    /// literal, like `it's`, are written as basic strings.
+    /// This applies to all strings in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use toml::ser::LiteralString;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Config {
+    ///     path: LiteralString<&'static str>,
+    /// }
+    ///
+    /// let config = Config {
+    ///     path: LiteralString(r"C:\Users"),
+    /// };
+    /// assert_eq!(toml::to_string(&config).unwrap(), "path = 'C:\\Users'\n");
+    /// ```
+    LiteralString,
+    LITERAL_STRING
+);
+
+hint_type!(
+    /// Write strings as multi-line strings, like `"""..."""`
+    ///
+    /// This applies to all strings in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use toml::ser::MultilineString;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Config {
+    ///     script: MultilineString<&'static str>,
+    /// }
+    ///
+    /// let config = Config {
+    ///     script: MultilineString("cargo build\ncargo test\n"),
+    /// };
+    /// assert_eq!(
+    ///     toml::to_string(&config).unwrap(),
+    ///     "script = \"\"\"\ncargo build\ncargo test\n\"\"\"\n"
+    /// );
+    /// ```
+    MultilineString,
+    MULTILINE_STRING
+);
+
+hint_type!(
+    /// Write integers in hexadecimal, like `0xFF`
+    ///
+    /// Negative integers are always written in decimal.
+    /// This applies to all integers in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use toml::ser::Hex;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Config {
+    ///     mask: Hex<u32>,
+    /// }
+    ///
+    /// let config = Config {
+    ///     mask: Hex(0xFFFF_0000),
+    /// };
+    /// assert_eq!(toml::to_string(&config).unwrap(), "mask = 0xFFFF0000\n");
+    /// ```
+    Hex,
+    HEX
+);
+
+hint_type!(
+    /// Write a table as dotted keys in its parent table, like `a.b = 1`
+    ///
+    /// Tables nested in the value are also dotted.
+    /// Within inline tables and arrays, this is written as an inline table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use toml::ser::Dotted;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Package {
+    ///     name: &'static str,
+    ///     metadata: Dotted<Metadata>,
+    /// }
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Metadata {
+    ///     msrv: &'static str,
+    ///     docs: Docs,
+    /// }
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Docs {
+    ///     all_features: bool,
+    /// }
+    ///
+    /// let package = Package {
+    ///     name: "toml",
+    ///     metadata: Dotted(Metadata {
+    ///         msrv: "1.85",
+    ///         docs: Docs { all_features: true },
+    ///     }),
+    /// };
+    /// assert_eq!(
+    ///     toml::to_string(&package).unwrap(),
+    ///     "\
+    /// name = \"toml\"
+    /// metadata.msrv = \"1.85\"
+    /// metadata.docs.all_features = true
+    /// "
+    /// );
+    /// ```
+    Dotted,
+    DOTTED
+);
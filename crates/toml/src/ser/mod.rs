@@ -4,9 +4,12 @@
 //! into TOML documents (as strings). Note that some top-level functions here
 //! are also provided at the top of the crate.
 
+#[cfg(feature = "display")]
+mod comment;
 #[cfg(feature = "display")]
 mod document;
 mod error;
+mod hint;
 #[cfg(feature = "display")]
 mod style;
 #[cfg(feature = "display")]
@@ -20,6 +23,27 @@ pub use document::Buffer;
 pub use document::Serializer;
 pub use error::Error;
 pub(crate) use error::ErrorInner;
+pub use hint::Dotted;
+pub use hint::Hex;
+pub use hint::Inline;
+pub use hint::LiteralString;
+pub use hint::Multiline;
+pub use hint::MultilineString;
+pub use serde_spanned::Commented;
+pub(crate) use serde_spanned::ser::CommentedSerializer;
+#[cfg(feature = "display")]
+pub(crate) use serde_spanned::ser::Comments;
+pub(crate) use serde_spanned::ser::is_commented;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlEscapes;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlFloatFormat;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlIntegerFormat;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlLayout;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlVersion;
 #[cfg(feature = "display")]
 pub use value::ValueSerializer;
 
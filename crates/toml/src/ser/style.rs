@@ -1,4 +1,35 @@
+use super::hint::Hint;
+
 #[derive(Copy, Clone, Default)]
 pub(crate) struct Style {
     pub(crate) multiline_array: bool,
+    pub(crate) datetime_format: toml_datetime::DatetimeFormat,
+    pub(crate) integer_format: toml_writer::TomlIntegerFormat,
+    pub(crate) float_format: toml_writer::TomlFloatFormat,
+    pub(crate) layout: Option<toml_writer::TomlLayout>,
+    pub(crate) fold_strings: Option<usize>,
+    pub(crate) escapes: toml_writer::TomlEscapes,
+    pub(crate) string_style: Option<StringStyle>,
+}
+
+impl Style {
+    /// Apply a formatting hint to the style of a value
+    ///
+    /// Hints for the layout of tables, like [`Hint::Inline`], are handled by the serializers.
+    pub(crate) fn with_hint(mut self, hint: Hint) -> Self {
+        match hint {
+            Hint::Multiline => self.multiline_array = true,
+            Hint::LiteralString => self.string_style = Some(StringStyle::Literal),
+            Hint::MultilineString => self.string_style = Some(StringStyle::Multiline),
+            Hint::Hex => self.integer_format = self.integer_format.as_hex_upper(),
+            Hint::Inline | Hint::Dotted => {}
+        }
+        self
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum StringStyle {
+    Literal,
+    Multiline,
 }
@@ -4,6 +4,7 @@ use toml_writer::TomlWrite as _;
 
 use super::Error;
 use super::Style;
+use super::key::escaped_key;
 use crate::alloc_prelude::*;
 
 #[doc(hidden)]
@@ -117,7 +118,7 @@ impl<'d> SerializeTupleVariant<'d> {
     ) -> Result<Self, Error> {
         dst.open_inline_table()?;
         dst.space()?;
-        dst.key(variant)?;
+        dst.key(escaped_key(variant, style.escapes))?;
         dst.space()?;
         dst.keyval_sep()?;
         dst.space()?;
@@ -5,6 +5,17 @@ use crate::alloc_prelude::*;
 
 pub(crate) struct KeySerializer<'d> {
     pub(crate) dst: &'d mut String,
+    pub(crate) escapes: toml_writer::TomlEscapes,
+}
+
+/// Encode `key` using `escapes`
+pub(crate) fn escaped_key(
+    key: &str,
+    escapes: toml_writer::TomlEscapes,
+) -> toml_writer::TomlKey<'_> {
+    toml_writer::TomlKeyBuilder::new(key)
+        .with_escapes(escapes)
+        .as_default()
 }
 
 impl serde_core::ser::Serializer for KeySerializer<'_> {
@@ -84,12 +95,12 @@ impl serde_core::ser::Serializer for KeySerializer<'_> {
     fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
         let mut b = [0; 4];
         let result = v.encode_utf8(&mut b);
-        self.dst.key(&*result)?;
+        self.dst.key(escaped_key(result, self.escapes))?;
         Ok(())
     }
 
     fn serialize_str(self, value: &str) -> Result<Self::Ok, Self::Error> {
-        self.dst.key(value)?;
+        self.dst.key(escaped_key(value, self.escapes))?;
         Ok(())
     }
 
@@ -122,7 +133,7 @@ impl serde_core::ser::Serializer for KeySerializer<'_> {
         _variant_index: u32,
         variant: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        self.dst.key(variant)?;
+        self.dst.key(escaped_key(variant, self.escapes))?;
         Ok(())
     }
 
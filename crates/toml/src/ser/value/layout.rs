@@ -0,0 +1,472 @@
+use toml_writer::LayoutValue;
+use toml_writer::TomlWrite as _;
+
+use super::CommentedSerializer;
+use super::Error;
+use super::Style;
+use super::ValueSerializer;
+use super::dt_err;
+use super::hint;
+use super::is_commented;
+use super::is_datetime_string;
+use super::key::KeySerializer;
+use super::key::escaped_key;
+use crate::alloc_prelude::*;
+
+/// Serialize a value into a [`LayoutValue`] so its arrays and inline tables can be fit to a line
+/// width
+///
+/// Scalars are encoded by [`ValueSerializer`], so they match the output without a layout.
+pub(crate) struct LayoutSerializer<'n> {
+    style: Style,
+    is_none: Option<&'n mut bool>,
+}
+
+impl<'n> LayoutSerializer<'n> {
+    pub(crate) fn new(style: Style) -> Self {
+        Self {
+            style,
+            is_none: None,
+        }
+    }
+
+    fn map_value(is_none: &'n mut bool, style: Style) -> Self {
+        Self {
+            style,
+            is_none: Some(is_none),
+        }
+    }
+
+    fn scalar(
+        self,
+        serialize: impl FnOnce(ValueSerializer<'_>) -> Result<(), Error>,
+    ) -> Result<LayoutValue, Error> {
+        let mut encoded = String::new();
+        serialize(ValueSerializer::with_style(&mut encoded, self.style))?;
+        Ok(LayoutValue::Scalar(encoded))
+    }
+}
+
+impl serde_core::ser::Serializer for LayoutSerializer<'_> {
+    type Ok = LayoutValue;
+    type Error = Error;
+    type SerializeSeq = SerializeLayoutArray;
+    type SerializeTuple = SerializeLayoutArray;
+    type SerializeTupleStruct = SerializeLayoutArray;
+    type SerializeTupleVariant = SerializeLayoutArray;
+    type SerializeMap = SerializeLayoutTable;
+    type SerializeStruct = SerializeLayoutTable;
+    type SerializeStructVariant = SerializeLayoutTable;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_bool(v).map(drop))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_i8(v).map(drop))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_i16(v).map(drop))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_i32(v).map(drop))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_i64(v).map(drop))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_i128(v).map(drop))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_u8(v).map(drop))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_u16(v).map(drop))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_u32(v).map(drop))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_u64(v).map(drop))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_u128(v).map(drop))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_f32(v).map(drop))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_f64(v).map(drop))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_char(v).map(drop))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_str(v).map(drop))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok, Self::Error> {
+        use serde_core::ser::Serialize;
+        value.serialize(self)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        if let Some(is_none) = self.is_none {
+            *is_none = true;
+        }
+        Err(Error::unsupported_none())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        value.serialize(LayoutSerializer::new(self.style))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_unit().map(drop))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| s.serialize_unit_struct(name).map(drop))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.scalar(|s| {
+            s.serialize_unit_variant(name, variant_index, variant)
+                .map(drop)
+        })
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        if is_datetime_string(name) || crate::de::is_raw_value(name) {
+            return self.scalar(|s| s.serialize_newtype_struct(name, value).map(drop));
+        }
+        if let Some(hint) = hint(name) {
+            return value.serialize(LayoutSerializer {
+                style: self.style.with_hint(hint),
+                is_none: self.is_none,
+            });
+        }
+        if is_commented(name) {
+            return value.serialize(CommentedSerializer::new(|_| Ok(self)));
+        }
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        let value = value.serialize(LayoutSerializer::new(self.style))?;
+        Ok(LayoutValue::inline_table([(variant, value)]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(SerializeLayoutArray::new(self.style, len, None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(SerializeLayoutArray::new(
+            self.style,
+            Some(len),
+            Some(variant),
+        ))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(SerializeLayoutTable::new(self.style, None))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let mut table = SerializeLayoutTable::new(self.style, None);
+        if toml_datetime::ser::is_datetime(name) {
+            table.datetime = Some(toml_datetime::ser::DatetimeSerializer::new());
+        }
+        Ok(table)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(SerializeLayoutTable::new(self.style, Some(variant)))
+    }
+}
+
+pub(crate) struct SerializeLayoutArray {
+    values: Vec<LayoutValue>,
+    variant: Option<&'static str>,
+    style: Style,
+}
+
+impl SerializeLayoutArray {
+    pub(crate) fn new(style: Style, len: Option<usize>, variant: Option<&'static str>) -> Self {
+        Self {
+            values: Vec::with_capacity(len.unwrap_or(0)),
+            variant,
+            style,
+        }
+    }
+
+    pub(crate) fn push<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        self.values
+            .push(value.serialize(LayoutSerializer::new(self.style))?);
+        Ok(())
+    }
+
+    pub(crate) fn end(self) -> LayoutValue {
+        let value = LayoutValue::Array(self.values);
+        match self.variant {
+            Some(variant) => LayoutValue::inline_table([(variant, value)]),
+            None => value,
+        }
+    }
+}
+
+impl serde_core::ser::SerializeSeq for SerializeLayoutArray {
+    type Ok = LayoutValue;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.end())
+    }
+}
+
+impl serde_core::ser::SerializeTuple for SerializeLayoutArray {
+    type Ok = LayoutValue;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.end())
+    }
+}
+
+impl serde_core::ser::SerializeTupleStruct for SerializeLayoutArray {
+    type Ok = LayoutValue;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.end())
+    }
+}
+
+impl serde_core::ser::SerializeTupleVariant for SerializeLayoutArray {
+    type Ok = LayoutValue;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.end())
+    }
+}
+
+pub(crate) struct SerializeLayoutTable {
+    entries: Vec<(String, LayoutValue)>,
+    key: Option<String>,
+    variant: Option<&'static str>,
+    datetime: Option<toml_datetime::ser::DatetimeSerializer>,
+    style: Style,
+}
+
+impl SerializeLayoutTable {
+    fn new(style: Style, variant: Option<&'static str>) -> Self {
+        Self {
+            entries: Vec::new(),
+            key: None,
+            variant,
+            datetime: None,
+            style,
+        }
+    }
+
+    fn push<T>(&mut self, encoded_key: String, value: &T) -> Result<(), Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        let mut is_none = false;
+        match value.serialize(LayoutSerializer::map_value(&mut is_none, self.style)) {
+            Ok(value) => {
+                self.entries.push((encoded_key, value));
+            }
+            Err(e) => {
+                if !(e == Error::unsupported_none() && is_none) {
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<LayoutValue, Error> {
+        if let Some(datetime) = self.datetime {
+            let value = datetime.end_lossless().map_err(dt_err)?;
+            let value = self.style.datetime_format.format_lossless(&value);
+            return Ok(LayoutValue::Scalar(value.to_string()));
+        }
+
+        let value = LayoutValue::InlineTable(self.entries);
+        match self.variant {
+            Some(variant) => Ok(LayoutValue::inline_table([(variant, value)])),
+            None => Ok(value),
+        }
+    }
+}
+
+impl serde_core::ser::SerializeMap for SerializeLayoutTable {
+    type Ok = LayoutValue;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, input: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        let mut encoded_key = String::new();
+        input.serialize(KeySerializer {
+            dst: &mut encoded_key,
+            escapes: self.style.escapes,
+        })?;
+        self.key = Some(encoded_key);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        let encoded_key = self
+            .key
+            .take()
+            .expect("always called after `serialize_key`");
+        self.push(encoded_key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.end()
+    }
+}
+
+impl serde_core::ser::SerializeStruct for SerializeLayoutTable {
+    type Ok = LayoutValue;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        if let Some(datetime) = &mut self.datetime {
+            return datetime.serialize_field(key, value).map_err(dt_err);
+        }
+
+        let mut encoded_key = String::new();
+        encoded_key.key(escaped_key(key, self.style.escapes))?;
+        self.push(encoded_key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.end()
+    }
+}
+
+impl serde_core::ser::SerializeStructVariant for SerializeLayoutTable {
+    type Ok = LayoutValue;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: serde_core::ser::Serialize + ?Sized,
+    {
+        serde_core::ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.end()
+    }
+}
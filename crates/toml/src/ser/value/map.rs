@@ -2,12 +2,17 @@ use core::fmt::Write as _;
 
 use toml_writer::TomlWrite as _;
 
+use super::CommentedSerializer;
 use super::Error;
 use super::Style;
 use super::ValueSerializer;
 use super::array::SerializeTupleVariant;
 use super::array::SerializeValueArray;
+use super::hint;
+use super::is_commented;
+use super::is_datetime_string;
 use super::key::KeySerializer;
+use super::key::escaped_key;
 use crate::alloc_prelude::*;
 
 #[doc(hidden)]
@@ -28,7 +33,7 @@ impl<'d> SerializeMap<'d> {
         style: Style,
     ) -> Result<Self, Error> {
         if toml_datetime::ser::is_datetime(name) {
-            Ok(Self::Datetime(SerializeDatetime::new(dst)))
+            Ok(Self::Datetime(SerializeDatetime::new(dst, style)))
         } else {
             Ok(Self::map(dst, style)?)
         }
@@ -93,13 +98,15 @@ impl<'d> serde_core::ser::SerializeStruct for SerializeMap<'d> {
 pub struct SerializeDatetime<'d> {
     dst: &'d mut String,
     inner: toml_datetime::ser::DatetimeSerializer,
+    style: Style,
 }
 
 impl<'d> SerializeDatetime<'d> {
-    pub(crate) fn new(dst: &'d mut String) -> Self {
+    pub(crate) fn new(dst: &'d mut String, style: Style) -> Self {
         Self {
             dst,
             inner: toml_datetime::ser::DatetimeSerializer::new(),
+            style,
         }
     }
 }
@@ -141,13 +148,14 @@ impl<'d> serde_core::ser::SerializeStruct for SerializeDatetime<'d> {
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        let value = self.inner.end().map_err(dt_err)?;
+        let value = self.inner.end_lossless().map_err(dt_err)?;
+        let value = self.style.datetime_format.format_lossless(&value);
         write!(self.dst, "{value}")?;
         Ok(self.dst)
     }
 }
 
-fn dt_err(err: toml_datetime::ser::SerializerError) -> Error {
+pub(crate) fn dt_err(err: toml_datetime::ser::SerializerError) -> Error {
     match err {
         toml_datetime::ser::SerializerError::InvalidFormat(err) => Error::new(err),
         _ => Error::date_invalid(),
@@ -193,6 +201,7 @@ impl<'d> serde_core::ser::SerializeMap for SerializeTable<'d> {
         let mut encoded_key = String::new();
         input.serialize(KeySerializer {
             dst: &mut encoded_key,
+            escapes: self.style.escapes,
         })?;
         self.key = Some(encoded_key);
         Ok(())
@@ -262,7 +271,7 @@ impl<'d> serde_core::ser::SerializeStruct for SerializeTable<'d> {
                 }
                 self.seen_value = true;
                 self.dst.space()?;
-                self.dst.key(key)?;
+                self.dst.key(escaped_key(key, self.style.escapes))?;
                 self.dst.space()?;
                 self.dst.keyval_sep()?;
                 self.dst.space()?;
@@ -401,12 +410,26 @@ impl<'d> serde_core::ser::Serializer for MapValueSerializer<'d> {
 
     fn serialize_newtype_struct<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
+        if is_datetime_string(name) || crate::de::is_raw_value(name) {
+            return ValueSerializer::with_style(self.dst, self.style)
+                .serialize_newtype_struct(name, value);
+        }
+        if let Some(hint) = hint(name) {
+            return value.serialize(MapValueSerializer::new(
+                self.dst,
+                self.is_none,
+                self.style.with_hint(hint),
+            ));
+        }
+        if is_commented(name) {
+            return value.serialize(CommentedSerializer::new(|_| Ok(self)));
+        }
         value.serialize(self)
     }
 
@@ -500,7 +523,7 @@ impl<'d> SerializeStructVariant<'d> {
     ) -> Result<Self, Error> {
         dst.open_inline_table()?;
         dst.space()?;
-        dst.key(variant)?;
+        dst.key(escaped_key(variant, style.escapes))?;
         dst.space()?;
         dst.keyval_sep()?;
         dst.space()?;
@@ -1,10 +1,16 @@
 mod array;
 mod key;
+mod layout;
 mod map;
+mod raw;
 
 use toml_writer::TomlWrite as _;
 
+use super::CommentedSerializer;
 use super::Error;
+use super::hint::hint;
+use super::is_commented;
+use super::style::StringStyle;
 use super::style::Style;
 use crate::alloc_prelude::*;
 #[allow(clippy::wildcard_imports)]
@@ -12,7 +18,11 @@ pub(crate) use array::*;
 #[allow(clippy::wildcard_imports)]
 pub(crate) use key::*;
 #[allow(clippy::wildcard_imports)]
+pub(crate) use layout::*;
+#[allow(clippy::wildcard_imports)]
 pub(crate) use map::*;
+#[allow(clippy::wildcard_imports)]
+pub(crate) use raw::*;
 
 /// Serialization for TOML [values][crate::Value].
 ///
@@ -75,9 +85,102 @@ impl<'d> ValueSerializer<'d> {
         }
     }
 
+    /// Format datetimes according to `format`
+    pub fn datetime_format(mut self, format: toml_datetime::DatetimeFormat) -> Self {
+        self.style.datetime_format = format;
+        self
+    }
+
+    /// Format integers according to `format`
+    ///
+    /// Negative integers are always written in decimal.
+    pub fn integer_format(mut self, format: toml_writer::TomlIntegerFormat) -> Self {
+        self.style.integer_format = format;
+        self
+    }
+
+    /// Fold strings with lines longer than `width` into multi-line strings
+    ///
+    /// See [`Serializer::fold_strings`][crate::Serializer::fold_strings].
+    pub fn fold_strings(mut self, width: usize) -> Self {
+        self.style.fold_strings = Some(width);
+        self
+    }
+
+    /// Escape strings and keys according to `escapes`
+    ///
+    /// See [`Serializer::escapes`][crate::Serializer::escapes].
+    pub fn escapes(mut self, escapes: toml_writer::TomlEscapes) -> Self {
+        self.style.escapes = escapes;
+        self
+    }
+
+    /// Format floats according to `format`
+    pub fn float_format(mut self, format: toml_writer::TomlFloatFormat) -> Self {
+        self.style.float_format = format;
+        self
+    }
+
     pub(crate) fn with_style(dst: &'d mut String, style: Style) -> Self {
         Self { dst, style }
     }
+
+    fn serialize_integer<N>(self, v: N) -> Result<&'d mut String, Error>
+    where
+        N: PartialOrd + Default + Copy,
+        toml_writer::TomlInteger<N>: toml_writer::WriteTomlValue,
+    {
+        let format = self.style.integer_format;
+        // Only decimal supports negative integers
+        let integer = format
+            .format(v)
+            .or_else(|| format.as_decimal().format(v))
+            .expect("decimal supports all integers");
+        self.dst.value(integer)?;
+        Ok(self.dst)
+    }
+}
+
+/// Write the value of a key-value pair, fitting it to [`Style::layout`] when set
+pub(crate) fn write_value<T>(dst: &mut String, value: &T, style: Style) -> Result<(), Error>
+where
+    T: serde_core::ser::Serialize + ?Sized,
+{
+    match style.layout {
+        Some(layout) => {
+            let value = value.serialize(LayoutSerializer::new(style))?;
+            write_layout(dst, &value, layout)
+        }
+        None => {
+            value.serialize(ValueSerializer::with_style(dst, style))?;
+            Ok(())
+        }
+    }
+}
+
+/// Whether a newtype struct is a datetime from [`as_string`][crate::value::as_string]
+fn is_datetime_string(name: &'static str) -> bool {
+    #[cfg(feature = "portable")]
+    {
+        toml_datetime::ser::is_datetime_string(name)
+    }
+    #[cfg(not(feature = "portable"))]
+    {
+        let _ = name;
+        false
+    }
+}
+
+/// Write `value`, starting from the current column of `dst`
+pub(crate) fn write_layout(
+    dst: &mut String,
+    value: &toml_writer::LayoutValue,
+    layout: toml_writer::TomlLayout,
+) -> Result<(), Error> {
+    let line_start = dst.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = dst[line_start..].chars().count();
+    layout.write(dst, value, column)?;
+    Ok(())
 }
 
 impl<'d> serde_core::ser::Serializer for ValueSerializer<'d> {
@@ -97,53 +200,43 @@ impl<'d> serde_core::ser::Serializer for ValueSerializer<'d> {
     }
 
     fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        self.serialize_integer(v)
     }
 
     fn serialize_f32(self, mut v: f32) -> Result<Self::Ok, Self::Error> {
@@ -157,7 +250,7 @@ impl<'d> serde_core::ser::Serializer for ValueSerializer<'d> {
         if v.is_nan() {
             v = v.copysign(1.0);
         }
-        self.dst.value(v)?;
+        self.dst.value(self.style.float_format.format(v))?;
         Ok(self.dst)
     }
 
@@ -172,17 +265,36 @@ impl<'d> serde_core::ser::Serializer for ValueSerializer<'d> {
         if v.is_nan() {
             v = v.copysign(1.0);
         }
-        self.dst.value(v)?;
+        self.dst.value(self.style.float_format.format(v))?;
         Ok(self.dst)
     }
 
     fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
-        Ok(self.dst)
+        let mut b = [0; 4];
+        let result = v.encode_utf8(&mut b);
+        self.serialize_str(result)
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        self.dst.value(v)?;
+        let builder = toml_writer::TomlStringBuilder::new(v).with_escapes(self.style.escapes);
+        let string = match self.style.string_style {
+            Some(StringStyle::Literal) if v.contains('\n') => builder
+                .as_ml_literal()
+                .unwrap_or_else(|| builder.as_default()),
+            Some(StringStyle::Literal) => {
+                builder.as_literal().unwrap_or_else(|| builder.as_default())
+            }
+            Some(StringStyle::Multiline) => builder
+                .as_ml_basic_pretty()
+                .or_else(|| builder.as_ml_literal())
+                .unwrap_or_else(|| builder.as_ml_basic()),
+            None => self
+                .style
+                .fold_strings
+                .and_then(|width| builder.as_ml_basic_folded(width))
+                .unwrap_or_else(|| builder.as_default()),
+        };
+        self.dst.value(string)?;
         Ok(self.dst)
     }
 
@@ -221,12 +333,28 @@ impl<'d> serde_core::ser::Serializer for ValueSerializer<'d> {
 
     fn serialize_newtype_struct<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
+        #[cfg(feature = "portable")]
+        if toml_datetime::ser::is_datetime_string(name) {
+            let datetime = toml_datetime::ser::datetime_from_string(value).map_err(dt_err)?;
+            return serde_core::ser::Serialize::serialize(&datetime, self);
+        }
+        if crate::de::is_raw_value(name) {
+            value.serialize(RawValueSerializer { dst: self.dst })?;
+            return Ok(self.dst);
+        }
+        if let Some(hint) = hint(name) {
+            return value.serialize(Self::with_style(self.dst, self.style.with_hint(hint)));
+        }
+        if is_commented(name) {
+            // Comments can't be written within values
+            return value.serialize(CommentedSerializer::new(|_| Ok(self)));
+        }
         value.serialize(self)
     }
 
@@ -242,7 +370,7 @@ impl<'d> serde_core::ser::Serializer for ValueSerializer<'d> {
     {
         self.dst.open_inline_table()?;
         self.dst.space()?;
-        self.dst.key(variant)?;
+        self.dst.key(escaped_key(variant, self.style.escapes))?;
         self.dst.space()?;
         self.dst.keyval_sep()?;
         self.dst.space()?;
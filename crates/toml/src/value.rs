@@ -15,7 +15,15 @@ use serde_core::ser;
 
 use crate::alloc_prelude::*;
 
-pub use toml_datetime::{Date, Datetime, DatetimeParseError, Offset, Time};
+#[cfg(feature = "portable")]
+pub use toml_datetime::PortableDatetime;
+pub use toml_datetime::as_datetime;
+#[cfg(feature = "portable")]
+pub use toml_datetime::as_string;
+pub use toml_datetime::{
+    Date, Datetime, DatetimeConversionError, DatetimeFormat, DatetimeParseError, FormattedDatetime,
+    LosslessDatetime, Offset, Time, Timestamp,
+};
 
 /// Type representing a TOML array, payload of the `Value::Array` variant
 pub type Array = Vec<Value>;
@@ -34,8 +42,8 @@ pub enum Value {
     Float(f64),
     /// Represents a TOML boolean
     Boolean(bool),
-    /// Represents a TOML datetime
-    Datetime(Datetime),
+    /// Represents a TOML datetime, keeping fractional seconds as written
+    Datetime(LosslessDatetime),
     /// Represents a TOML array
     Array(Array),
     /// Represents a TOML table
@@ -157,7 +165,7 @@ impl Value {
     /// ```
     pub fn as_datetime(&self) -> Option<&Datetime> {
         match *self {
-            Self::Datetime(ref s) => Some(s),
+            Self::Datetime(ref s) => Some(s.datetime()),
             _ => None,
         }
     }
@@ -299,6 +307,7 @@ impl_into_value!(Float: f64);
 impl_into_value!(Float: f32);
 impl_into_value!(Boolean: bool);
 impl_into_value!(Datetime: Datetime);
+impl_into_value!(Datetime: LosslessDatetime);
 impl_into_value!(Table: Table);
 
 /// Types that can be used to index a `toml::Value`
@@ -619,7 +628,7 @@ impl<'de> de::Deserializer<'de> for Value {
     {
         match (toml_datetime::de::is_datetime(name), self) {
             (true, Self::Datetime(v)) => {
-                visitor.visit_map(toml_datetime::de::DatetimeDeserializer::new(v))
+                visitor.visit_map(toml_datetime::de::DatetimeDeserializer::new_lossless(v))
             }
             (_, value) => value.deserialize_any(visitor),
         }
@@ -634,12 +643,14 @@ impl<'de> de::Deserializer<'de> for Value {
 
 pub(crate) struct SeqDeserializer {
     iter: vec::IntoIter<Value>,
+    index: usize,
 }
 
 impl SeqDeserializer {
     fn new(vec: Vec<Value>) -> Self {
         Self {
             iter: vec.into_iter(),
+            index: 0,
         }
     }
 }
@@ -652,7 +663,14 @@ impl<'de> de::SeqAccess<'de> for SeqDeserializer {
         T: de::DeserializeSeed<'de>,
     {
         match self.iter.next() {
-            Some(value) => seed.deserialize(value).map(Some),
+            Some(value) => {
+                let index = self.index;
+                self.index += 1;
+                seed.deserialize(value).map(Some).map_err(|mut error| {
+                    error.add_index(index);
+                    error
+                })
+            }
             None => Ok(None),
         }
     }
@@ -952,12 +970,21 @@ impl ser::Serializer for ValueSerializer {
 
     fn serialize_newtype_struct<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<Value, crate::ser::Error>
     where
         T: ser::Serialize + ?Sized,
     {
+        #[cfg(feature = "portable")]
+        if toml_datetime::ser::is_datetime_string(name) {
+            let datetime =
+                toml_datetime::ser::datetime_from_string(value).map_err(crate::ser::Error::new)?;
+            return Ok(Value::Datetime(datetime.into()));
+        }
+        if crate::ser::is_commented(name) {
+            return value.serialize(crate::ser::CommentedSerializer::new(|_| Ok(self)));
+        }
         value.serialize(self)
     }
 
@@ -140,6 +140,8 @@ fn duplicate_key_with_crlf() {
         str![[r#"
 TOML parse error at line 5, column 1
   |
+4 | a = 1
+  | - first defined here
 5 | a = 2
   | ^
 duplicate key
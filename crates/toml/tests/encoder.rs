@@ -72,19 +72,19 @@ fn from_decoded_scalar(
             .map_err(toml_test_harness::Error::new)
             .map(toml::Value::Boolean),
         toml_test_harness::DecodedScalar::Datetime(value) => value
-            .parse::<toml::value::Datetime>()
+            .parse::<toml::value::LosslessDatetime>()
             .map_err(toml_test_harness::Error::new)
             .map(toml::Value::Datetime),
         toml_test_harness::DecodedScalar::DatetimeLocal(value) => value
-            .parse::<toml::value::Datetime>()
+            .parse::<toml::value::LosslessDatetime>()
             .map_err(toml_test_harness::Error::new)
             .map(toml::Value::Datetime),
         toml_test_harness::DecodedScalar::DateLocal(value) => value
-            .parse::<toml::value::Datetime>()
+            .parse::<toml::value::LosslessDatetime>()
             .map_err(toml_test_harness::Error::new)
             .map(toml::Value::Datetime),
         toml_test_harness::DecodedScalar::TimeLocal(value) => value
-            .parse::<toml::value::Datetime>()
+            .parse::<toml::value::LosslessDatetime>()
             .map_err(toml_test_harness::Error::new)
             .map(toml::Value::Datetime),
     }
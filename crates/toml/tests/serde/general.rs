@@ -273,6 +273,7 @@ TOML parse error at line 1, column 7
 1 | bar = "a"
   |       ^^^
 invalid type: string "a", expected isize
+note: in `bar`
 
 "#]],
         str![[r#"
@@ -295,6 +296,7 @@ TOML parse error at line 2, column 7
 2 | bar = "a"
   |       ^^^
 invalid type: string "a", expected isize
+note: in `foo.bar`
 
 "#]],
         str![[r#"
@@ -914,6 +916,7 @@ TOML parse error at line 8, column 9
 8 | debug = 'a'
   |         ^^^
 expected a boolean or an integer
+note: in `profile.dev.debug`
 
 "#]]
     );
@@ -938,6 +941,64 @@ TOML parse error at line 8, column 17
 8 | dev = { debug = 'a' }
   |                 ^^^
 expected a boolean or an integer
+note: in `profile.dev.debug`
+
+"#]]
+    );
+}
+
+#[test]
+fn error_includes_path() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Config {
+        servers: Vec<Server>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Server {
+        #[serde(rename = "port number")]
+        port: u16,
+    }
+
+    let input = r#"
+[[servers]]
+"port number" = 80
+
+[[servers]]
+"port number" = "http"
+"#;
+    let path = [
+        toml::de::PathSegment::Key("servers".to_owned()),
+        toml::de::PathSegment::Index(1),
+        toml::de::PathSegment::Key("port number".to_owned()),
+    ];
+
+    let err = crate::from_str::<Config>(input).unwrap_err();
+    assert_eq!(err.path(), path);
+    assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+TOML parse error at line 6, column 17
+  |
+6 | "port number" = "http"
+  |                 ^^^^^^
+invalid type: string "http", expected u16
+note: in `servers[1]."port number"`
+
+"#]]
+    );
+
+    let value = crate::from_str::<crate::SerdeValue>(input).unwrap();
+    let err = value.try_into::<Config>().unwrap_err();
+    assert_eq!(err.path(), path);
+    assert_eq!(err.span(), None);
+    assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+invalid type: string "http", expected u16
+in `servers[1]."port number"`
 
 "#]]
     );
@@ -2181,3 +2242,183 @@ key = "value"
 "#]]
     );
 }
+
+#[test]
+fn from_slice_encodings() {
+    type Table = BTreeMap<String, String>;
+
+    let utf8_bom = b"\xEF\xBB\xBFkey = 'value'";
+    let utf16_le = b"\xFF\xFEk\0e\0y\0 \0=\0 \0'\0v\0a\0l\0u\0e\0'\0";
+    let utf16_be = b"\xFE\xFF\0k\0e\0y\0 \0=\0 \0'\0v\0a\0l\0u\0e\0'";
+    for input in [&utf8_bom[..], &utf16_le[..], &utf16_be[..]] {
+        let table = toml::from_slice::<Table>(input).unwrap();
+        assert_data_eq!(
+            table.to_debug(),
+            str![[r#"
+{
+    "key": "value",
+}
+
+"#]]
+        );
+    }
+}
+
+#[test]
+fn from_slice_borrow() {
+    type Table<'s> = BTreeMap<&'s str, &'s str>;
+
+    let input = b"\xEF\xBB\xBFkey = 'value'";
+    let table = toml::from_slice::<Table<'_>>(input).unwrap();
+    assert_data_eq!(
+        table.to_debug(),
+        str![[r#"
+{
+    "key": "value",
+}
+
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn as_datetime() {
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Release {
+        #[serde(with = "toml::value::as_datetime")]
+        published: chrono::DateTime<chrono::FixedOffset>,
+        #[serde(with = "toml::value::as_datetime::option")]
+        yanked: Option<chrono::NaiveDate>,
+    }
+
+    let input = "published = 1979-05-27T00:32:00-07:00\nyanked = 1979-05-28\n";
+    let release = crate::from_str::<Release>(input).unwrap();
+    assert_eq!(release.published.timestamp(), 296_638_320);
+    assert_eq!(release.yanked, chrono::NaiveDate::from_ymd_opt(1979, 5, 28));
+    assert_data_eq!(
+        crate::to_string(&release).unwrap(),
+        str![[r#"
+published = 1979-05-27T00:32:00-07:00
+yanked = 1979-05-28
+
+"#]]
+    );
+
+    let err = crate::from_str::<Release>("published = 1979-05-27T00:32:00\n").unwrap_err();
+    assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+TOML parse error at line 1, column 13
+  |
+1 | published = 1979-05-27T00:32:00
+  |             ^^^^^^^^^^^^^^^^^^^
+expected an offset datetime
+note: in `published`
+
+"#]]
+    );
+}
+
+#[test]
+fn datetime_fraction_round_trip() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Times {
+        a: toml::value::LosslessDatetime,
+        b: toml::value::LosslessDatetime,
+        c: crate::Datetime,
+    }
+
+    let input = "a = 07:32:00.500\nb = 1979-05-27T07:32:00.1234567891Z\nc = 07:32:00.500\n";
+    let times = crate::from_str::<Times>(input).unwrap();
+    assert_eq!(times.a.fraction(), Some("500"));
+    assert_data_eq!(
+        crate::to_string(&times).unwrap(),
+        str![[r#"
+a = 07:32:00.500
+b = 1979-05-27T07:32:00.1234567891Z
+c = 07:32:00.5
+
+"#]]
+    );
+
+    let format = toml::value::DatetimeFormat::new()
+        .with_space_separator()
+        .with_precision(3);
+    let mut output = String::new();
+    let serializer = toml::ser::ValueSerializer::new(&mut output).datetime_format(format);
+    times.b.serialize(serializer).unwrap();
+    assert_data_eq!(output, str!["1979-05-27 07:32:00.123Z"]);
+}
+
+#[test]
+fn datetime_fraction_value_round_trip() {
+    let input = "a = 07:32:00.500\nb = 1979-05-27T07:32:00.1234567891Z\n";
+    let table = crate::from_str::<crate::SerdeTable>(input).unwrap();
+    assert_data_eq!(crate::to_string(&table).unwrap(), input);
+
+    let b = table["b"]
+        .clone()
+        .try_into::<toml::value::LosslessDatetime>()
+        .unwrap();
+    assert_eq!(b.fraction(), Some("1234567891"));
+
+    let value = crate::SerdeValue::Table(table);
+    assert_data_eq!(crate::to_string(&value).unwrap(), input);
+}
+
+#[test]
+#[cfg(feature = "portable")]
+fn datetime_as_string() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Release {
+        #[serde(with = "toml::value::as_string")]
+        published: crate::Datetime,
+        #[serde(default, with = "toml::value::as_string::option")]
+        yanked: Option<crate::Datetime>,
+        updated: toml::value::PortableDatetime,
+    }
+
+    let release = Release {
+        published: "1979-05-27T07:32:00Z".parse().unwrap(),
+        yanked: Some("1979-05-28".parse().unwrap()),
+        updated: toml::value::PortableDatetime("1979-05-27T00:32:00-07:00".parse().unwrap()),
+    };
+
+    let toml = crate::to_string(&release).unwrap();
+    assert_data_eq!(
+        &toml,
+        str![[r#"
+published = 1979-05-27T07:32:00Z
+yanked = 1979-05-28
+updated = 1979-05-27T00:32:00-07:00
+
+"#]]
+    );
+    assert_eq!(crate::from_str::<Release>(&toml).unwrap(), release);
+
+    let json = serde_json::to_string(&release).unwrap();
+    assert_data_eq!(
+        &json,
+        str![[
+            r#"{"published":"1979-05-27T07:32:00Z","yanked":"1979-05-28","updated":"1979-05-27T00:32:00-07:00"}"#
+        ]]
+    );
+    assert_eq!(serde_json::from_str::<Release>(&json).unwrap(), release);
+
+    let toml = "published = '1979-05-27T07:32:00Z'\nupdated = '1979-05-27T00:32:00-07:00'\n";
+    let release = crate::from_str::<Release>(toml).unwrap();
+    assert_eq!(release.yanked, None);
+    assert_data_eq!(
+        crate::to_string(&release).unwrap(),
+        str![[r#"
+published = 1979-05-27T07:32:00Z
+updated = 1979-05-27T00:32:00-07:00
+
+"#]]
+    );
+
+    let value = crate::SerdeValue::try_from(&release).unwrap();
+    assert!(value.get("published").unwrap().is_datetime());
+    assert!(value.get("updated").unwrap().is_datetime());
+}
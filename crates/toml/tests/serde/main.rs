@@ -14,7 +14,10 @@ mod de_enum;
 mod de_errors;
 mod de_key;
 mod general;
+mod raw_value;
+mod ser_comments;
 mod ser_enum;
+mod ser_hints;
 mod ser_key;
 mod ser_tables_last;
 mod ser_to_string;
@@ -22,6 +25,7 @@ mod ser_to_string_pretty;
 mod spanned;
 
 use toml::Spanned;
+use toml::SpannedEntry;
 use toml::from_str;
 use toml::to_string;
 use toml::to_string_pretty;
@@ -0,0 +1,181 @@
+use serde::Deserialize;
+use serde::Serialize;
+use snapbox::assert_data_eq;
+use snapbox::str;
+use toml::de::RawValue;
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Manifest<'a> {
+    #[serde(borrow)]
+    plugin: Plugin<'a>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Plugin<'a> {
+    name: String,
+    #[serde(borrow)]
+    config: RawValue<'a>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct LintConfig {
+    level: u32,
+    tags: Vec<String>,
+}
+
+#[test]
+fn inline_value_is_verbatim() {
+    let input = r#"
+[plugin]
+name = "lint"
+config = {   level = 0x3,tags = [ 'a', "b" ] }
+"#;
+    let manifest: Manifest<'_> = toml::from_str(input).unwrap();
+    let config = &manifest.plugin.config;
+    assert_data_eq!(
+        config.get(),
+        str![[r#"{   level = 0x3,tags = [ 'a', "b" ] }"#]]
+    );
+    assert_eq!(&input[config.span()], config.get());
+
+    let config: LintConfig = config.deserialize_into().unwrap();
+    assert_eq!(
+        config,
+        LintConfig {
+            level: 3,
+            tags: vec!["a".to_owned(), "b".to_owned()],
+        }
+    );
+}
+
+#[test]
+fn standard_table() {
+    let input = r#"
+[plugin]
+name = "lint"
+
+[plugin.config]
+level = 3 # comment
+tags = ["a", "b"]
+extra.'quoted key' = true
+
+[plugin.config.nested]
+
+[[plugin.config.rules]]
+id = 1
+
+[[plugin.config.rules]]
+id = 2
+"#;
+    let manifest: Manifest<'_> = toml::from_str(input).unwrap();
+    let config = &manifest.plugin.config;
+    assert_data_eq!(
+        config.get(),
+        str![[
+            r#"{ level = 3, tags = ["a", "b"], extra = { 'quoted key' = true }, nested = {}, rules = [{ id = 1 }, { id = 2 }] }"#
+        ]]
+    );
+    assert_data_eq!(&input[config.span()], str!["[plugin.config]"]);
+
+    let config: LintConfig = config.deserialize_into().unwrap();
+    assert_eq!(
+        config,
+        LintConfig {
+            level: 3,
+            tags: vec!["a".to_owned(), "b".to_owned()],
+        }
+    );
+}
+
+#[test]
+fn scalar() {
+    let value = RawValue::parse("1_000").unwrap();
+    assert_eq!(value.get(), "1_000");
+    assert_eq!(value.span(), 0..5);
+    assert_eq!(value.deserialize_into::<u32>().unwrap(), 1000);
+    assert_eq!(value.to_string(), "1_000");
+}
+
+#[test]
+fn serialize_verbatim() {
+    let input = r#"
+[plugin]
+name = "lint"
+config = {   level = 0x3,tags = [ 'a', "b" ] }
+"#;
+    let manifest: Manifest<'_> = toml::from_str(input).unwrap();
+    assert_data_eq!(
+        toml::to_string(&manifest).unwrap(),
+        str![[r#"
+[plugin]
+name = "lint"
+config = {   level = 0x3,tags = [ 'a', "b" ] }
+
+"#]]
+    );
+}
+
+#[test]
+fn deserialize_owned() {
+    #[derive(Deserialize, Debug)]
+    struct Plugin {
+        #[serde(deserialize_with = "RawValue::deserialize_owned")]
+        config: RawValue<'static>,
+    }
+
+    fn parse<T: serde::de::DeserializeOwned>(input: String) -> T {
+        toml::from_str(&input).unwrap()
+    }
+
+    let plugin: Plugin = parse("config = [1, 2]".to_owned());
+    assert_eq!(plugin.config.get(), "[1, 2]");
+    assert_eq!(plugin.config.span(), 9..15);
+}
+
+#[test]
+fn from_slice_utf16() {
+    #[derive(Deserialize, Debug)]
+    struct Release<'a> {
+        #[serde(borrow)]
+        config: RawValue<'a>,
+        published: toml::value::LosslessDatetime,
+    }
+
+    let input = "config = { level = 0x3 }\npublished = 1979-05-27T07:32:00.1234567891Z\n";
+    let mut utf16_le = vec![0xFF, 0xFE];
+    utf16_le.extend(input.encode_utf16().flat_map(u16::to_le_bytes));
+
+    let release = toml::from_slice::<Release<'_>>(&utf16_le).unwrap();
+    assert_eq!(release.config.get(), "{ level = 0x3 }");
+    assert_eq!(release.config.span(), 9..24);
+    assert_eq!(
+        release.published.to_string(),
+        "1979-05-27T07:32:00.1234567891Z"
+    );
+}
+
+#[test]
+fn requires_source() {
+    let value = toml::Value::Integer(1);
+    let err = value.try_into::<RawValue<'_>>().unwrap_err();
+    assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+invalid type: newtype struct, expected a TOML value with its source text
+
+"#]]
+    );
+}
+
+#[test]
+fn document() {
+    let value = toml::from_str::<RawValue<'_>>("b = 1\na = [\n  2,\n]\n").unwrap();
+    assert_data_eq!(
+        value.get(),
+        str![[r#"
+{ b = 1, a = [
+  2,
+] }
+"#]]
+    );
+}
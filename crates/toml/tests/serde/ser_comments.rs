@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use snapbox::assert_data_eq;
+use snapbox::prelude::*;
+use snapbox::str;
+use toml::ser::Commented;
+use toml::ser::Dotted;
+use toml::ser::Inline;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Manifest {
+    package: Commented<Package>,
+    features: Commented<BTreeMap<String, Commented<Vec<String>>>>,
+    bin: Commented<Vec<Commented<Target>>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Package {
+    name: Commented<String>,
+    version: String,
+    metadata: Commented<Dotted<Metadata>>,
+    badges: Commented<Inline<BTreeMap<String, Commented<String>>>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Metadata {
+    msrv: Commented<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Target {
+    name: String,
+}
+
+fn manifest() -> Manifest {
+    Manifest {
+        package: Commented::new(Package {
+            name: Commented::new("toml".to_owned())
+                .with_comment("The name of the package")
+                .with_trailing_comment("not yet published"),
+            version: "1.0.0".to_owned(),
+            metadata: Commented::new(Dotted(Metadata {
+                msrv: Commented::new("1.85".to_owned()).with_trailing_comment("edition 2024"),
+            }))
+            .with_comment("Metadata for other tools"),
+            badges: Commented::new(Inline(BTreeMap::from([(
+                "maintenance".to_owned(),
+                Commented::new("passively-maintained".to_owned()).with_comment("dropped"),
+            )])))
+            .with_trailing_comment("see crates.io"),
+        })
+        .with_comment("Package\n\nSee the docs")
+        .with_trailing_comment("required"),
+        features: Commented::new(BTreeMap::from([(
+            "default".to_owned(),
+            Commented::new(vec!["std".to_owned()]).with_comment("Enabled by default"),
+        )])),
+        bin: Commented::new(vec![
+            Commented::new(Target {
+                name: "a".to_owned(),
+            })
+            .with_comment("The first binary"),
+            Commented::new(Target {
+                name: "b".to_owned(),
+            })
+            .with_trailing_comment("the second binary"),
+        ])
+        .with_comment("Binaries"),
+    }
+}
+
+#[test]
+fn to_string() {
+    let manifest = manifest();
+    let toml = crate::to_string(&manifest).unwrap();
+    assert_data_eq!(
+        &toml,
+        str![[r#"
+# Package
+#
+# See the docs
+[package] # required
+# The name of the package
+name = "toml" # not yet published
+version = "1.0.0"
+# Metadata for other tools
+metadata.msrv = "1.85" # edition 2024
+badges = { maintenance = "passively-maintained" } # see crates.io
+
+[features]
+# Enabled by default
+default = ["std"]
+
+# Binaries
+# The first binary
+[[bin]]
+name = "a"
+
+[[bin]] # the second binary
+name = "b"
+
+"#]]
+        .raw()
+    );
+    let roundtrip: Manifest = crate::from_str(&toml).unwrap();
+    assert_eq!(roundtrip.package.get_ref().version, "1.0.0");
+    assert_eq!(roundtrip.package.comment(), None);
+}
+
+#[test]
+fn to_string_pretty() {
+    let manifest = manifest();
+    let toml = crate::to_string_pretty(&manifest).unwrap();
+    assert_data_eq!(
+        &toml,
+        str![[r#"
+# Package
+#
+# See the docs
+[package] # required
+# The name of the package
+name = "toml" # not yet published
+version = "1.0.0"
+# Metadata for other tools
+metadata.msrv = "1.85" # edition 2024
+badges = { maintenance = "passively-maintained" } # see crates.io
+
+[features]
+# Enabled by default
+default = ["std"]
+
+# Binaries
+# The first binary
+[[bin]]
+name = "a"
+
+[[bin]] # the second binary
+name = "b"
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn root() {
+    #[derive(Serialize)]
+    struct Config {
+        name: &'static str,
+        table: BTreeMap<&'static str, &'static str>,
+    }
+
+    let config = Commented::new(Config {
+        name: "toml",
+        table: BTreeMap::from([("key", "value")]),
+    })
+    .with_comment("Generated")
+    .with_trailing_comment("do not edit");
+    assert_data_eq!(
+        crate::to_string(&config).unwrap(),
+        str![[r#"
+# Generated
+# do not edit
+name = "toml"
+
+[table]
+key = "value"
+
+"#]]
+        .raw()
+    );
+
+    let config = Commented::new(BTreeMap::from([(
+        "table",
+        BTreeMap::from([("key", "value")]),
+    )]))
+    .with_comment("Generated");
+    assert_data_eq!(
+        crate::to_string(&config).unwrap(),
+        str![[r#"
+# Generated
+
+[table]
+key = "value"
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn empty_table() {
+    let config = BTreeMap::from([(
+        "a",
+        BTreeMap::from([(
+            "b",
+            Commented::new(BTreeMap::<&str, &str>::new()).with_comment("Nothing here"),
+        )]),
+    )]);
+    assert_data_eq!(
+        crate::to_string(&config).unwrap(),
+        str![[r#"
+# Nothing here
+[a.b]
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn to_string_value() {
+    let value = BTreeMap::from([(
+        "key",
+        Commented::new(vec![Commented::new(1).with_comment("one")]).with_comment("dropped"),
+    )]);
+    assert_data_eq!(
+        crate::to_string_value(&value).unwrap(),
+        str!["{ key = [1] }"].raw()
+    );
+}
+
+#[test]
+fn to_value() {
+    let value = toml::Value::try_from(BTreeMap::from([(
+        "key",
+        Commented::new(1).with_comment("dropped"),
+    )]))
+    .unwrap();
+    assert_eq!(value["key"].as_integer(), Some(1));
+}
+
+#[test]
+fn invalid_comment() {
+    let value = BTreeMap::from([("key", Commented::new(1).with_trailing_comment("a\nb"))]);
+    assert_data_eq!(
+        crate::to_string(&value).unwrap_err().to_string(),
+        str!["trailing comment may not contain control characters other than tabs"]
+    );
+
+    let value = BTreeMap::from([("key", Commented::new(1).with_comment("a\u{7f}b"))]);
+    assert_data_eq!(
+        crate::to_string(&value).unwrap_err().to_string(),
+        str!["comment may not contain control characters other than tabs and newlines"]
+    );
+}
+
+#[test]
+fn other_serializers() {
+    let value = Commented::new(1)
+        .with_comment("one")
+        .with_trailing_comment("first");
+    let json = serde_json::to_value(&value).unwrap();
+    assert_eq!(json["$__serde_spanned_private_comment"], "one");
+    assert_eq!(json["$__serde_spanned_private_value"], 1);
+}
@@ -229,3 +229,306 @@ debug = true
 "#]],
     );
 }
+
+#[test]
+fn layout() {
+    #[derive(Serialize)]
+    struct Package {
+        name: &'static str,
+        keywords: Vec<&'static str>,
+        matrix: Vec<Vec<u32>>,
+        dependency: Dependency,
+        variant: Variant,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        missing: Option<u32>,
+    }
+
+    #[derive(Serialize)]
+    struct Dependency {
+        version: &'static str,
+        optional: Option<bool>,
+        features: Vec<&'static str>,
+    }
+
+    #[derive(Serialize)]
+    enum Variant {
+        Tuple(u32, u32),
+    }
+
+    let package = Package {
+        name: "toml",
+        keywords: vec!["encoding", "toml", "no_std"],
+        matrix: vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
+        dependency: Dependency {
+            version: "1.0",
+            optional: None,
+            features: vec!["std", "serde"],
+        },
+        variant: Variant::Tuple(1, 2),
+        missing: None,
+    };
+
+    let mut output = toml::ser::Buffer::new();
+    let layout = toml::ser::TomlLayout::new().with_max_width(30);
+    let serializer = toml::Serializer::pretty(&mut output).layout(layout);
+    package.serialize(serializer).unwrap();
+    assert_data_eq!(
+        output.to_string(),
+        str![[r#"
+name = "toml"
+keywords = [
+    "encoding",
+    "toml",
+    "no_std",
+]
+matrix = [
+    [1, 2, 3],
+    [4, 5, 6],
+    [7, 8, 9],
+]
+
+[dependency]
+version = "1.0"
+features = ["std", "serde"]
+
+[variant]
+Tuple = [1, 2]
+
+"#]]
+    );
+
+    #[derive(Serialize)]
+    struct Inline {
+        dependencies: Vec<Vec<Dependency>>,
+    }
+
+    let inline = Inline {
+        dependencies: vec![vec![
+            Dependency {
+                version: "1.0",
+                optional: None,
+                features: vec!["std"],
+            },
+            Dependency {
+                version: "2.0",
+                optional: Some(true),
+                features: vec!["std", "serde", "preserve_order"],
+            },
+        ]],
+    };
+    let mut output = toml::ser::Buffer::new();
+    let layout = toml::ser::TomlLayout::new().with_max_width(50);
+    let serializer = toml::Serializer::new(&mut output).layout(layout);
+    inline.serialize(serializer).unwrap();
+    assert_data_eq!(
+        output.to_string(),
+        str![[r#"
+dependencies = [
+    [
+        { version = "1.0", features = ["std"] },
+        { version = "2.0", optional = true, features = ["std", "serde", "preserve_order"] },
+    ],
+]
+
+"#]]
+    );
+    crate::from_str::<crate::SerdeTable>(&output.to_string()).unwrap();
+
+    let mut output = toml::ser::Buffer::new();
+    let layout = layout.with_version(toml::ser::TomlVersion::V1_1);
+    let serializer = toml::Serializer::new(&mut output).layout(layout);
+    inline.serialize(serializer).unwrap();
+    assert_data_eq!(
+        output.to_string(),
+        str![[r#"
+dependencies = [
+    [
+        { version = "1.0", features = ["std"] },
+        {
+            version = "2.0",
+            optional = true,
+            features = [
+                "std",
+                "serde",
+                "preserve_order",
+            ],
+        },
+    ],
+]
+
+"#]]
+    );
+    crate::from_str::<crate::SerdeTable>(&output.to_string()).unwrap();
+}
+
+#[test]
+fn float_format() {
+    #[derive(Serialize)]
+    struct Measurements {
+        mass: f64,
+        ratio: f32,
+        distance: f64,
+        missing: f64,
+        samples: Vec<f64>,
+    }
+
+    let measurements = Measurements {
+        mass: 2.0,
+        ratio: 0.125,
+        distance: 149_597_870_700.0,
+        missing: -f64::NAN,
+        samples: vec![0.5, -1.0, f64::INFINITY],
+    };
+
+    let mut output = toml::ser::Buffer::new();
+    let format = toml::ser::TomlFloatFormat::new()
+        .as_fixed(2)
+        .with_exponent_above(1e6)
+        .with_plus_sign(true);
+    let serializer = toml::Serializer::pretty(&mut output).float_format(format);
+    measurements.serialize(serializer).unwrap();
+    assert_data_eq!(
+        output.to_string(),
+        str![[r#"
+mass = +2.00
+ratio = +0.12
+distance = +1.50e11
+missing = +nan
+samples = [
+    +0.50,
+    -1.00,
+    +inf,
+]
+
+"#]]
+    );
+}
+
+#[test]
+fn integer_format() {
+    #[derive(Serialize)]
+    struct Limits {
+        max_size: u64,
+        min_offset: i32,
+        mask: u32,
+        nested: Vec<i64>,
+    }
+
+    let limits = Limits {
+        max_size: 10_000_000,
+        min_offset: -4096,
+        mask: 0xDEAD_BEEF,
+        nested: vec![1_000, -1_000],
+    };
+
+    let mut output = toml::ser::Buffer::new();
+    let format = toml::ser::TomlIntegerFormat::new()
+        .as_hex_lower()
+        .with_grouping(true);
+    let serializer = toml::Serializer::new(&mut output).integer_format(format);
+    limits.serialize(serializer).unwrap();
+    assert_data_eq!(
+        output.to_string(),
+        str![[r#"
+max_size = 0x98_9680
+min_offset = -4_096
+mask = 0xdead_beef
+nested = [0x3e8, -1_000]
+
+"#]]
+    );
+}
+
+#[test]
+fn fold_strings() {
+    #[derive(Serialize)]
+    struct Query {
+        name: &'static str,
+        description: String,
+        sql: &'static str,
+        tags: Vec<&'static str>,
+    }
+
+    let query = Query {
+        name: "packages",
+        description: "Lists every package along with its latest published version".to_owned(),
+        sql: "SELECT name, version FROM packages\nWHERE yanked = false ORDER BY name",
+        tags: vec!["a tag that is too long to fit within the width"],
+    };
+
+    let mut output = toml::ser::Buffer::new();
+    let serializer = toml::Serializer::pretty(&mut output).fold_strings(30);
+    query.serialize(serializer).unwrap();
+    assert_data_eq!(
+        output.to_string(),
+        str![[r#"
+name = "packages"
+description = """
+Lists every package along \
+with its latest published \
+version"""
+sql = """
+SELECT name, version FROM \
+packages
+WHERE yanked = false ORDER \
+BY name"""
+tags = ["""
+a tag that is too long to \
+fit within the width"""]
+
+"#]]
+        .raw()
+    );
+
+    let value = toml::from_str::<toml::Table>(&output.to_string()).unwrap();
+    assert_eq!(
+        value["description"].as_str(),
+        Some(query.description.as_str())
+    );
+    assert_eq!(value["sql"].as_str(), Some(query.sql));
+}
+
+#[test]
+fn escapes() {
+    #[derive(Serialize)]
+    struct Config {
+        banner: &'static str,
+        separator: char,
+        #[serde(rename = "données")]
+        data: std::collections::BTreeMap<&'static str, &'static str>,
+    }
+
+    let config = Config {
+        banner: "\u{1b}[1mBienvenue à bord\u{1b}[0m",
+        separator: '·',
+        data: [("clé", "valeur"), ("plain", "naïve")]
+            .into_iter()
+            .collect(),
+    };
+
+    let mut output = toml::ser::Buffer::new();
+    let escapes = toml::ser::TomlEscapes::new()
+        .with_version(toml::ser::TomlVersion::V1_1)
+        .with_ascii_only(true);
+    let serializer = toml::Serializer::pretty(&mut output).escapes(escapes);
+    config.serialize(serializer).unwrap();
+    let output = output.to_string();
+    assert!(output.is_ascii());
+    assert_data_eq!(
+        &output,
+        str![[r#"
+banner = "\e[1mBienvenue \xE0 bord\e[0m"
+separator = "\xB7"
+
+["donn\xE9es"]
+"cl\xE9" = "valeur"
+plain = "na\xEFve"
+
+"#]]
+        .raw()
+    );
+
+    let value = toml::from_str::<toml::Table>(&output).unwrap();
+    assert_eq!(value["banner"].as_str(), Some(config.banner));
+    assert_eq!(value["données"]["clé"].as_str(), Some("valeur"));
+}
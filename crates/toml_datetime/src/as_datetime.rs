@@ -0,0 +1,95 @@
+//! Serialize date library types as a TOML [`Datetime`]
+//!
+//! For use with `#[serde(with = "toml_datetime::as_datetime")]` on any type that converts to and
+//! from [`Datetime`], like those of `chrono`, `time` and `jiff` when their features are enabled.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Release {
+//!     #[serde(with = "toml_datetime::as_datetime")]
+//!     published: chrono::DateTime<chrono::FixedOffset>,
+//!     #[serde(with = "toml_datetime::as_datetime::option")]
+//!     yanked: Option<chrono::NaiveDate>,
+//! }
+//! ```
+
+use core::fmt::Display;
+
+use crate::Datetime;
+
+/// Serialize `value` as a [`Datetime`]
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Clone + TryInto<Datetime>,
+    T::Error: Display,
+    S: serde_core::ser::Serializer,
+{
+    let datetime: Datetime = value
+        .clone()
+        .try_into()
+        .map_err(serde_core::ser::Error::custom)?;
+    serde_core::ser::Serialize::serialize(&datetime, serializer)
+}
+
+/// Deserialize a [`Datetime`] into `T`
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    Datetime: TryInto<T>,
+    <Datetime as TryInto<T>>::Error: Display,
+    D: serde_core::de::Deserializer<'de>,
+{
+    let datetime: Datetime = serde_core::de::Deserialize::deserialize(deserializer)?;
+    datetime.try_into().map_err(serde_core::de::Error::custom)
+}
+
+/// Serialize `Option`s of date library types as an optional [`Datetime`]
+///
+/// For use with `#[serde(with = "toml_datetime::as_datetime::option")]`
+pub mod option {
+    use core::fmt::Display;
+
+    use crate::Datetime;
+
+    /// Serialize `value` as an optional [`Datetime`]
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Clone + TryInto<Datetime>,
+        T::Error: Display,
+        S: serde_core::ser::Serializer,
+    {
+        match value {
+            Some(value) => serializer.serialize_some(&AsDatetime(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional [`Datetime`] into `Option<T>`
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        Datetime: TryInto<T>,
+        <Datetime as TryInto<T>>::Error: Display,
+        D: serde_core::de::Deserializer<'de>,
+    {
+        let datetime: Option<Datetime> = serde_core::de::Deserialize::deserialize(deserializer)?;
+        datetime
+            .map(|datetime| datetime.try_into().map_err(serde_core::de::Error::custom))
+            .transpose()
+    }
+
+    struct AsDatetime<'a, T>(&'a T);
+
+    impl<T> serde_core::ser::Serialize for AsDatetime<'_, T>
+    where
+        T: Clone + TryInto<Datetime>,
+        T::Error: Display,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde_core::ser::Serializer,
+        {
+            super::serialize(self.0, serializer)
+        }
+    }
+}
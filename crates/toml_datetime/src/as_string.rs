@@ -0,0 +1,190 @@
+//! Serialize date library types as a TOML [`Datetime`] in TOML and as a string elsewhere
+//!
+//! For structs shared between TOML and other formats, like JSON, where a [`Datetime`] would
+//! otherwise serialize as an opaque struct.
+//! When deserializing, either a TOML datetime or a string is accepted.
+//!
+//! For use with `#[serde(with = "toml_datetime::as_string")]` on any type that converts to and
+//! from [`Datetime`].
+//! Alternatively, see [`PortableDatetime`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Release {
+//!     // `published = 1979-05-27T07:32:00Z` in TOML, `"published": "1979-05-27T07:32:00Z"` in JSON
+//!     #[serde(with = "toml_datetime::as_string")]
+//!     published: toml_datetime::Datetime,
+//!     #[serde(with = "toml_datetime::as_string::option")]
+//!     yanked: Option<toml_datetime::Datetime>,
+//! }
+//! ```
+
+use core::fmt;
+use core::fmt::Display;
+
+use crate::Datetime;
+
+/// Serialize `value` as a TOML [`Datetime`] or a string, depending on the format
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Clone + TryInto<Datetime>,
+    T::Error: Display,
+    S: serde_core::ser::Serializer,
+{
+    let datetime: Datetime = value
+        .clone()
+        .try_into()
+        .map_err(serde_core::ser::Error::custom)?;
+    serializer.serialize_newtype_struct(crate::datetime::STRING_NAME, &DatetimeString(datetime))
+}
+
+/// Deserialize a TOML [`Datetime`] or a string into `T`
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    Datetime: TryInto<T>,
+    <Datetime as TryInto<T>>::Error: Display,
+    D: serde_core::de::Deserializer<'de>,
+{
+    let datetime = deserializer.deserialize_any(DatetimeOrStringVisitor)?;
+    datetime.try_into().map_err(serde_core::de::Error::custom)
+}
+
+/// Serialize `Option`s of date library types as an optional TOML [`Datetime`] or string
+///
+/// For use with `#[serde(with = "toml_datetime::as_string::option")]`
+pub mod option {
+    use core::fmt::Display;
+
+    use crate::Datetime;
+    use crate::PortableDatetime;
+
+    /// Serialize `value` as an optional TOML [`Datetime`] or string, depending on the format
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Clone + TryInto<Datetime>,
+        T::Error: Display,
+        S: serde_core::ser::Serializer,
+    {
+        match value {
+            Some(value) => serializer.serialize_some(&PortableDatetime(value.clone())),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional TOML [`Datetime`] or string into `Option<T>`
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        Datetime: TryInto<T>,
+        <Datetime as TryInto<T>>::Error: Display,
+        D: serde_core::de::Deserializer<'de>,
+    {
+        let value: Option<PortableDatetime<T>> =
+            serde_core::de::Deserialize::deserialize(deserializer)?;
+        Ok(value.map(|value| value.0))
+    }
+}
+
+/// A date library type that serializes as a TOML [`Datetime`] in TOML and as a string elsewhere
+///
+/// See [`as_string`][crate::as_string] for details.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Release {
+///     published: toml_datetime::PortableDatetime,
+/// }
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
+pub struct PortableDatetime<T = Datetime>(pub T);
+
+impl<T> From<T> for PortableDatetime<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> core::ops::Deref for PortableDatetime<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for PortableDatetime<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> serde_core::ser::Serialize for PortableDatetime<T>
+where
+    T: Clone + TryInto<Datetime>,
+    T::Error: Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::ser::Serializer,
+    {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T> serde_core::de::Deserialize<'de> for PortableDatetime<T>
+where
+    Datetime: TryInto<T>,
+    <Datetime as TryInto<T>>::Error: Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde_core::de::Deserializer<'de>,
+    {
+        deserialize(deserializer).map(Self)
+    }
+}
+
+/// The payload of the [`STRING_NAME`][crate::datetime::STRING_NAME] newtype struct
+struct DatetimeString(Datetime);
+
+impl serde_core::ser::Serialize for DatetimeString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::ser::Serializer,
+    {
+        serializer.collect_str(&self.0)
+    }
+}
+
+struct DatetimeOrStringVisitor;
+
+impl<'de> serde_core::de::Visitor<'de> for DatetimeOrStringVisitor {
+    type Value = Datetime;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a TOML datetime or a string containing a datetime")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Datetime, E>
+    where
+        E: serde_core::de::Error,
+    {
+        s.parse().map_err(serde_core::de::Error::custom)
+    }
+
+    fn visit_map<V>(self, mut visitor: V) -> Result<Datetime, V::Error>
+    where
+        V: serde_core::de::MapAccess<'de>,
+    {
+        match crate::de::VisitMap::next_key_seed(&mut visitor)? {
+            Some(crate::de::VisitMap::Datetime(datetime)) => Ok(datetime.into()),
+            _ => Err(serde_core::de::Error::invalid_type(
+                serde_core::de::Unexpected::Map,
+                &self,
+            )),
+        }
+    }
+}
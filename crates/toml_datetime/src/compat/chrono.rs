@@ -0,0 +1,199 @@
+use ::chrono::Datelike as _;
+use ::chrono::Timelike as _;
+
+use super::local_date;
+use super::local_datetime;
+use super::local_time;
+use super::offset_datetime;
+use super::to_time;
+use super::to_year;
+use crate::Date;
+use crate::Datetime;
+use crate::DatetimeConversionError;
+use crate::Offset;
+use crate::Time;
+
+impl TryFrom<::chrono::NaiveDate> for Date {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::chrono::NaiveDate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: to_year(value.year())?,
+            month: value.month() as u8,
+            day: value.day() as u8,
+        })
+    }
+}
+
+impl TryFrom<Date> for ::chrono::NaiveDate {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Date) -> Result<Self, Self::Error> {
+        Self::from_ymd_opt(
+            i32::from(value.year),
+            u32::from(value.month),
+            u32::from(value.day),
+        )
+        .ok_or_else(|| DatetimeConversionError::new("invalid date"))
+    }
+}
+
+impl From<::chrono::NaiveTime> for Time {
+    fn from(value: ::chrono::NaiveTime) -> Self {
+        // chrono represents leap seconds as an overflow of the nanoseconds
+        let (second, nanosecond) = match value.nanosecond().checked_sub(NANOS_PER_SECOND) {
+            Some(nanosecond) => (value.second() + 1, nanosecond),
+            None => (value.second(), value.nanosecond()),
+        };
+        to_time(
+            value.hour() as u8,
+            value.minute() as u8,
+            second as u8,
+            nanosecond,
+        )
+    }
+}
+
+impl TryFrom<Time> for ::chrono::NaiveTime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Time) -> Result<Self, Self::Error> {
+        let (second, nanosecond) = match value.whole_second() {
+            LEAP_SECOND => (
+                LEAP_SECOND - 1,
+                value.subsec_nanosecond().checked_add(NANOS_PER_SECOND),
+            ),
+            second => (second, Some(value.subsec_nanosecond())),
+        };
+        nanosecond
+            .and_then(|nanosecond| {
+                Self::from_hms_nano_opt(
+                    u32::from(value.hour),
+                    u32::from(value.minute),
+                    u32::from(second),
+                    nanosecond,
+                )
+            })
+            .ok_or_else(|| DatetimeConversionError::new("invalid time"))
+    }
+}
+
+impl TryFrom<::chrono::FixedOffset> for Offset {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::chrono::FixedOffset) -> Result<Self, Self::Error> {
+        Self::from_seconds(value.local_minus_utc())
+    }
+}
+
+impl TryFrom<Offset> for ::chrono::FixedOffset {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Offset) -> Result<Self, Self::Error> {
+        Self::east_opt(value.to_seconds()?)
+            .ok_or_else(|| DatetimeConversionError::new("offset is out of range"))
+    }
+}
+
+impl TryFrom<::chrono::NaiveDate> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::chrono::NaiveDate) -> Result<Self, Self::Error> {
+        Date::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<Datetime> for ::chrono::NaiveDate {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        local_date(value)?.try_into()
+    }
+}
+
+impl From<::chrono::NaiveTime> for Datetime {
+    fn from(value: ::chrono::NaiveTime) -> Self {
+        Time::from(value).into()
+    }
+}
+
+impl TryFrom<Datetime> for ::chrono::NaiveTime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        local_time(value)?.try_into()
+    }
+}
+
+impl TryFrom<::chrono::NaiveDateTime> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::chrono::NaiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Self {
+            date: Some(value.date().try_into()?),
+            time: Some(value.time().into()),
+            offset: None,
+        })
+    }
+}
+
+impl TryFrom<Datetime> for ::chrono::NaiveDateTime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        let (date, time) = local_datetime(value)?;
+        Ok(Self::new(date.try_into()?, time.try_into()?))
+    }
+}
+
+impl TryFrom<::chrono::DateTime<::chrono::FixedOffset>> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::chrono::DateTime<::chrono::FixedOffset>) -> Result<Self, Self::Error> {
+        let local = value.naive_local();
+        Ok(Self {
+            date: Some(local.date().try_into()?),
+            time: Some(local.time().into()),
+            offset: Some((*value.offset()).try_into()?),
+        })
+    }
+}
+
+impl TryFrom<Datetime> for ::chrono::DateTime<::chrono::FixedOffset> {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        let (date, time, offset) = offset_datetime(value)?;
+        let local = ::chrono::NaiveDateTime::new(date.try_into()?, time.try_into()?);
+        let offset = ::chrono::FixedOffset::try_from(offset)?;
+        local
+            .and_local_timezone(offset)
+            .single()
+            .ok_or_else(|| DatetimeConversionError::new("datetime is out of range"))
+    }
+}
+
+impl TryFrom<::chrono::DateTime<::chrono::Utc>> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::chrono::DateTime<::chrono::Utc>) -> Result<Self, Self::Error> {
+        let utc = value.naive_utc();
+        Ok(Self {
+            date: Some(utc.date().try_into()?),
+            time: Some(utc.time().into()),
+            offset: Some(Offset::Z),
+        })
+    }
+}
+
+impl TryFrom<Datetime> for ::chrono::DateTime<::chrono::Utc> {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        ::chrono::DateTime::<::chrono::FixedOffset>::try_from(value)
+            .map(|datetime| datetime.to_utc())
+    }
+}
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+const LEAP_SECOND: u8 = 60;
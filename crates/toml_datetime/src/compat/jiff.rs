@@ -0,0 +1,157 @@
+use super::local_date;
+use super::local_datetime;
+use super::local_time;
+use super::offset_datetime;
+use super::to_time;
+use super::to_year;
+use crate::Date;
+use crate::Datetime;
+use crate::DatetimeConversionError;
+use crate::Offset;
+use crate::Time;
+
+impl TryFrom<::jiff::civil::Date> for Date {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::jiff::civil::Date) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: to_year(value.year())?,
+            month: value.month() as u8,
+            day: value.day() as u8,
+        })
+    }
+}
+
+impl TryFrom<Date> for ::jiff::civil::Date {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Date) -> Result<Self, Self::Error> {
+        let invalid = |_| DatetimeConversionError::new("invalid date");
+        Self::new(
+            i16::try_from(value.year).map_err(invalid)?,
+            i8::try_from(value.month).map_err(invalid)?,
+            i8::try_from(value.day).map_err(invalid)?,
+        )
+        .map_err(|_| DatetimeConversionError::new("invalid date"))
+    }
+}
+
+impl From<::jiff::civil::Time> for Time {
+    fn from(value: ::jiff::civil::Time) -> Self {
+        to_time(
+            value.hour() as u8,
+            value.minute() as u8,
+            value.second() as u8,
+            value.subsec_nanosecond() as u32,
+        )
+    }
+}
+
+impl TryFrom<Time> for ::jiff::civil::Time {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Time) -> Result<Self, Self::Error> {
+        let invalid = |_| DatetimeConversionError::new("invalid time");
+        Self::new(
+            i8::try_from(value.hour).map_err(invalid)?,
+            i8::try_from(value.minute).map_err(invalid)?,
+            i8::try_from(value.whole_second()).map_err(invalid)?,
+            i32::try_from(value.subsec_nanosecond()).map_err(invalid)?,
+        )
+        .map_err(|_| DatetimeConversionError::new("invalid time"))
+    }
+}
+
+impl TryFrom<::jiff::tz::Offset> for Offset {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::jiff::tz::Offset) -> Result<Self, Self::Error> {
+        Self::from_seconds(value.seconds())
+    }
+}
+
+impl TryFrom<Offset> for ::jiff::tz::Offset {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Offset) -> Result<Self, Self::Error> {
+        Self::from_seconds(value.to_seconds()?)
+            .map_err(|_| DatetimeConversionError::new("offset is out of range"))
+    }
+}
+
+impl TryFrom<::jiff::civil::Date> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::jiff::civil::Date) -> Result<Self, Self::Error> {
+        Date::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<Datetime> for ::jiff::civil::Date {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        local_date(value)?.try_into()
+    }
+}
+
+impl From<::jiff::civil::Time> for Datetime {
+    fn from(value: ::jiff::civil::Time) -> Self {
+        Time::from(value).into()
+    }
+}
+
+impl TryFrom<Datetime> for ::jiff::civil::Time {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        local_time(value)?.try_into()
+    }
+}
+
+impl TryFrom<::jiff::civil::DateTime> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::jiff::civil::DateTime) -> Result<Self, Self::Error> {
+        Ok(Self {
+            date: Some(value.date().try_into()?),
+            time: Some(value.time().into()),
+            offset: None,
+        })
+    }
+}
+
+impl TryFrom<Datetime> for ::jiff::civil::DateTime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        let (date, time) = local_datetime(value)?;
+        Ok(Self::from_parts(date.try_into()?, time.try_into()?))
+    }
+}
+
+/// Converts to an offset datetime in UTC
+impl TryFrom<::jiff::Timestamp> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::jiff::Timestamp) -> Result<Self, Self::Error> {
+        let utc = ::jiff::tz::Offset::UTC.to_datetime(value);
+        Ok(Self {
+            date: Some(utc.date().try_into()?),
+            time: Some(utc.time().into()),
+            offset: Some(Offset::Z),
+        })
+    }
+}
+
+impl TryFrom<Datetime> for ::jiff::Timestamp {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        let (date, time, offset) = offset_datetime(value)?;
+        let local = ::jiff::civil::DateTime::from_parts(date.try_into()?, time.try_into()?);
+        ::jiff::tz::Offset::try_from(offset)?
+            .to_timestamp(local)
+            .map_err(|_| DatetimeConversionError::new("datetime is out of range"))
+    }
+}
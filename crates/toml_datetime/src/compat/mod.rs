@@ -0,0 +1,118 @@
+//! Conversions between [`Datetime`] and the types of date libraries
+//!
+//! Conversions into date library types require the matching kind of TOML datetime, like an
+//! [Offset Date-Time](https://toml.io/en/v1.0.0#offset-date-time) for an instant in time.
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "time")]
+mod time;
+
+use crate::Date;
+use crate::Datetime;
+use crate::DatetimeConversionError;
+use crate::Offset;
+use crate::Time;
+
+fn local_date(datetime: Datetime) -> Result<Date, DatetimeConversionError> {
+    match datetime {
+        Datetime {
+            date: Some(date),
+            time: None,
+            offset: None,
+        } => Ok(date),
+        _ => Err(DatetimeConversionError::new("expected a local date")),
+    }
+}
+
+fn local_time(datetime: Datetime) -> Result<Time, DatetimeConversionError> {
+    match datetime {
+        Datetime {
+            date: None,
+            time: Some(time),
+            offset: None,
+        } => Ok(time),
+        _ => Err(DatetimeConversionError::new("expected a local time")),
+    }
+}
+
+fn local_datetime(datetime: Datetime) -> Result<(Date, Time), DatetimeConversionError> {
+    match datetime {
+        Datetime {
+            date: Some(date),
+            time: Some(time),
+            offset: None,
+        } => Ok((date, time)),
+        _ => Err(DatetimeConversionError::new("expected a local datetime")),
+    }
+}
+
+fn offset_datetime(datetime: Datetime) -> Result<(Date, Time, Offset), DatetimeConversionError> {
+    match datetime {
+        Datetime {
+            date: Some(date),
+            time: Some(time),
+            offset: Some(offset),
+        } => Ok((date, time, offset)),
+        _ => Err(DatetimeConversionError::new("expected an offset datetime")),
+    }
+}
+
+fn to_year(year: impl TryInto<u16>) -> Result<u16, DatetimeConversionError> {
+    year.try_into()
+        .ok()
+        .filter(|year| *year <= MAX_YEAR)
+        .ok_or_else(|| DatetimeConversionError::new("year is out of range for TOML"))
+}
+
+fn to_time(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Time {
+    Time {
+        hour,
+        minute,
+        second: Some(second),
+        nanosecond: (nanosecond != 0).then_some(nanosecond),
+    }
+}
+
+impl Time {
+    /// Seconds with TOML's defaults for omitted fields
+    fn whole_second(&self) -> u8 {
+        self.second.unwrap_or(0)
+    }
+
+    /// Nanoseconds with TOML's defaults for omitted fields
+    fn subsec_nanosecond(&self) -> u32 {
+        self.nanosecond.unwrap_or(0)
+    }
+}
+
+impl Offset {
+    fn to_seconds(self) -> Result<i32, DatetimeConversionError> {
+        match self {
+            Self::Z => Ok(0),
+            Self::Custom { minutes } if minutes.unsigned_abs() < MINUTES_PER_DAY => {
+                Ok(i32::from(minutes) * 60)
+            }
+            Self::Custom { .. } => Err(DatetimeConversionError::new("offset is out of range")),
+        }
+    }
+
+    fn from_seconds(seconds: i32) -> Result<Self, DatetimeConversionError> {
+        if seconds % 60 != 0 {
+            return Err(DatetimeConversionError::new(
+                "offset is not a whole number of minutes",
+            ));
+        }
+        let minutes = seconds / 60;
+        i16::try_from(minutes)
+            .ok()
+            .filter(|minutes| minutes.unsigned_abs() < MINUTES_PER_DAY)
+            .map(|minutes| Self::Custom { minutes })
+            .ok_or_else(|| DatetimeConversionError::new("offset is out of range"))
+    }
+}
+
+const MAX_YEAR: u16 = 9999;
+const MINUTES_PER_DAY: u16 = 24 * 60;
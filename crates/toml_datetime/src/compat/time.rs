@@ -0,0 +1,151 @@
+use super::local_date;
+use super::local_datetime;
+use super::local_time;
+use super::offset_datetime;
+use super::to_time;
+use super::to_year;
+use crate::Date;
+use crate::Datetime;
+use crate::DatetimeConversionError;
+use crate::Offset;
+use crate::Time;
+
+impl TryFrom<::time::Date> for Date {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::time::Date) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: to_year(value.year())?,
+            month: value.month().into(),
+            day: value.day(),
+        })
+    }
+}
+
+impl TryFrom<Date> for ::time::Date {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Date) -> Result<Self, Self::Error> {
+        ::time::Month::try_from(value.month)
+            .and_then(|month| Self::from_calendar_date(i32::from(value.year), month, value.day))
+            .map_err(|_| DatetimeConversionError::new("invalid date"))
+    }
+}
+
+impl From<::time::Time> for Time {
+    fn from(value: ::time::Time) -> Self {
+        to_time(
+            value.hour(),
+            value.minute(),
+            value.second(),
+            value.nanosecond(),
+        )
+    }
+}
+
+impl TryFrom<Time> for ::time::Time {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Time) -> Result<Self, Self::Error> {
+        Self::from_hms_nano(
+            value.hour,
+            value.minute,
+            value.whole_second(),
+            value.subsec_nanosecond(),
+        )
+        .map_err(|_| DatetimeConversionError::new("invalid time"))
+    }
+}
+
+impl TryFrom<::time::UtcOffset> for Offset {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::time::UtcOffset) -> Result<Self, Self::Error> {
+        Self::from_seconds(value.whole_seconds())
+    }
+}
+
+impl TryFrom<Offset> for ::time::UtcOffset {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Offset) -> Result<Self, Self::Error> {
+        Self::from_whole_seconds(value.to_seconds()?)
+            .map_err(|_| DatetimeConversionError::new("offset is out of range"))
+    }
+}
+
+impl TryFrom<::time::Date> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::time::Date) -> Result<Self, Self::Error> {
+        Date::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<Datetime> for ::time::Date {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        local_date(value)?.try_into()
+    }
+}
+
+impl From<::time::Time> for Datetime {
+    fn from(value: ::time::Time) -> Self {
+        Time::from(value).into()
+    }
+}
+
+impl TryFrom<Datetime> for ::time::Time {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        local_time(value)?.try_into()
+    }
+}
+
+impl TryFrom<::time::PrimitiveDateTime> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::time::PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Self {
+            date: Some(value.date().try_into()?),
+            time: Some(value.time().into()),
+            offset: None,
+        })
+    }
+}
+
+impl TryFrom<Datetime> for ::time::PrimitiveDateTime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        let (date, time) = local_datetime(value)?;
+        Ok(Self::new(date.try_into()?, time.try_into()?))
+    }
+}
+
+impl TryFrom<::time::OffsetDateTime> for Datetime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: ::time::OffsetDateTime) -> Result<Self, Self::Error> {
+        Ok(Self {
+            date: Some(value.date().try_into()?),
+            time: Some(value.time().into()),
+            offset: Some(value.offset().try_into()?),
+        })
+    }
+}
+
+impl TryFrom<Datetime> for ::time::OffsetDateTime {
+    type Error = DatetimeConversionError;
+
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        let (date, time, offset) = offset_datetime(value)?;
+        Ok(Self::new_in_offset(
+            date.try_into()?,
+            time.try_into()?,
+            offset.try_into()?,
+        ))
+    }
+}
@@ -1,6 +1,12 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned as _;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::fmt;
 use core::str::{self, FromStr};
 
+use crate::DatetimeFormat;
+
 /// A parsed TOML datetime value
 ///
 /// This structure is intended to represent the datetime primitive type that can
@@ -68,6 +74,9 @@ use core::str::{self, FromStr};
 /// **4. Local Time**: If only `time` is given, `Datetime` corresponds to a
 /// [Local Time]; see the docs for [`Time`].
 ///
+/// Comparisons, like [`Ord`], are of the fields, see [`Datetime::cmp_instant`] to compare
+/// offset datetimes chronologically.
+///
 /// [TOML v1.0.0 spec]: https://toml.io/en/v1.0.0
 /// [Offset Date-Time]: https://toml.io/en/v1.0.0#offset-date-time
 /// [Local Date-Time]: https://toml.io/en/v1.0.0#local-date-time
@@ -102,6 +111,14 @@ pub(crate) const NAME: &str = "$__toml_private_Datetime";
 pub(crate) fn is_datetime(name: &'static str) -> bool {
     name == NAME
 }
+// `as_string` serializes a newtype struct with this name, wrapping the datetime as a string, for
+// TOML serializers to write as a datetime while other formats see a string.
+#[cfg(feature = "portable")]
+pub(crate) const STRING_NAME: &str = "$__toml_private_DatetimeString";
+#[cfg(feature = "portable")]
+pub(crate) fn is_datetime_string(name: &'static str) -> bool {
+    name == STRING_NAME
+}
 
 /// A parsed TOML date value
 ///
@@ -178,6 +195,65 @@ pub enum Offset {
 }
 
 impl Datetime {
+    /// Create a `Datetime`, checking it is a valid TOML datetime
+    ///
+    /// In addition to the checks of [`Date::new`] and [`Time::new`]:
+    /// - The fields must be one of the four combinations of TOML datetime
+    /// - A leap second must be at `23:59:60` in UTC, when known
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use toml_datetime::{Date, Datetime, Offset, Time};
+    /// let date = Date::new(1990, 12, 31).unwrap();
+    /// let time = Time::new(15, 59, Some(60), None).unwrap();
+    /// let offset = Offset::Custom { minutes: -8 * 60 };
+    /// assert!(Datetime::new(Some(date), Some(time), Some(offset)).is_ok());
+    /// assert!(Datetime::new(Some(date), Some(time), Some(Offset::Z)).is_err());
+    /// ```
+    pub fn new(
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<Offset>,
+    ) -> Result<Self, DatetimeParseError> {
+        let datetime = Self { date, time, offset };
+        datetime.validate()?;
+        Ok(datetime)
+    }
+
+    fn validate(&self) -> Result<(), DatetimeParseError> {
+        match (self.date, self.time, self.offset) {
+            (Some(date), Some(time), Some(offset)) => {
+                date.validate()?;
+                time.validate()?;
+                offset.validate()?;
+                if time.second == Some(LEAP_SECOND) {
+                    let offset = match offset {
+                        Offset::Z => 0,
+                        Offset::Custom { minutes } => minutes,
+                    };
+                    let minute_of_day = i16::from(time.hour) * 60 + i16::from(time.minute);
+                    if (minute_of_day - offset).rem_euclid(24 * 60) != 24 * 60 - 1 {
+                        return Err(DatetimeParseError::new()
+                            .what("time")
+                            .expected("leap second at 23:59:60 UTC"));
+                    }
+                }
+            }
+            (Some(date), Some(time), None) => {
+                date.validate()?;
+                time.validate()?;
+            }
+            (Some(date), None, None) => date.validate()?,
+            (None, Some(time), None) => time.validate()?,
+            _ => {
+                return Err(DatetimeParseError::new()
+                    .expected("offset datetime, local datetime, local date, or local time"));
+            }
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "serde")]
     fn type_name(&self) -> &'static str {
         match (
@@ -195,6 +271,48 @@ impl Datetime {
 }
 
 impl Date {
+    /// Create a `Date`, checking the day exists in the proleptic Gregorian calendar
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use toml_datetime::Date;
+    /// assert!(Date::new(2024, 2, 29).is_ok());
+    /// assert!(Date::new(2023, 2, 29).is_err());
+    /// ```
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, DatetimeParseError> {
+        let date = Self { year, month, day };
+        date.validate()?;
+        Ok(date)
+    }
+
+    fn validate(&self) -> Result<(), DatetimeParseError> {
+        if self.year > 9999 {
+            return Err(DatetimeParseError::new()
+                .what("date")
+                .expected("a four-digit year (YYYY)"));
+        }
+        if self.month < 1 || self.month > 12 {
+            return Err(DatetimeParseError::new()
+                .what("date")
+                .expected("month between 01 and 12"));
+        }
+        let is_leap_year =
+            (self.year % 4 == 0) && ((self.year % 100 != 0) || (self.year % 400 == 0));
+        let (max_days_in_month, expected_day) = match self.month {
+            2 if is_leap_year => (29, "day between 01 and 29"),
+            2 => (28, "day between 01 and 28"),
+            4 | 6 | 9 | 11 => (30, "day between 01 and 30"),
+            _ => (31, "day between 01 and 31"),
+        };
+        if self.day < 1 || self.day > max_days_in_month {
+            return Err(DatetimeParseError::new()
+                .what("date")
+                .expected(expected_day));
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "serde")]
     fn type_name() -> &'static str {
         "local date"
@@ -202,12 +320,77 @@ impl Date {
 }
 
 impl Time {
+    /// Create a `Time`, checking each field is in range
+    ///
+    /// A leap second, `60`, is allowed as its validity depends on the offset,
+    /// see [`Datetime::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use toml_datetime::Time;
+    /// assert!(Time::new(7, 32, Some(0), Some(999_999_000)).is_ok());
+    /// assert!(Time::new(24, 0, None, None).is_err());
+    /// ```
+    pub fn new(
+        hour: u8,
+        minute: u8,
+        second: Option<u8>,
+        nanosecond: Option<u32>,
+    ) -> Result<Self, DatetimeParseError> {
+        let time = Self {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        };
+        time.validate()?;
+        Ok(time)
+    }
+
+    fn validate(&self) -> Result<(), DatetimeParseError> {
+        if self.hour > 23 {
+            return Err(DatetimeParseError::new()
+                .what("time")
+                .expected("hour between 00 and 23"));
+        }
+        if self.minute > 59 {
+            return Err(DatetimeParseError::new()
+                .what("time")
+                .expected("minute between 00 and 59"));
+        }
+        // 00-58, 00-59, 00-60 based on leap second rules
+        if self.second.unwrap_or(0) > LEAP_SECOND {
+            return Err(DatetimeParseError::new()
+                .what("time")
+                .expected("second between 00 and 60"));
+        }
+        if self.nanosecond.unwrap_or(0) > 999_999_999 {
+            return Err(DatetimeParseError::new()
+                .what("time")
+                .expected("nanoseconds overflowed"));
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "serde")]
     fn type_name() -> &'static str {
         "local time"
     }
 }
 
+impl Offset {
+    fn validate(&self) -> Result<(), DatetimeParseError> {
+        match *self {
+            Self::Z => Ok(()),
+            Self::Custom { minutes } if minutes.unsigned_abs() < 24 * 60 => Ok(()),
+            Self::Custom { .. } => Err(DatetimeParseError::new()
+                .what("offset")
+                .expected("hours between 00 and 23")),
+        }
+    }
+}
+
 impl From<Date> for Datetime {
     fn from(other: Date) -> Self {
         Self {
@@ -228,22 +411,90 @@ impl From<Time> for Datetime {
     }
 }
 
+/// A [`Datetime`] that keeps its fractional seconds as written
+///
+/// [`Datetime`] truncates fractional seconds to nanoseconds and drops trailing zeros, so
+/// `07:32:00.500` is written back as `07:32:00.5`.
+/// This keeps all of the digits for round-tripping them losslessly.
+///
+/// Equality and ordering only consider the [`Datetime`].
+///
+/// # Example
+///
+/// ```rust
+/// # use toml_datetime::LosslessDatetime;
+/// let datetime: LosslessDatetime = "07:32:00.1234567890".parse().unwrap();
+/// assert_eq!(datetime.fraction(), Some("1234567890"));
+/// assert_eq!(datetime.datetime().time.unwrap().nanosecond, Some(123_456_789));
+/// assert_eq!(datetime.to_string(), "07:32:00.1234567890");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct LosslessDatetime {
+    datetime: Datetime,
+    fraction: Option<String>,
+}
+
+#[cfg(feature = "alloc")]
+impl LosslessDatetime {
+    /// The datetime, with fractional seconds truncated to nanoseconds
+    pub fn datetime(&self) -> &Datetime {
+        &self.datetime
+    }
+
+    /// The digits of the fractional seconds, as written
+    ///
+    /// This is `None` when the fractional seconds come from [`Time::nanosecond`], like when
+    /// converting from a [`Datetime`].
+    pub fn fraction(&self) -> Option<&str> {
+        self.fraction.as_deref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Datetime> for LosslessDatetime {
+    fn from(datetime: Datetime) -> Self {
+        Self {
+            datetime,
+            fraction: None,
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
+impl From<LosslessDatetime> for Datetime {
+    fn from(datetime: LosslessDatetime) -> Self {
+        datetime.datetime
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for LosslessDatetime {
+    fn eq(&self, other: &Self) -> bool {
+        self.datetime == other.datetime
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for LosslessDatetime {}
+
+#[cfg(feature = "alloc")]
+impl PartialOrd for LosslessDatetime {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Ord for LosslessDatetime {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.datetime.cmp(&other.datetime)
+    }
+}
+
 impl fmt::Display for Datetime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(ref date) = self.date {
-            write!(f, "{date}")?;
-        }
-        if let Some(ref time) = self.time {
-            if self.date.is_some() {
-                write!(f, "T")?;
-            }
-            write!(f, "{time}")?;
-        }
-        if let Some(ref offset) = self.offset {
-            write!(f, "{offset}")?;
-        }
-        Ok(())
+        DatetimeFormat::new().format(*self).fmt(f)
     }
 }
 
@@ -253,25 +504,16 @@ impl fmt::Display for Date {
     }
 }
 
-#[cfg(feature = "alloc")]
 impl fmt::Display for Time {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
-        if let Some(second) = self
-            .second
-            .or_else(|| self.nanosecond.is_some().then_some(0))
-        {
-            write!(f, ":{second:02}")?;
-        }
-        if let Some(nanosecond) = self.nanosecond {
-            let s = alloc::format!("{nanosecond:09}");
-            let mut s = s.trim_end_matches('0');
-            if s.is_empty() {
-                s = "0";
-            }
-            write!(f, ".{s}")?;
-        }
-        Ok(())
+        DatetimeFormat::new().format(Datetime::from(*self)).fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for LosslessDatetime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DatetimeFormat::new().format_lossless(self).fmt(f)
     }
 }
 
@@ -297,190 +539,191 @@ impl FromStr for Datetime {
     type Err = DatetimeParseError;
 
     fn from_str(date: &str) -> Result<Self, DatetimeParseError> {
-        // Accepted formats:
-        //
-        // 0000-00-00T00:00:00.00Z
-        // 0000-00-00T00:00:00.00
-        // 0000-00-00
-        // 00:00:00.00
-        //
-        // ```abnf
-        // ;; Date and Time (as defined in RFC 3339)
-        //
-        // date-time      = offset-date-time / local-date-time / local-date / local-time
-        //
-        // date-fullyear  = 4DIGIT
-        // date-month     = 2DIGIT  ; 01-12
-        // date-mday      = 2DIGIT  ; 01-28, 01-29, 01-30, 01-31 based on month/year
-        // time-delim     = "T" / %x20 ; T, t, or space
-        // time-hour      = 2DIGIT  ; 00-23
-        // time-minute    = 2DIGIT  ; 00-59
-        // time-second    = 2DIGIT  ; 00-58, 00-59, 00-60 based on leap second rules
-        // time-secfrac   = "." 1*DIGIT
-        // time-numoffset = ( "+" / "-" ) time-hour ":" time-minute
-        // time-offset    = "Z" / time-numoffset
-        //
-        // partial-time = time-hour ":" time-minute [ ":" time-second [ time-secfrac ] ]
-        // full-date      = date-fullyear "-" date-month "-" date-mday
-        // full-time      = partial-time time-offset
-        //
-        // ;; Offset Date-Time
-        //
-        // offset-date-time = full-date time-delim full-time
-        //
-        // ;; Local Date-Time
-        //
-        // local-date-time = full-date time-delim partial-time
-        //
-        // ;; Local Date
-        //
-        // local-date = full-date
-        //
-        // ;; Local Time
-        //
-        // local-time = partial-time
-        // ```
-        let mut result = Self {
-            date: None,
-            time: None,
-            offset: None,
-        };
+        parse_datetime(date).map(|(datetime, _fraction)| datetime)
+    }
+}
 
-        let mut lexer = Lexer::new(date);
+#[cfg(feature = "alloc")]
+impl FromStr for LosslessDatetime {
+    type Err = DatetimeParseError;
 
-        let digits = lexer
-            .next()
-            .ok_or(DatetimeParseError::new().expected("year or hour"))?;
-        digits
-            .is(TokenKind::Digits)
-            .map_err(|err| err.expected("year or hour"))?;
-        let sep = lexer
-            .next()
-            .ok_or(DatetimeParseError::new().expected("`-` (YYYY-MM) or `:` (HH:MM)"))?;
-        match sep.kind {
-            TokenKind::Dash => {
-                let year = digits;
-                let month = lexer
-                    .next()
-                    .ok_or_else(|| DatetimeParseError::new().what("date").expected("month"))?;
-                month
-                    .is(TokenKind::Digits)
-                    .map_err(|err| err.what("date").expected("month"))?;
-                let sep = lexer.next().ok_or(
-                    DatetimeParseError::new()
-                        .what("date")
-                        .expected("`-` (MM-DD)"),
-                )?;
-                sep.is(TokenKind::Dash)
-                    .map_err(|err| err.what("date").expected("`-` (MM-DD)"))?;
-                let day = lexer
-                    .next()
-                    .ok_or(DatetimeParseError::new().what("date").expected("day"))?;
-                day.is(TokenKind::Digits)
-                    .map_err(|err| err.what("date").expected("day"))?;
+    fn from_str(date: &str) -> Result<Self, DatetimeParseError> {
+        parse_datetime(date).map(|(datetime, fraction)| Self {
+            datetime,
+            fraction: fraction.map(|fraction| fraction.to_owned()),
+        })
+    }
+}
 
-                if year.raw.len() != 4 {
-                    return Err(DatetimeParseError::new()
-                        .what("date")
-                        .expected("a four-digit year (YYYY)"));
-                }
-                if month.raw.len() != 2 {
-                    return Err(DatetimeParseError::new()
-                        .what("date")
-                        .expected("a two-digit month (MM)"));
-                }
-                if day.raw.len() != 2 {
-                    return Err(DatetimeParseError::new()
-                        .what("date")
-                        .expected("a two-digit day (DD)"));
-                }
-                let date = Date {
-                    year: year.raw.parse().map_err(|_err| DatetimeParseError::new())?,
-                    month: month
-                        .raw
-                        .parse()
-                        .map_err(|_err| DatetimeParseError::new())?,
-                    day: day.raw.parse().map_err(|_err| DatetimeParseError::new())?,
-                };
-                if date.month < 1 || date.month > 12 {
-                    return Err(DatetimeParseError::new()
-                        .what("date")
-                        .expected("month between 01 and 12"));
-                }
-                let is_leap_year =
-                    (date.year % 4 == 0) && ((date.year % 100 != 0) || (date.year % 400 == 0));
-                let (max_days_in_month, expected_day) = match date.month {
-                    2 if is_leap_year => (29, "day between 01 and 29"),
-                    2 => (28, "day between 01 and 28"),
-                    4 | 6 | 9 | 11 => (30, "day between 01 and 30"),
-                    _ => (31, "day between 01 and 31"),
-                };
-                if date.day < 1 || date.day > max_days_in_month {
-                    return Err(DatetimeParseError::new()
-                        .what("date")
-                        .expected(expected_day));
-                }
+/// Parse a [`Datetime`], along with its fractional seconds as written
+fn parse_datetime(date: &str) -> Result<(Datetime, Option<&str>), DatetimeParseError> {
+    // Accepted formats:
+    //
+    // 0000-00-00T00:00:00.00Z
+    // 0000-00-00T00:00:00.00
+    // 0000-00-00
+    // 00:00:00.00
+    //
+    // ```abnf
+    // ;; Date and Time (as defined in RFC 3339)
+    //
+    // date-time      = offset-date-time / local-date-time / local-date / local-time
+    //
+    // date-fullyear  = 4DIGIT
+    // date-month     = 2DIGIT  ; 01-12
+    // date-mday      = 2DIGIT  ; 01-28, 01-29, 01-30, 01-31 based on month/year
+    // time-delim     = "T" / %x20 ; T, t, or space
+    // time-hour      = 2DIGIT  ; 00-23
+    // time-minute    = 2DIGIT  ; 00-59
+    // time-second    = 2DIGIT  ; 00-58, 00-59, 00-60 based on leap second rules
+    // time-secfrac   = "." 1*DIGIT
+    // time-numoffset = ( "+" / "-" ) time-hour ":" time-minute
+    // time-offset    = "Z" / time-numoffset
+    //
+    // partial-time = time-hour ":" time-minute [ ":" time-second [ time-secfrac ] ]
+    // full-date      = date-fullyear "-" date-month "-" date-mday
+    // full-time      = partial-time time-offset
+    //
+    // ;; Offset Date-Time
+    //
+    // offset-date-time = full-date time-delim full-time
+    //
+    // ;; Local Date-Time
+    //
+    // local-date-time = full-date time-delim partial-time
+    //
+    // ;; Local Date
+    //
+    // local-date = full-date
+    //
+    // ;; Local Time
+    //
+    // local-time = partial-time
+    // ```
+    let mut result = Datetime {
+        date: None,
+        time: None,
+        offset: None,
+    };
+
+    let mut lexer = Lexer::new(date);
+
+    let digits = lexer
+        .next()
+        .ok_or(DatetimeParseError::new().expected("year or hour"))?;
+    digits
+        .is(TokenKind::Digits)
+        .map_err(|err| err.expected("year or hour"))?;
+    let sep = lexer
+        .next()
+        .ok_or(DatetimeParseError::new().expected("`-` (YYYY-MM) or `:` (HH:MM)"))?;
+    match sep.kind {
+        TokenKind::Dash => {
+            let year = digits;
+            let month = lexer
+                .next()
+                .ok_or_else(|| DatetimeParseError::new().what("date").expected("month"))?;
+            month
+                .is(TokenKind::Digits)
+                .map_err(|err| err.what("date").expected("month"))?;
+            let sep = lexer.next().ok_or(
+                DatetimeParseError::new()
+                    .what("date")
+                    .expected("`-` (MM-DD)"),
+            )?;
+            sep.is(TokenKind::Dash)
+                .map_err(|err| err.what("date").expected("`-` (MM-DD)"))?;
+            let day = lexer
+                .next()
+                .ok_or(DatetimeParseError::new().what("date").expected("day"))?;
+            day.is(TokenKind::Digits)
+                .map_err(|err| err.what("date").expected("day"))?;
 
-                result.date = Some(date);
+            if year.raw.len() != 4 {
+                return Err(DatetimeParseError::new()
+                    .what("date")
+                    .expected("a four-digit year (YYYY)"));
             }
-            TokenKind::Colon => lexer = Lexer::new(date),
-            _ => {
-                return Err(DatetimeParseError::new().expected("`-` (YYYY-MM) or `:` (HH:MM)"));
+            if month.raw.len() != 2 {
+                return Err(DatetimeParseError::new()
+                    .what("date")
+                    .expected("a two-digit month (MM)"));
             }
+            if day.raw.len() != 2 {
+                return Err(DatetimeParseError::new()
+                    .what("date")
+                    .expected("a two-digit day (DD)"));
+            }
+            let date = Date {
+                year: year.raw.parse().map_err(|_err| DatetimeParseError::new())?,
+                month: month
+                    .raw
+                    .parse()
+                    .map_err(|_err| DatetimeParseError::new())?,
+                day: day.raw.parse().map_err(|_err| DatetimeParseError::new())?,
+            };
+            date.validate()?;
+
+            result.date = Some(date);
         }
+        TokenKind::Colon => lexer = Lexer::new(date),
+        _ => {
+            return Err(DatetimeParseError::new().expected("`-` (YYYY-MM) or `:` (HH:MM)"));
+        }
+    }
 
-        // Next parse the "partial-time" if available
-        let partial_time = if result.date.is_some() {
-            let sep = lexer.next();
-            match sep {
-                Some(token) if matches!(token.kind, TokenKind::T | TokenKind::Space) => true,
-                Some(_token) => {
-                    return Err(DatetimeParseError::new()
-                        .what("date-time")
-                        .expected("`T` between date and time"));
-                }
-                None => false,
+    // Next parse the "partial-time" if available
+    let mut fraction = None;
+    let partial_time = if result.date.is_some() {
+        let sep = lexer.next();
+        match sep {
+            Some(token) if matches!(token.kind, TokenKind::T | TokenKind::Space) => true,
+            Some(_token) => {
+                return Err(DatetimeParseError::new()
+                    .what("date-time")
+                    .expected("`T` between date and time"));
             }
-        } else {
-            result.date.is_none()
-        };
+            None => false,
+        }
+    } else {
+        result.date.is_none()
+    };
 
-        if partial_time {
-            let hour = lexer
-                .next()
-                .ok_or_else(|| DatetimeParseError::new().what("time").expected("hour"))?;
-            hour.is(TokenKind::Digits)
-                .map_err(|err| err.what("time").expected("hour"))?;
-            let sep = lexer.next().ok_or(
-                DatetimeParseError::new()
-                    .what("time")
-                    .expected("`:` (HH:MM)"),
-            )?;
-            sep.is(TokenKind::Colon)
-                .map_err(|err| err.what("time").expected("`:` (HH:MM)"))?;
-            let minute = lexer
+    if partial_time {
+        let hour = lexer
+            .next()
+            .ok_or_else(|| DatetimeParseError::new().what("time").expected("hour"))?;
+        hour.is(TokenKind::Digits)
+            .map_err(|err| err.what("time").expected("hour"))?;
+        let sep = lexer.next().ok_or(
+            DatetimeParseError::new()
+                .what("time")
+                .expected("`:` (HH:MM)"),
+        )?;
+        sep.is(TokenKind::Colon)
+            .map_err(|err| err.what("time").expected("`:` (HH:MM)"))?;
+        let minute = lexer
+            .next()
+            .ok_or(DatetimeParseError::new().what("time").expected("minute"))?;
+        minute
+            .is(TokenKind::Digits)
+            .map_err(|err| err.what("time").expected("minute"))?;
+        let second = if lexer.clone().next().map(|t| t.kind) == Some(TokenKind::Colon) {
+            let sep = lexer.next().ok_or(DatetimeParseError::new())?;
+            sep.is(TokenKind::Colon)?;
+            let second = lexer
                 .next()
-                .ok_or(DatetimeParseError::new().what("time").expected("minute"))?;
-            minute
+                .ok_or(DatetimeParseError::new().what("time").expected("second"))?;
+            second
                 .is(TokenKind::Digits)
-                .map_err(|err| err.what("time").expected("minute"))?;
-            let second = if lexer.clone().next().map(|t| t.kind) == Some(TokenKind::Colon) {
-                let sep = lexer.next().ok_or(DatetimeParseError::new())?;
-                sep.is(TokenKind::Colon)?;
-                let second = lexer
-                    .next()
-                    .ok_or(DatetimeParseError::new().what("time").expected("second"))?;
-                second
-                    .is(TokenKind::Digits)
-                    .map_err(|err| err.what("time").expected("second"))?;
-                Some(second)
-            } else {
-                None
-            };
+                .map_err(|err| err.what("time").expected("second"))?;
+            Some(second)
+        } else {
+            None
+        };
 
-            let nanosecond = if second.is_some()
-                && lexer.clone().next().map(|t| t.kind) == Some(TokenKind::Dot)
-            {
+        let nanosecond =
+            if second.is_some() && lexer.clone().next().map(|t| t.kind) == Some(TokenKind::Dot) {
                 let sep = lexer.next().ok_or(DatetimeParseError::new())?;
                 sep.is(TokenKind::Dot)?;
                 let nanosecond = lexer.next().ok_or(
@@ -496,147 +739,127 @@ impl FromStr for Datetime {
                 None
             };
 
-            if hour.raw.len() != 2 {
-                return Err(DatetimeParseError::new()
-                    .what("time")
-                    .expected("a two-digit hour (HH)"));
-            }
-            if minute.raw.len() != 2 {
-                return Err(DatetimeParseError::new()
-                    .what("time")
-                    .expected("a two-digit minute (MM)"));
-            }
-            if let Some(second) = second {
-                if second.raw.len() != 2 {
-                    return Err(DatetimeParseError::new()
-                        .what("time")
-                        .expected("a two-digit second (SS)"));
-                }
-            }
-
-            let time = Time {
-                hour: hour.raw.parse().map_err(|_err| DatetimeParseError::new())?,
-                minute: minute
-                    .raw
-                    .parse()
-                    .map_err(|_err| DatetimeParseError::new())?,
-                second: second
-                    .map(|t| t.raw.parse().map_err(|_err| DatetimeParseError::new()))
-                    .transpose()?,
-                nanosecond: nanosecond.map(|t| s_to_nanoseconds(t.raw)),
-            };
-
-            if time.hour > 23 {
-                return Err(DatetimeParseError::new()
-                    .what("time")
-                    .expected("hour between 00 and 23"));
-            }
-            if time.minute > 59 {
-                return Err(DatetimeParseError::new()
-                    .what("time")
-                    .expected("minute between 00 and 59"));
-            }
-            // 00-58, 00-59, 00-60 based on leap second rules
-            if time.second.unwrap_or(0) > 60 {
-                return Err(DatetimeParseError::new()
-                    .what("time")
-                    .expected("second between 00 and 60"));
-            }
-            if time.nanosecond.unwrap_or(0) > 999_999_999 {
+        if hour.raw.len() != 2 {
+            return Err(DatetimeParseError::new()
+                .what("time")
+                .expected("a two-digit hour (HH)"));
+        }
+        if minute.raw.len() != 2 {
+            return Err(DatetimeParseError::new()
+                .what("time")
+                .expected("a two-digit minute (MM)"));
+        }
+        if let Some(second) = second {
+            if second.raw.len() != 2 {
                 return Err(DatetimeParseError::new()
                     .what("time")
-                    .expected("nanoseconds overflowed"));
+                    .expected("a two-digit second (SS)"));
             }
-
-            result.time = Some(time);
         }
 
-        // And finally, parse the offset
-        if result.date.is_some() && result.time.is_some() {
-            match lexer.next() {
-                Some(token) if token.kind == TokenKind::Z => {
-                    result.offset = Some(Offset::Z);
-                }
-                Some(token) if matches!(token.kind, TokenKind::Plus | TokenKind::Dash) => {
-                    let sign = if token.kind == TokenKind::Plus { 1 } else { -1 };
-                    let hours = lexer
-                        .next()
-                        .ok_or(DatetimeParseError::new().what("offset").expected("hour"))?;
-                    hours
-                        .is(TokenKind::Digits)
-                        .map_err(|err| err.what("offset").expected("hour"))?;
-                    let sep = lexer.next().ok_or(
-                        DatetimeParseError::new()
-                            .what("offset")
-                            .expected("`:` (HH:MM)"),
-                    )?;
-                    sep.is(TokenKind::Colon)
-                        .map_err(|err| err.what("offset").expected("`:` (HH:MM)"))?;
-                    let minutes = lexer
-                        .next()
-                        .ok_or(DatetimeParseError::new().what("offset").expected("minute"))?;
-                    minutes
-                        .is(TokenKind::Digits)
-                        .map_err(|err| err.what("offset").expected("minute"))?;
-
-                    if hours.raw.len() != 2 {
-                        return Err(DatetimeParseError::new()
-                            .what("offset")
-                            .expected("a two-digit hour (HH)"));
-                    }
-                    if minutes.raw.len() != 2 {
-                        return Err(DatetimeParseError::new()
-                            .what("offset")
-                            .expected("a two-digit minute (MM)"));
-                    }
+        fraction = nanosecond.map(|t| t.raw);
+        let time = Time {
+            hour: hour.raw.parse().map_err(|_err| DatetimeParseError::new())?,
+            minute: minute
+                .raw
+                .parse()
+                .map_err(|_err| DatetimeParseError::new())?,
+            second: second
+                .map(|t| t.raw.parse().map_err(|_err| DatetimeParseError::new()))
+                .transpose()?,
+            nanosecond: nanosecond.map(|t| s_to_nanoseconds(t.raw)),
+        };
 
-                    let hours = hours
-                        .raw
-                        .parse::<u8>()
-                        .map_err(|_err| DatetimeParseError::new())?;
-                    let minutes = minutes
-                        .raw
-                        .parse::<u8>()
-                        .map_err(|_err| DatetimeParseError::new())?;
+        time.validate()?;
 
-                    if hours > 23 {
-                        return Err(DatetimeParseError::new()
-                            .what("offset")
-                            .expected("hours between 00 and 23"));
-                    }
-                    if minutes > 59 {
-                        return Err(DatetimeParseError::new()
-                            .what("offset")
-                            .expected("minutes between 00 and 59"));
-                    }
+        result.time = Some(time);
+    }
 
-                    let total_minutes = sign * (hours as i16 * 60 + minutes as i16);
+    // And finally, parse the offset
+    if result.date.is_some() && result.time.is_some() {
+        match lexer.next() {
+            Some(token) if token.kind == TokenKind::Z => {
+                result.offset = Some(Offset::Z);
+            }
+            Some(token) if matches!(token.kind, TokenKind::Plus | TokenKind::Dash) => {
+                let sign = if token.kind == TokenKind::Plus { 1 } else { -1 };
+                let hours = lexer
+                    .next()
+                    .ok_or(DatetimeParseError::new().what("offset").expected("hour"))?;
+                hours
+                    .is(TokenKind::Digits)
+                    .map_err(|err| err.what("offset").expected("hour"))?;
+                let sep = lexer.next().ok_or(
+                    DatetimeParseError::new()
+                        .what("offset")
+                        .expected("`:` (HH:MM)"),
+                )?;
+                sep.is(TokenKind::Colon)
+                    .map_err(|err| err.what("offset").expected("`:` (HH:MM)"))?;
+                let minutes = lexer
+                    .next()
+                    .ok_or(DatetimeParseError::new().what("offset").expected("minute"))?;
+                minutes
+                    .is(TokenKind::Digits)
+                    .map_err(|err| err.what("offset").expected("minute"))?;
 
-                    if !((-24 * 60)..=(24 * 60)).contains(&total_minutes) {
-                        return Err(DatetimeParseError::new().what("offset"));
-                    }
+                if hours.raw.len() != 2 {
+                    return Err(DatetimeParseError::new()
+                        .what("offset")
+                        .expected("a two-digit hour (HH)"));
+                }
+                if minutes.raw.len() != 2 {
+                    return Err(DatetimeParseError::new()
+                        .what("offset")
+                        .expected("a two-digit minute (MM)"));
+                }
+
+                let hours = hours
+                    .raw
+                    .parse::<u8>()
+                    .map_err(|_err| DatetimeParseError::new())?;
+                let minutes = minutes
+                    .raw
+                    .parse::<u8>()
+                    .map_err(|_err| DatetimeParseError::new())?;
 
-                    result.offset = Some(Offset::Custom {
-                        minutes: total_minutes,
-                    });
+                if hours > 23 {
+                    return Err(DatetimeParseError::new()
+                        .what("offset")
+                        .expected("hours between 00 and 23"));
                 }
-                Some(_token) => {
+                if minutes > 59 {
                     return Err(DatetimeParseError::new()
                         .what("offset")
-                        .expected("`Z`, +OFFSET, -OFFSET"));
+                        .expected("minutes between 00 and 59"));
                 }
-                None => {}
-            }
-        }
 
-        // Return an error if we didn't hit eof, otherwise return our parsed
-        // date
-        if lexer.unknown().is_some() {
-            return Err(DatetimeParseError::new());
+                let total_minutes = sign * (hours as i16 * 60 + minutes as i16);
+
+                if !((-24 * 60)..=(24 * 60)).contains(&total_minutes) {
+                    return Err(DatetimeParseError::new().what("offset"));
+                }
+
+                result.offset = Some(Offset::Custom {
+                    minutes: total_minutes,
+                });
+            }
+            Some(_token) => {
+                return Err(DatetimeParseError::new()
+                    .what("offset")
+                    .expected("`Z`, +OFFSET, -OFFSET"));
+            }
+            None => {}
         }
+    }
 
-        Ok(result)
+    // Return an error if we didn't hit eof, otherwise return our parsed
+    // date
+    if lexer.unknown().is_some() {
+        return Err(DatetimeParseError::new());
     }
+
+    Ok((result, fraction))
 }
 
 fn s_to_nanoseconds(input: &str) -> u32 {
@@ -736,7 +959,11 @@ impl<'s> Iterator for Lexer<'s> {
     }
 }
 
-/// Error returned from parsing a `Datetime` in the `FromStr` implementation.
+const LEAP_SECOND: u8 = 60;
+pub(crate) const NANOSECOND_PRECISION: usize = 9;
+
+/// Error returned from parsing a `Datetime` in the `FromStr` implementation or from its checked
+/// constructors, like [`Datetime::new`].
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct DatetimeParseError {
@@ -777,6 +1004,28 @@ impl fmt::Display for DatetimeParseError {
 
 impl core::error::Error for DatetimeParseError {}
 
+/// Error returned from converting between [`Datetime`] and the types of date libraries
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DatetimeConversionError {
+    what: &'static str,
+}
+
+impl DatetimeConversionError {
+    #[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+    pub(crate) fn new(what: &'static str) -> Self {
+        Self { what }
+    }
+}
+
+impl fmt::Display for DatetimeConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.what.fmt(f)
+    }
+}
+
+impl core::error::Error for DatetimeConversionError {}
+
 #[cfg(feature = "serde")]
 #[cfg(feature = "alloc")]
 impl serde_core::ser::Serialize for Datetime {
@@ -793,6 +1042,22 @@ impl serde_core::ser::Serialize for Datetime {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg(feature = "alloc")]
+impl serde_core::ser::Serialize for LosslessDatetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::ser::Serializer,
+    {
+        use crate::alloc::string::ToString as _;
+        use serde_core::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct(NAME, 1)?;
+        s.serialize_field(FIELD, &self.to_string())?;
+        s.end()
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg(feature = "alloc")]
 impl serde_core::ser::Serialize for Date {
@@ -848,6 +1113,40 @@ impl<'de> serde_core::de::Deserialize<'de> for Datetime {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg(feature = "alloc")]
+impl<'de> serde_core::de::Deserialize<'de> for LosslessDatetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde_core::de::Deserializer<'de>,
+    {
+        struct LosslessDatetimeVisitor;
+
+        impl<'de> serde_core::de::Visitor<'de> for LosslessDatetimeVisitor {
+            type Value = LosslessDatetime;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a TOML datetime")
+            }
+
+            fn visit_map<V>(self, mut visitor: V) -> Result<LosslessDatetime, V::Error>
+            where
+                V: serde_core::de::MapAccess<'de>,
+            {
+                let value = visitor.next_key::<DatetimeKey>()?;
+                if value.is_none() {
+                    return Err(serde_core::de::Error::custom("datetime key not found"));
+                }
+                let v: DatetimeFromString<LosslessDatetime> = visitor.next_value()?;
+                Ok(v.value)
+            }
+        }
+
+        static FIELDS: [&str; 1] = [FIELD];
+        deserializer.deserialize_struct(NAME, &FIELDS, LosslessDatetimeVisitor)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<'de> serde_core::de::Deserialize<'de> for Date {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -926,26 +1225,32 @@ impl<'de> serde_core::de::Deserialize<'de> for DatetimeKey {
 }
 
 #[cfg(feature = "serde")]
-pub(crate) struct DatetimeFromString {
-    pub(crate) value: Datetime,
+pub(crate) struct DatetimeFromString<T = Datetime> {
+    pub(crate) value: T,
 }
 
 #[cfg(feature = "serde")]
-impl<'de> serde_core::de::Deserialize<'de> for DatetimeFromString {
+impl<'de, T> serde_core::de::Deserialize<'de> for DatetimeFromString<T>
+where
+    T: FromStr<Err = DatetimeParseError>,
+{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde_core::de::Deserializer<'de>,
     {
-        struct Visitor;
+        struct Visitor<T>(core::marker::PhantomData<T>);
 
-        impl serde_core::de::Visitor<'_> for Visitor {
-            type Value = DatetimeFromString;
+        impl<T> serde_core::de::Visitor<'_> for Visitor<T>
+        where
+            T: FromStr<Err = DatetimeParseError>,
+        {
+            type Value = DatetimeFromString<T>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("string containing a datetime")
             }
 
-            fn visit_str<E>(self, s: &str) -> Result<DatetimeFromString, E>
+            fn visit_str<E>(self, s: &str) -> Result<DatetimeFromString<T>, E>
             where
                 E: serde_core::de::Error,
             {
@@ -956,6 +1261,6 @@ impl<'de> serde_core::de::Deserialize<'de> for DatetimeFromString {
             }
         }
 
-        deserializer.deserialize_str(Visitor)
+        deserializer.deserialize_str(Visitor(core::marker::PhantomData))
     }
 }
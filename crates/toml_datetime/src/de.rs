@@ -12,13 +12,19 @@ pub fn is_datetime(name: &'static str) -> bool {
 
 /// Deserializer / format support for emitting [`Datetime`][crate::Datetime]
 pub struct DatetimeDeserializer<E> {
-    date: Option<crate::Datetime>,
+    date: Option<crate::LosslessDatetime>,
     _error: core::marker::PhantomData<E>,
 }
 
 impl<E> DatetimeDeserializer<E> {
     /// Create a deserializer to emit [`Datetime`][crate::Datetime]
     pub fn new(date: crate::Datetime) -> Self {
+        Self::new_lossless(date.into())
+    }
+
+    /// Create a deserializer to emit [`Datetime`][crate::Datetime], keeping fractional seconds
+    /// as written for [`LosslessDatetime`][crate::LosslessDatetime]
+    pub fn new_lossless(date: crate::LosslessDatetime) -> Self {
         Self {
             date: Some(date),
             _error: Default::default(),
@@ -59,8 +65,9 @@ where
 /// Integrate [`Datetime`][crate::Datetime] into an untagged deserialize
 #[cfg(feature = "alloc")]
 pub enum VisitMap<'de> {
-    /// The map was deserialized as a [Datetime][crate::Datetime] value
-    Datetime(crate::Datetime),
+    /// The map was deserialized as a [Datetime][crate::Datetime] value, keeping fractional
+    /// seconds as written
+    Datetime(crate::LosslessDatetime),
     /// The map is of an unknown format and needs further deserialization
     Key(alloc::borrow::Cow<'de, str>),
 }
@@ -77,7 +84,8 @@ impl<'de> VisitMap<'de> {
         let result = if let Some(key) = key {
             VisitMap::Key(key)
         } else {
-            let date: crate::datetime::DatetimeFromString = visitor.next_value()?;
+            let date: crate::datetime::DatetimeFromString<crate::LosslessDatetime> =
+                visitor.next_value()?;
             VisitMap::Datetime(date.value)
         };
         Ok(Some(result))
@@ -0,0 +1,229 @@
+use core::fmt::{self, Write as _};
+
+use crate::Datetime;
+use crate::Offset;
+use crate::Time;
+use crate::datetime::NANOSECOND_PRECISION;
+
+/// Describes how a TOML datetime should be formatted.
+///
+/// By default, this matches `Display` for [`Datetime`], writing seconds when present and
+/// fractional seconds as precise as they were parsed.
+/// To keep fractional seconds beyond nanoseconds or with trailing zeros, see
+/// [`DatetimeFormat::format_lossless`].
+///
+/// # Example
+///
+/// ```rust
+/// # use toml_datetime::{Datetime, DatetimeFormat};
+/// let datetime: Datetime = "1979-05-27T07:32:00.5Z".parse().unwrap();
+/// let format = DatetimeFormat::new()
+///     .with_space_separator()
+///     .with_lowercase_z()
+///     .with_precision(3);
+/// assert_eq!(format.format(datetime).to_string(), "1979-05-27 07:32:00.500z");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DatetimeFormat {
+    separator: char,
+    z: char,
+    precision: Option<u8>,
+    seconds: Option<bool>,
+}
+
+impl DatetimeFormat {
+    /// Creates a new datetime format, matching `Display` for [`Datetime`].
+    pub fn new() -> Self {
+        Self {
+            separator: 'T',
+            z: 'Z',
+            precision: None,
+            seconds: None,
+        }
+    }
+
+    /// Separates the date and time with `T`, like `1979-05-27T07:32:00` (default).
+    pub fn with_t_separator(mut self) -> Self {
+        self.separator = 'T';
+        self
+    }
+
+    /// Separates the date and time with `t`, like `1979-05-27t07:32:00`.
+    pub fn with_lowercase_t_separator(mut self) -> Self {
+        self.separator = 't';
+        self
+    }
+
+    /// Separates the date and time with a space, like `1979-05-27 07:32:00`.
+    pub fn with_space_separator(mut self) -> Self {
+        self.separator = ' ';
+        self
+    }
+
+    /// Writes a UTC offset as `Z` (default).
+    pub fn with_uppercase_z(mut self) -> Self {
+        self.z = 'Z';
+        self
+    }
+
+    /// Writes a UTC offset as `z`.
+    pub fn with_lowercase_z(mut self) -> Self {
+        self.z = 'z';
+        self
+    }
+
+    /// Writes fractional seconds with exactly `precision` digits, truncating or padding with
+    /// zeros.
+    ///
+    /// A `precision` of `0` omits fractional seconds.
+    pub fn with_precision(mut self, precision: u8) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Writes fractional seconds as precise as they were parsed (default).
+    pub fn with_parsed_precision(mut self) -> Self {
+        self.precision = None;
+        self
+    }
+
+    /// Always writes seconds, as required before TOML 1.1.
+    pub fn with_seconds(mut self) -> Self {
+        self.seconds = Some(true);
+        self
+    }
+
+    /// Omits seconds when they are zero and there are no fractional seconds, as allowed by
+    /// TOML 1.1.
+    pub fn without_seconds(mut self) -> Self {
+        self.seconds = Some(false);
+        self
+    }
+
+    /// Writes seconds when they were parsed (default).
+    pub fn with_parsed_seconds(mut self) -> Self {
+        self.seconds = None;
+        self
+    }
+
+    /// Formats `datetime` as a TOML datetime.
+    pub fn format(self, datetime: Datetime) -> FormattedDatetime<'static> {
+        FormattedDatetime {
+            datetime,
+            fraction: None,
+            format: self,
+        }
+    }
+
+    /// Formats `datetime` as a TOML datetime, with fractional seconds as written.
+    #[cfg(feature = "alloc")]
+    pub fn format_lossless(self, datetime: &crate::LosslessDatetime) -> FormattedDatetime<'_> {
+        FormattedDatetime {
+            datetime: *datetime.datetime(),
+            fraction: datetime.fraction(),
+            format: self,
+        }
+    }
+
+    /// The digits of the fractional seconds to write with the number of zeros to pad them with
+    fn fraction<'f>(
+        &self,
+        time: &Time,
+        fraction: Option<&'f str>,
+        buffer: &'f mut [u8; NANOSECOND_PRECISION],
+    ) -> Option<(&'f str, usize)> {
+        let parsed = fraction.or_else(|| {
+            time.nanosecond
+                .map(|nanosecond| nanosecond_digits(nanosecond, buffer))
+        });
+        match self.precision {
+            None => parsed.map(|digits| (digits, 0)),
+            Some(0) => None,
+            Some(precision) => {
+                let precision = usize::from(precision);
+                let digits = parsed.unwrap_or("0");
+                let digits = &digits[..digits.len().min(precision)];
+                Some((digits, precision - digits.len()))
+            }
+        }
+    }
+
+    fn write_time(
+        &self,
+        time: &Time,
+        fraction: Option<&str>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{:02}:{:02}", time.hour, time.minute)?;
+        let mut buffer = [b'0'; NANOSECOND_PRECISION];
+        let fraction = self.fraction(time, fraction, &mut buffer);
+        let second = time.second.unwrap_or(0);
+        let write_second = match self.seconds {
+            Some(true) => true,
+            Some(false) => second != 0 || fraction.is_some(),
+            None => time.second.is_some() || fraction.is_some(),
+        };
+        if write_second {
+            write!(f, ":{second:02}")?;
+        }
+        if let Some((digits, padding)) = fraction {
+            write!(f, ".{digits}")?;
+            for _ in 0..padding {
+                f.write_char('0')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The shortest digits for `nanosecond`, keeping at least one
+fn nanosecond_digits(nanosecond: u32, buffer: &mut [u8; NANOSECOND_PRECISION]) -> &str {
+    let mut remaining = nanosecond;
+    for digit in buffer.iter_mut().rev() {
+        *digit = b'0' + (remaining % 10) as u8;
+        remaining /= 10;
+    }
+    let len = buffer
+        .iter()
+        .rposition(|b| *b != b'0')
+        .map(|i| i + 1)
+        .unwrap_or(1);
+    core::str::from_utf8(&buffer[..len]).expect("ASCII digits")
+}
+
+impl Default for DatetimeFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Helper struct for formatting TOML datetimes.
+///
+/// This may be constructed by calling [`DatetimeFormat::format()`] or
+/// [`DatetimeFormat::format_lossless()`].
+#[derive(Copy, Clone, Debug)]
+pub struct FormattedDatetime<'f> {
+    datetime: Datetime,
+    fraction: Option<&'f str>,
+    format: DatetimeFormat,
+}
+
+impl fmt::Display for FormattedDatetime<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref date) = self.datetime.date {
+            write!(f, "{date}")?;
+        }
+        if let Some(ref time) = self.datetime.time {
+            if self.datetime.date.is_some() {
+                f.write_char(self.format.separator)?;
+            }
+            self.format.write_time(time, self.fraction, f)?;
+        }
+        match self.datetime.offset {
+            Some(Offset::Z) => f.write_char(self.format.z)?,
+            Some(offset) => write!(f, "{offset}")?,
+            None => {}
+        }
+        Ok(())
+    }
+}
@@ -19,8 +19,17 @@
 #[allow(unused_extern_crates)]
 extern crate alloc;
 
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+mod compat;
 mod datetime;
+mod format;
+mod timestamp;
 
+#[cfg(feature = "serde")]
+#[cfg(feature = "alloc")]
+pub mod as_datetime;
+#[cfg(feature = "portable")]
+pub mod as_string;
 #[cfg(feature = "serde")]
 #[cfg(feature = "alloc")]
 pub mod de;
@@ -28,11 +37,19 @@ pub mod de;
 #[cfg(feature = "alloc")]
 pub mod ser;
 
+#[cfg(feature = "portable")]
+pub use crate::as_string::PortableDatetime;
 pub use crate::datetime::Date;
 pub use crate::datetime::Datetime;
+pub use crate::datetime::DatetimeConversionError;
 pub use crate::datetime::DatetimeParseError;
+#[cfg(feature = "alloc")]
+pub use crate::datetime::LosslessDatetime;
 pub use crate::datetime::Offset;
 pub use crate::datetime::Time;
+pub use crate::format::DatetimeFormat;
+pub use crate::format::FormattedDatetime;
+pub use crate::timestamp::Timestamp;
 
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
@@ -5,6 +5,26 @@ pub fn is_datetime(name: &'static str) -> bool {
     crate::datetime::is_datetime(name)
 }
 
+/// Check if serializing a [`Datetime`][crate::Datetime] as a newtype struct wrapping a string
+///
+/// See [`as_string`][crate::as_string] and [`datetime_from_string`]
+#[cfg(feature = "portable")]
+pub fn is_datetime_string(name: &'static str) -> bool {
+    crate::datetime::is_datetime_string(name)
+}
+
+/// Extract the [`Datetime`][crate::Datetime] from the value of an [`is_datetime_string`]
+/// newtype struct
+#[cfg(feature = "portable")]
+pub fn datetime_from_string<T>(value: &T) -> Result<crate::Datetime, SerializerError>
+where
+    T: serde_core::ser::Serialize + ?Sized,
+{
+    value
+        .serialize(DatetimeFieldSerializer::default())
+        .map(Into::into)
+}
+
 /// See [`DatetimeSerializer`]
 #[derive(Debug)]
 #[non_exhaustive]
@@ -38,7 +58,7 @@ impl core::error::Error for SerializerError {}
 /// Serializer / format support for emitting [`Datetime`][crate::Datetime]
 #[derive(Default)]
 pub struct DatetimeSerializer {
-    value: Option<crate::Datetime>,
+    value: Option<crate::LosslessDatetime>,
 }
 
 impl DatetimeSerializer {
@@ -65,6 +85,11 @@ impl DatetimeSerializer {
 
     /// See [`serde_core::ser::SerializeStruct::end`]
     pub fn end(self) -> Result<crate::Datetime, SerializerError> {
+        self.end_lossless().map(Into::into)
+    }
+
+    /// Like [`DatetimeSerializer::end`], keeping fractional seconds as written
+    pub fn end_lossless(self) -> Result<crate::LosslessDatetime, SerializerError> {
         self.value.ok_or(SerializerError::InvalidProtocol)
     }
 }
@@ -73,7 +98,7 @@ impl DatetimeSerializer {
 struct DatetimeFieldSerializer {}
 
 impl serde_core::ser::Serializer for DatetimeFieldSerializer {
-    type Ok = crate::Datetime;
+    type Ok = crate::LosslessDatetime;
     type Error = SerializerError;
     type SerializeSeq = serde_core::ser::Impossible<Self::Ok, Self::Error>;
     type SerializeTuple = serde_core::ser::Impossible<Self::Ok, Self::Error>;
@@ -132,7 +157,7 @@ impl serde_core::ser::Serializer for DatetimeFieldSerializer {
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        v.parse::<crate::Datetime>()
+        v.parse::<crate::LosslessDatetime>()
             .map_err(SerializerError::InvalidFormat)
     }
 
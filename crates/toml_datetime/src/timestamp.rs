@@ -0,0 +1,167 @@
+use crate::Date;
+use crate::Datetime;
+use crate::Offset;
+use crate::Time;
+
+/// An instant in time, as an offset from the Unix epoch, `1970-01-01T00:00:00Z`
+///
+/// Unlike [`Datetime`], ordering a `Timestamp` is chronological, see [`Datetime::to_timestamp`].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
+pub struct Timestamp {
+    seconds: i64,
+    nanoseconds: u32,
+}
+
+impl Timestamp {
+    /// The Unix epoch, `1970-01-01T00:00:00Z`
+    pub const UNIX_EPOCH: Self = Self {
+        seconds: 0,
+        nanoseconds: 0,
+    };
+
+    /// Create a `Timestamp` from seconds since the Unix epoch and the nanoseconds within that
+    /// second
+    ///
+    /// Returns `None` if `nanoseconds` is a second or more.
+    pub fn new(seconds: i64, nanoseconds: u32) -> Option<Self> {
+        (nanoseconds < NANOS_PER_SECOND).then_some(Self {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Whole seconds since the Unix epoch, rounded down
+    pub fn seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Nanoseconds after [`Timestamp::seconds`]
+    pub fn subsec_nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Convert to [`std::time::SystemTime`]
+    ///
+    /// Returns `None` if the platform can't represent the instant.
+    #[cfg(feature = "std")]
+    pub fn to_system_time(self) -> Option<std::time::SystemTime> {
+        let epoch = std::time::SystemTime::UNIX_EPOCH;
+        let nanoseconds = core::time::Duration::from_nanos(u64::from(self.nanoseconds));
+        let seconds = core::time::Duration::from_secs(self.seconds.unsigned_abs());
+        if 0 <= self.seconds {
+            epoch.checked_add(seconds)?.checked_add(nanoseconds)
+        } else {
+            epoch.checked_sub(seconds)?.checked_add(nanoseconds)
+        }
+    }
+}
+
+impl Datetime {
+    /// The instant in time of an [Offset Date-Time](https://toml.io/en/v1.0.0#offset-date-time)
+    ///
+    /// Returns `None` for local dates and times as they have no relation to an instant in time.
+    ///
+    /// Like Unix time, a leap second is treated as the first second of the next day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use toml_datetime::Datetime;
+    /// let utc: Datetime = "1979-05-27T07:32:00Z".parse().unwrap();
+    /// let pdt: Datetime = "1979-05-27T00:32:00-07:00".parse().unwrap();
+    /// assert_ne!(utc, pdt);
+    /// assert_eq!(utc.to_timestamp(), pdt.to_timestamp());
+    /// assert_eq!(utc.to_timestamp().unwrap().seconds(), 296_638_320);
+    /// ```
+    pub fn to_timestamp(&self) -> Option<Timestamp> {
+        let (Some(date), Some(time), Some(offset)) = (self.date, self.time, self.offset) else {
+            return None;
+        };
+        let offset = match offset {
+            Offset::Z => 0,
+            Offset::Custom { minutes } => i64::from(minutes) * SECONDS_PER_MINUTE,
+        };
+        let seconds = days_from_civil(date) * SECONDS_PER_DAY
+            + i64::from(time.hour) * SECONDS_PER_HOUR
+            + i64::from(time.minute) * SECONDS_PER_MINUTE
+            + i64::from(time.second.unwrap_or(0))
+            - offset;
+        Timestamp::new(seconds, time.nanosecond.unwrap_or(0))
+    }
+
+    /// Create an [Offset Date-Time](https://toml.io/en/v1.0.0#offset-date-time) in UTC
+    ///
+    /// Returns `None` if the year is outside of what TOML can represent, `0000` to `9999`.
+    pub fn from_timestamp(timestamp: Timestamp) -> Option<Self> {
+        let days = timestamp.seconds.div_euclid(SECONDS_PER_DAY);
+        let seconds = timestamp.seconds.rem_euclid(SECONDS_PER_DAY);
+        let date = civil_from_days(days)?;
+        let time = Time {
+            hour: (seconds / SECONDS_PER_HOUR) as u8,
+            minute: (seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE) as u8,
+            second: Some((seconds % SECONDS_PER_MINUTE) as u8),
+            nanosecond: (timestamp.nanoseconds != 0).then_some(timestamp.nanoseconds),
+        };
+        Some(Self {
+            date: Some(date),
+            time: Some(time),
+            offset: Some(Offset::Z),
+        })
+    }
+
+    /// Compare the instants in time of two [Offset Date-Times]
+    ///
+    /// Unlike [`Ord`], which compares fields, this accounts for the offset so `07:32:00Z` and
+    /// `00:32:00-07:00` on the same day are equal.
+    ///
+    /// Returns `None` unless both are [Offset Date-Times].
+    ///
+    /// [Offset Date-Times]: https://toml.io/en/v1.0.0#offset-date-time
+    pub fn cmp_instant(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.to_timestamp()?.cmp(&other.to_timestamp()?))
+    }
+}
+
+/// Days since the Unix epoch for a proleptic Gregorian date
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(date: Date) -> i64 {
+    let month = i64::from(date.month);
+    let year = i64::from(date.year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(date.day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * DAYS_PER_ERA + day_of_era - UNIX_EPOCH_DAYS
+}
+
+/// Proleptic Gregorian date for days since the Unix epoch
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> Option<Date> {
+    let days = days.checked_add(UNIX_EPOCH_DAYS)?;
+    let era = days.div_euclid(DAYS_PER_ERA);
+    let day_of_era = days.rem_euclid(DAYS_PER_ERA);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = (shifted_month + 2) % 12 + 1;
+    let year = era
+        .checked_mul(400)?
+        .checked_add(year_of_era + i64::from(month <= 2))?;
+    Some(Date {
+        year: u16::try_from(year).ok().filter(|year| *year <= 9999)?,
+        month: month as u8,
+        day: day as u8,
+    })
+}
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+const SECONDS_PER_MINUTE: i64 = 60;
+const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+const DAYS_PER_ERA: i64 = 146_097;
+/// Days from `0000-03-01` to `1970-01-01`
+const UNIX_EPOCH_DAYS: i64 = 719_468;
@@ -0,0 +1,172 @@
+use toml_datetime::Date;
+use toml_datetime::Datetime;
+use toml_datetime::Offset;
+use toml_datetime::Time;
+use toml_datetime::Timestamp;
+
+#[track_caller]
+fn datetime(input: &str) -> Datetime {
+    input.parse().unwrap()
+}
+
+#[test]
+fn date_new() {
+    assert_eq!(
+        Date::new(2000, 2, 29).unwrap(),
+        Date {
+            year: 2000,
+            month: 2,
+            day: 29
+        }
+    );
+    assert_eq!(
+        Date::new(1900, 2, 29).unwrap_err().to_string(),
+        "invalid date, expected day between 01 and 28"
+    );
+    assert_eq!(
+        Date::new(2023, 4, 31).unwrap_err().to_string(),
+        "invalid date, expected day between 01 and 30"
+    );
+    assert_eq!(
+        Date::new(2023, 13, 1).unwrap_err().to_string(),
+        "invalid date, expected month between 01 and 12"
+    );
+    assert_eq!(
+        Date::new(10_000, 1, 1).unwrap_err().to_string(),
+        "invalid date, expected a four-digit year (YYYY)"
+    );
+}
+
+#[test]
+fn time_new() {
+    assert!(Time::new(23, 59, Some(60), Some(999_999_999)).is_ok());
+    assert_eq!(
+        Time::new(23, 60, None, None).unwrap_err().to_string(),
+        "invalid time, expected minute between 00 and 59"
+    );
+    assert_eq!(
+        Time::new(23, 59, Some(61), None).unwrap_err().to_string(),
+        "invalid time, expected second between 00 and 60"
+    );
+}
+
+#[test]
+fn datetime_new() {
+    let date = Date::new(1990, 12, 31).unwrap();
+    let leap = Time::new(23, 59, Some(60), None).unwrap();
+    assert!(Datetime::new(Some(date), Some(leap), Some(Offset::Z)).is_ok());
+    assert!(Datetime::new(Some(date), Some(leap), None).is_ok());
+    assert!(Datetime::new(None, Some(leap), None).is_ok());
+
+    let leap = Time::new(5, 29, Some(60), None).unwrap();
+    let ist = Offset::Custom { minutes: 330 };
+    assert!(Datetime::new(Some(date), Some(leap), Some(ist)).is_ok());
+    assert_eq!(
+        Datetime::new(Some(date), Some(leap), Some(Offset::Z))
+            .unwrap_err()
+            .to_string(),
+        "invalid time, expected leap second at 23:59:60 UTC"
+    );
+
+    assert_eq!(
+        Datetime::new(Some(date), None, Some(Offset::Z))
+            .unwrap_err()
+            .to_string(),
+        "invalid datetime, expected offset datetime, local datetime, local date, or local time"
+    );
+    assert_eq!(
+        Datetime::new(
+            Some(date),
+            Some(leap),
+            Some(Offset::Custom { minutes: 24 * 60 })
+        )
+        .unwrap_err()
+        .to_string(),
+        "invalid offset, expected hours between 00 and 23"
+    );
+}
+
+#[test]
+fn to_timestamp() {
+    let epoch = datetime("1970-01-01T00:00:00Z").to_timestamp().unwrap();
+    assert_eq!(epoch, Timestamp::UNIX_EPOCH);
+
+    let before = datetime("1969-12-31T23:59:59.5Z").to_timestamp().unwrap();
+    assert_eq!(
+        (before.seconds(), before.subsec_nanoseconds()),
+        (-1, 500_000_000)
+    );
+
+    let min = datetime("0000-01-01T00:00:00Z").to_timestamp().unwrap();
+    assert_eq!(min.seconds(), -62_167_219_200);
+    let max = datetime("9999-12-31T23:59:59Z").to_timestamp().unwrap();
+    assert_eq!(max.seconds(), 253_402_300_799);
+
+    assert_eq!(datetime("1979-05-27T07:32:00").to_timestamp(), None);
+    assert_eq!(datetime("1979-05-27").to_timestamp(), None);
+    assert_eq!(datetime("07:32:00").to_timestamp(), None);
+}
+
+#[test]
+fn from_timestamp() {
+    for input in [
+        "0000-01-01T00:00:00Z",
+        "0000-02-29T12:00:00Z",
+        "1969-12-31T23:59:59.5Z",
+        "1970-01-01T00:00:00Z",
+        "2000-02-29T00:00:00.000001Z",
+        "9999-12-31T23:59:59.999999999Z",
+    ] {
+        let expected = datetime(input);
+        let actual = Datetime::from_timestamp(expected.to_timestamp().unwrap()).unwrap();
+        assert_eq!(actual, expected, "{input}");
+    }
+
+    let leap = datetime("1990-12-31T23:59:60Z").to_timestamp().unwrap();
+    assert_eq!(
+        Datetime::from_timestamp(leap).unwrap(),
+        datetime("1991-01-01T00:00:00Z")
+    );
+
+    let before_min = Timestamp::new(-62_167_219_201, 0).unwrap();
+    assert_eq!(Datetime::from_timestamp(before_min), None);
+    let after_max = Timestamp::new(253_402_300_800, 0).unwrap();
+    assert_eq!(Datetime::from_timestamp(after_max), None);
+    assert_eq!(Timestamp::new(0, 1_000_000_000), None);
+}
+
+#[test]
+fn cmp_instant() {
+    use std::cmp::Ordering;
+
+    let utc = datetime("1979-05-27T08:00:00Z");
+    let west = datetime("1979-05-27T07:00:00-01:00");
+    assert!(utc > west);
+    assert_eq!(utc.cmp_instant(&west), Some(Ordering::Equal));
+
+    let east = datetime("1979-05-27T08:30:00+01:00");
+    assert!(utc < east);
+    assert_eq!(utc.cmp_instant(&east), Some(Ordering::Greater));
+
+    let local = datetime("1979-05-27T08:00:00");
+    assert_eq!(utc.cmp_instant(&local), None);
+}
+
+#[test]
+fn system_time() {
+    let time = datetime("1979-05-27T00:32:00.5-07:00")
+        .to_timestamp()
+        .unwrap()
+        .to_system_time()
+        .unwrap();
+    let since = time.duration_since(std::time::UNIX_EPOCH).unwrap();
+    assert_eq!(since.as_millis(), 296_638_320_500);
+
+    let time = datetime("1969-12-31T23:59:59.25Z")
+        .to_timestamp()
+        .unwrap()
+        .to_system_time()
+        .unwrap();
+    let until = std::time::UNIX_EPOCH.duration_since(time).unwrap();
+    assert_eq!(until.as_millis(), 750);
+}
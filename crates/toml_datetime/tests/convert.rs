@@ -0,0 +1,149 @@
+#![cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+
+use toml_datetime::Datetime;
+
+#[track_caller]
+fn datetime(input: &str) -> Datetime {
+    input.parse().unwrap()
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    use super::datetime;
+    use toml_datetime::Datetime;
+
+    #[test]
+    fn offset_datetime() {
+        let toml = datetime("1979-05-27T00:32:00.999999-07:00");
+        let chrono = ::chrono::DateTime::<::chrono::FixedOffset>::try_from(toml).unwrap();
+        assert_eq!(chrono.timestamp(), 296_638_320);
+        assert_eq!(Datetime::try_from(chrono).unwrap(), toml);
+
+        let utc = ::chrono::DateTime::<::chrono::Utc>::try_from(toml).unwrap();
+        assert_eq!(
+            Datetime::try_from(utc).unwrap().to_string(),
+            "1979-05-27T07:32:00.999999Z"
+        );
+    }
+
+    #[test]
+    fn local() {
+        let toml = datetime("1979-05-27T07:32:00");
+        let chrono = ::chrono::NaiveDateTime::try_from(toml).unwrap();
+        assert_eq!(Datetime::try_from(chrono).unwrap(), toml);
+
+        let toml = datetime("1979-05-27");
+        let chrono = ::chrono::NaiveDate::try_from(toml).unwrap();
+        assert_eq!(Datetime::try_from(chrono).unwrap(), toml);
+
+        let toml = datetime("07:32:00.5");
+        let chrono = ::chrono::NaiveTime::try_from(toml).unwrap();
+        assert_eq!(Datetime::from(chrono), toml);
+    }
+
+    #[test]
+    fn leap_second() {
+        let toml = datetime("23:59:60.25");
+        let chrono = ::chrono::NaiveTime::try_from(toml).unwrap();
+        assert_eq!(::chrono::Timelike::second(&chrono), 59);
+        assert_eq!(::chrono::Timelike::nanosecond(&chrono), 1_250_000_000);
+        assert_eq!(Datetime::from(chrono), toml);
+    }
+
+    #[test]
+    fn mismatch() {
+        let err = ::chrono::NaiveDateTime::try_from(datetime("1979-05-27T07:32:00Z")).unwrap_err();
+        assert_eq!(err.to_string(), "expected a local datetime");
+        let err = ::chrono::NaiveDate::try_from(datetime("07:32:00")).unwrap_err();
+        assert_eq!(err.to_string(), "expected a local date");
+        let err = ::chrono::DateTime::<::chrono::FixedOffset>::try_from(datetime("1979-05-27"))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "expected an offset datetime");
+    }
+
+    #[test]
+    fn out_of_range() {
+        let date = ::chrono::NaiveDate::from_ymd_opt(10_000, 1, 1).unwrap();
+        let err = Datetime::try_from(date).unwrap_err();
+        assert_eq!(err.to_string(), "year is out of range for TOML");
+
+        let offset = ::chrono::FixedOffset::east_opt(30).unwrap();
+        let err = toml_datetime::Offset::try_from(offset).unwrap_err();
+        assert_eq!(err.to_string(), "offset is not a whole number of minutes");
+    }
+}
+
+#[cfg(feature = "time")]
+mod time {
+    use super::datetime;
+    use toml_datetime::Datetime;
+
+    #[test]
+    fn offset_datetime() {
+        let toml = datetime("1979-05-27T00:32:00.999999-07:00");
+        let time = ::time::OffsetDateTime::try_from(toml).unwrap();
+        assert_eq!(time.unix_timestamp(), 296_638_320);
+        assert_eq!(Datetime::try_from(time).unwrap(), toml);
+    }
+
+    #[test]
+    fn local() {
+        let toml = datetime("1979-05-27T07:32:00");
+        let time = ::time::PrimitiveDateTime::try_from(toml).unwrap();
+        assert_eq!(Datetime::try_from(time).unwrap(), toml);
+
+        let toml = datetime("1979-05-27");
+        let time = ::time::Date::try_from(toml).unwrap();
+        assert_eq!(Datetime::try_from(time).unwrap(), toml);
+
+        let toml = datetime("07:32:00.5");
+        let time = ::time::Time::try_from(toml).unwrap();
+        assert_eq!(Datetime::from(time), toml);
+    }
+
+    #[test]
+    fn mismatch() {
+        let err = ::time::OffsetDateTime::try_from(datetime("1979-05-27T07:32:00")).unwrap_err();
+        assert_eq!(err.to_string(), "expected an offset datetime");
+        let err = ::time::Time::try_from(datetime("23:59:60")).unwrap_err();
+        assert_eq!(err.to_string(), "invalid time");
+    }
+}
+
+#[cfg(feature = "jiff")]
+mod jiff {
+    use super::datetime;
+    use toml_datetime::Datetime;
+
+    #[test]
+    fn timestamp() {
+        let toml = datetime("1979-05-27T00:32:00.999999-07:00");
+        let jiff = ::jiff::Timestamp::try_from(toml).unwrap();
+        assert_eq!(jiff.as_second(), 296_638_320);
+        assert_eq!(
+            Datetime::try_from(jiff).unwrap().to_string(),
+            "1979-05-27T07:32:00.999999Z"
+        );
+    }
+
+    #[test]
+    fn local() {
+        let toml = datetime("1979-05-27T07:32:00");
+        let jiff = ::jiff::civil::DateTime::try_from(toml).unwrap();
+        assert_eq!(Datetime::try_from(jiff).unwrap(), toml);
+
+        let toml = datetime("1979-05-27");
+        let jiff = ::jiff::civil::Date::try_from(toml).unwrap();
+        assert_eq!(Datetime::try_from(jiff).unwrap(), toml);
+
+        let toml = datetime("07:32:00.5");
+        let jiff = ::jiff::civil::Time::try_from(toml).unwrap();
+        assert_eq!(Datetime::from(jiff), toml);
+    }
+
+    #[test]
+    fn mismatch() {
+        let err = ::jiff::Timestamp::try_from(datetime("1979-05-27")).unwrap_err();
+        assert_eq!(err.to_string(), "expected an offset datetime");
+    }
+}
@@ -1,5 +1,6 @@
 #![cfg(feature = "alloc")]
 
+use snapbox::assert_data_eq;
 use snapbox::prelude::*;
 use snapbox::str;
 
@@ -99,3 +100,114 @@ fn datetime_without_seconds() {
 "#]],
     );
 }
+
+#[test]
+fn fraction_round_trip() {
+    for input in [
+        "07:32:00.5",
+        "07:32:00.500",
+        "07:32:00.000",
+        "07:32:00.123456789",
+        "07:32:00.1234567891",
+        "1979-05-27T07:32:00.99999999999999999999Z",
+    ] {
+        let datetime = input.parse::<LosslessDatetime>().unwrap();
+        assert_eq!(datetime.to_string(), input);
+    }
+
+    let datetime = "07:32:00.1234567891".parse::<LosslessDatetime>().unwrap();
+    assert_eq!(datetime.fraction(), Some("1234567891"));
+    assert_eq!(
+        datetime.datetime().time.unwrap().nanosecond,
+        Some(123_456_789)
+    );
+    assert_eq!(datetime.datetime().to_string(), "07:32:00.123456789");
+    assert_eq!(datetime, "07:32:00.123456789".parse().unwrap());
+
+    let datetime = "07:32:00.500".parse::<Datetime>().unwrap();
+    assert_eq!(datetime.to_string(), "07:32:00.5");
+    assert_eq!(LosslessDatetime::from(datetime).fraction(), None);
+}
+
+#[test]
+fn format_lossless() {
+    let datetime = "1979-05-27T07:32:00.1234567891Z"
+        .parse::<LosslessDatetime>()
+        .unwrap();
+    assert_data_eq!(
+        DatetimeFormat::new()
+            .with_space_separator()
+            .format_lossless(&datetime)
+            .to_string(),
+        str!["1979-05-27 07:32:00.1234567891Z"]
+    );
+    assert_data_eq!(
+        DatetimeFormat::new()
+            .with_precision(12)
+            .format_lossless(&datetime)
+            .to_string(),
+        str!["1979-05-27T07:32:00.123456789100Z"]
+    );
+}
+
+#[test]
+fn format() {
+    let datetime = "1979-05-27T07:32:00.123456789Z"
+        .parse::<Datetime>()
+        .unwrap();
+    let format = |format: DatetimeFormat| format.format(datetime).to_string();
+    assert_data_eq!(
+        format(DatetimeFormat::new()),
+        str!["1979-05-27T07:32:00.123456789Z"]
+    );
+    assert_data_eq!(
+        format(DatetimeFormat::new().with_lowercase_t_separator()),
+        str!["1979-05-27t07:32:00.123456789Z"]
+    );
+    assert_data_eq!(
+        format(
+            DatetimeFormat::new()
+                .with_space_separator()
+                .with_lowercase_z()
+        ),
+        str!["1979-05-27 07:32:00.123456789z"]
+    );
+    assert_data_eq!(
+        format(DatetimeFormat::new().with_precision(3)),
+        str!["1979-05-27T07:32:00.123Z"]
+    );
+    assert_data_eq!(
+        format(DatetimeFormat::new().with_precision(12)),
+        str!["1979-05-27T07:32:00.123456789000Z"]
+    );
+    assert_data_eq!(
+        format(DatetimeFormat::new().with_precision(0).without_seconds()),
+        str!["1979-05-27T07:32Z"]
+    );
+}
+
+#[test]
+fn format_seconds() {
+    let without = "07:32".parse::<Datetime>().unwrap();
+    let with = "07:32:00".parse::<Datetime>().unwrap();
+    let leap = "23:59:60".parse::<Datetime>().unwrap();
+    let format = |format: DatetimeFormat| {
+        [without, with, leap].map(|datetime| format.format(datetime).to_string())
+    };
+    assert_eq!(
+        format(DatetimeFormat::new()),
+        ["07:32", "07:32:00", "23:59:60"]
+    );
+    assert_eq!(
+        format(DatetimeFormat::new().with_seconds()),
+        ["07:32:00", "07:32:00", "23:59:60"]
+    );
+    assert_eq!(
+        format(DatetimeFormat::new().without_seconds()),
+        ["07:32", "07:32", "23:59:60"]
+    );
+    assert_eq!(
+        format(DatetimeFormat::new().without_seconds().with_precision(1)),
+        ["07:32:00.0", "07:32:00.0", "23:59:60.0"]
+    );
+}
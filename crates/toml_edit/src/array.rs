@@ -422,7 +422,7 @@ impl<'s> IntoIterator for &'s Array {
     }
 }
 
-fn decorate_array(array: &mut Array) {
+pub(crate) fn decorate_array(array: &mut Array) {
     for (i, value) in array
         .values
         .iter_mut()
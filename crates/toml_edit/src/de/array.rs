@@ -1,13 +1,27 @@
+use std::sync::Arc;
+
 use crate::de::Error;
 
 pub(crate) struct ArrayDeserializer {
     input: Vec<crate::Item>,
     span: Option<std::ops::Range<usize>>,
+    source_id: Option<usize>,
+    raw: Option<Arc<str>>,
 }
 
 impl ArrayDeserializer {
-    pub(crate) fn new(input: Vec<crate::Item>, span: Option<std::ops::Range<usize>>) -> Self {
-        Self { input, span }
+    pub(crate) fn new(
+        input: Vec<crate::Item>,
+        span: Option<std::ops::Range<usize>>,
+        source_id: Option<usize>,
+        raw: Option<Arc<str>>,
+    ) -> Self {
+        Self {
+            input,
+            span,
+            source_id,
+            raw,
+        }
     }
 }
 
@@ -18,7 +32,7 @@ impl<'de> serde_core::Deserializer<'de> for ArrayDeserializer {
     where
         V: serde_core::de::Visitor<'de>,
     {
-        visitor.visit_seq(ArraySeqAccess::new(self.input))
+        visitor.visit_seq(ArraySeqAccess::new(self.input, self.source_id, self.raw))
     }
 
     fn deserialize_struct<V>(
@@ -32,8 +46,10 @@ impl<'de> serde_core::Deserializer<'de> for ArrayDeserializer {
     {
         if serde_spanned::de::is_spanned(name) {
             if let Some(span) = self.span.clone() {
+                let source_id = self.source_id;
                 return visitor.visit_map(
-                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span),
+                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span)
+                        .with_source_id(source_id),
                 );
             } else {
                 return Err(Error::custom("value is missing a span", None));
@@ -60,12 +76,20 @@ impl serde_core::de::IntoDeserializer<'_, Error> for ArrayDeserializer {
 
 pub(crate) struct ArraySeqAccess {
     iter: std::vec::IntoIter<crate::Item>,
+    source_id: Option<usize>,
+    raw: Option<Arc<str>>,
 }
 
 impl ArraySeqAccess {
-    pub(crate) fn new(input: Vec<crate::Item>) -> Self {
+    pub(crate) fn new(
+        input: Vec<crate::Item>,
+        source_id: Option<usize>,
+        raw: Option<Arc<str>>,
+    ) -> Self {
         Self {
             iter: input.into_iter(),
+            source_id,
+            raw,
         }
     }
 }
@@ -79,7 +103,11 @@ impl<'de> serde_core::de::SeqAccess<'de> for ArraySeqAccess {
     {
         match self.iter.next() {
             Some(v) => seed
-                .deserialize(crate::de::ValueDeserializer::new(v))
+                .deserialize(
+                    crate::de::ValueDeserializer::new(v)
+                        .with_source_id(self.source_id)
+                        .with_raw(self.raw.clone()),
+                )
                 .map(Some),
             None => Ok(None),
         }
@@ -0,0 +1,807 @@
+use serde_core::de::value::BorrowedStrDeserializer;
+
+use crate::de::Error;
+use crate::de::KeyDeserializer;
+use crate::de::TableEnumDeserializer;
+use crate::de::value::datetime_deserializer;
+use crate::de::value::validate_struct_keys;
+
+/// Deserialization borrowing from TOML [documents][crate::DocumentMut].
+///
+/// Unlike [`Deserializer`][super::Deserializer], strings and keys are borrowed from the document
+/// rather than copied, so fields like `&'de str` and `Cow<'de, str>` can be deserialized.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "parse")] {
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config<'a> {
+///     title: &'a str,
+/// }
+///
+/// let document = "title = 'TOML Example'".parse::<toml_edit::DocumentMut>().unwrap();
+/// let config: Config<'_> = toml_edit::de::from_document_ref(&document).unwrap();
+/// assert_eq!(config.title, "TOML Example");
+/// # }
+/// ```
+pub struct BorrowedDeserializer<'de> {
+    root: &'de crate::Item,
+    raw: Option<&'de str>,
+}
+
+impl<'de> From<&'de crate::DocumentMut> for BorrowedDeserializer<'de> {
+    fn from(doc: &'de crate::DocumentMut) -> Self {
+        Self {
+            root: &doc.root,
+            raw: None,
+        }
+    }
+}
+
+impl<'de, S: AsRef<str>> From<&'de crate::Document<S>> for BorrowedDeserializer<'de> {
+    fn from(doc: &'de crate::Document<S>) -> Self {
+        Self {
+            root: &doc.root,
+            raw: Some(doc.raw.as_ref()),
+        }
+    }
+}
+
+impl<'de> BorrowedDeserializer<'de> {
+    fn value(&self) -> BorrowedValueDeserializer<'de> {
+        BorrowedValueDeserializer::new(Input::Item(self.root)).with_raw(self.raw)
+    }
+
+    fn with_input(&self, mut e: Error) -> Error {
+        e.set_input(self.raw);
+        e
+    }
+}
+
+impl<'de> serde_core::Deserializer<'de> for BorrowedDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        self.value()
+            .deserialize_any(visitor)
+            .map_err(|e| self.with_input(e))
+    }
+
+    // `None` is interpreted as a missing field so be sure to implement `Some`
+    // as a present field.
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        self.value()
+            .deserialize_option(visitor)
+            .map_err(|e| self.with_input(e))
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        self.value()
+            .deserialize_newtype_struct(name, visitor)
+            .map_err(|e| self.with_input(e))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        self.value()
+            .deserialize_struct(name, fields, visitor)
+            .map_err(|e| self.with_input(e))
+    }
+
+    // Called when the type to deserialize is an enum, as opposed to a field in the type.
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        self.value()
+            .deserialize_enum(name, variants, visitor)
+            .map_err(|e| self.with_input(e))
+    }
+
+    serde_core::forward_to_deserialize_any! {
+        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string seq
+        bytes byte_buf map unit
+        ignored_any unit_struct tuple_struct tuple identifier
+    }
+}
+
+impl<'de> serde_core::de::IntoDeserializer<'de, Error> for BorrowedDeserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Input<'de> {
+    Item(&'de crate::Item),
+    Value(&'de crate::Value),
+}
+
+impl Input<'_> {
+    fn span(self) -> Option<std::ops::Range<usize>> {
+        match self {
+            Self::Item(item) => item.span(),
+            Self::Value(value) => value.span(),
+        }
+    }
+}
+
+/// Deserialization borrowing from TOML [values][crate::Value].
+///
+/// Unlike [`ValueDeserializer`][super::ValueDeserializer], strings and keys are borrowed from the value rather than copied.
+///
+/// This is created with
+/// [`IntoDeserializer::into_deserializer`][serde_core::de::IntoDeserializer::into_deserializer]
+/// on a `&Value`.
+pub struct BorrowedValueDeserializer<'de> {
+    input: Input<'de>,
+    raw: Option<&'de str>,
+    key: Option<&'de crate::Key>,
+}
+
+impl<'de> BorrowedValueDeserializer<'de> {
+    fn new(input: Input<'de>) -> Self {
+        Self {
+            input,
+            raw: None,
+            key: None,
+        }
+    }
+
+    /// The source text of the document, for what the parsed values lose
+    fn with_raw(mut self, raw: Option<&'de str>) -> Self {
+        self.raw = raw;
+        self
+    }
+
+    /// The key of the table entry this value is for, see [`serde_spanned::SpannedEntry`]
+    fn with_key(mut self, key: &'de crate::Key) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+impl<'de> serde_core::Deserializer<'de> for BorrowedValueDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        let span = self.input.span();
+        let value = match self.input {
+            Input::Item(crate::Item::None) => return visitor.visit_none(),
+            Input::Item(crate::Item::Table(v)) => {
+                return BorrowedTableDeserializer::new(&v.items, v.span.clone(), self.raw)
+                    .deserialize_any(visitor)
+                    .map_err(|e| with_span(e, span));
+            }
+            Input::Item(crate::Item::ArrayOfTables(v)) => {
+                return BorrowedArrayDeserializer::new(&v.values, v.span.clone(), self.raw)
+                    .deserialize_any(visitor)
+                    .map_err(|e| with_span(e, span));
+            }
+            Input::Item(crate::Item::Value(v)) | Input::Value(v) => v,
+        };
+        match value {
+            crate::Value::String(v) => visitor.visit_borrowed_str(v.value()),
+            crate::Value::Integer(v) => visitor.visit_i64(*v.value()),
+            crate::Value::Float(v) => visitor.visit_f64(*v.value()),
+            crate::Value::Boolean(v) => visitor.visit_bool(*v.value()),
+            crate::Value::Datetime(v) => visitor.visit_map(datetime_deserializer(v, self.raw)),
+            crate::Value::Array(v) => {
+                BorrowedArrayDeserializer::new(&v.values, v.span.clone(), self.raw)
+                    .deserialize_any(visitor)
+            }
+            crate::Value::InlineTable(v) => {
+                BorrowedTableDeserializer::new(&v.items, v.span.clone(), self.raw)
+                    .deserialize_any(visitor)
+            }
+        }
+        .map_err(|e| with_span(e, span))
+    }
+
+    // `None` is interpreted as a missing field so be sure to implement `Some`
+    // as a present field.
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        let span = self.input.span();
+        visitor.visit_some(self).map_err(|e| with_span(e, span))
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        let span = self.input.span();
+        visitor
+            .visit_newtype_struct(self)
+            .map_err(|e| with_span(e, span))
+    }
+
+    fn deserialize_struct<V>(
+        mut self,
+        name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        if serde_spanned::de::is_spanned(name) {
+            if let Some(span) = self.input.span() {
+                return visitor.visit_map(
+                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span),
+                );
+            } else {
+                return Err(Error::custom("value is missing a span", None));
+            }
+        }
+
+        if serde_spanned::de::is_spanned_entry(name) {
+            if let Some(key) = self.key.take() {
+                let key = BorrowedKeyDeserializer::new(key, key.span());
+                return visitor.visit_map(serde_spanned::de::SpannedEntryDeserializer::<
+                    _,
+                    Self,
+                    Error,
+                >::new(key, self));
+            } else {
+                return Err(Error::custom(
+                    "value is not in a table entry",
+                    self.input.span(),
+                ));
+            }
+        }
+
+        if toml_datetime::de::is_datetime(name) {
+            let span = self.input.span();
+            if let Input::Item(crate::Item::Value(crate::Value::Datetime(d)))
+            | Input::Value(crate::Value::Datetime(d)) = self.input
+            {
+                return visitor
+                    .visit_map(datetime_deserializer(d, self.raw))
+                    .map_err(|e| with_span(e, span));
+            }
+        }
+
+        self.deserialize_any(visitor)
+    }
+
+    // Called when the type to deserialize is an enum, as opposed to a field in the type.
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        let span = self.input.span();
+        match self.input {
+            Input::Item(crate::Item::Value(crate::Value::String(v)))
+            | Input::Value(crate::Value::String(v)) => {
+                visitor.visit_enum(BorrowedStrDeserializer::<Error>::new(v.value()))
+            }
+            Input::Item(crate::Item::Value(crate::Value::InlineTable(v)))
+            | Input::Value(crate::Value::InlineTable(v)) => {
+                if v.is_empty() {
+                    Err(Error::custom(
+                        "wanted exactly 1 element, found 0 elements",
+                        v.span(),
+                    ))
+                } else if v.len() != 1 {
+                    Err(Error::custom(
+                        "wanted exactly 1 element, more than 1 element",
+                        v.span(),
+                    ))
+                } else {
+                    BorrowedTableDeserializer::new(&v.items, v.span.clone(), self.raw)
+                        .deserialize_enum(name, variants, visitor)
+                }
+            }
+            Input::Item(crate::Item::Table(v)) => {
+                BorrowedTableDeserializer::new(&v.items, v.span.clone(), self.raw)
+                    .deserialize_enum(name, variants, visitor)
+            }
+            Input::Item(e) => Err(Error::custom("wanted string or table", e.span())),
+            Input::Value(e) => Err(Error::custom("wanted string or table", e.span())),
+        }
+        .map_err(|e| with_span(e, span))
+    }
+
+    serde_core::forward_to_deserialize_any! {
+        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string seq
+        bytes byte_buf map unit
+        ignored_any unit_struct tuple_struct tuple identifier
+    }
+}
+
+impl<'de> serde_core::de::IntoDeserializer<'de, Error> for BorrowedValueDeserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> serde_core::de::IntoDeserializer<'de, Error> for &'de crate::Value {
+    type Deserializer = BorrowedValueDeserializer<'de>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        BorrowedValueDeserializer::new(Input::Value(self))
+    }
+}
+
+struct BorrowedTableDeserializer<'de> {
+    span: Option<std::ops::Range<usize>>,
+    raw: Option<&'de str>,
+    items: &'de crate::table::KeyValuePairs,
+}
+
+impl<'de> BorrowedTableDeserializer<'de> {
+    fn new(
+        items: &'de crate::table::KeyValuePairs,
+        span: Option<std::ops::Range<usize>>,
+        raw: Option<&'de str>,
+    ) -> Self {
+        Self { span, raw, items }
+    }
+}
+
+impl<'de> serde_core::Deserializer<'de> for BorrowedTableDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        visitor.visit_map(BorrowedTableMapAccess {
+            iter: self.items.iter(),
+            raw: self.raw,
+            value: None,
+        })
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        if serde_spanned::de::is_spanned(name) {
+            if let Some(span) = self.span.clone() {
+                return visitor.visit_map(
+                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span),
+                );
+            } else {
+                return Err(Error::custom("value is missing a span", None));
+            }
+        }
+
+        self.deserialize_any(visitor)
+    }
+
+    // Called when the type to deserialize is an enum, as opposed to a field in the type.
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        let _ = name;
+        let _ = variants;
+        if self.items.is_empty() {
+            Err(Error::custom(
+                "wanted exactly 1 element, found 0 elements",
+                self.span,
+            ))
+        } else if self.items.len() != 1 {
+            Err(Error::custom(
+                "wanted exactly 1 element, more than 1 element",
+                self.span,
+            ))
+        } else {
+            let (key, value) = self.items.iter().next().expect("checked length");
+            visitor.visit_enum(BorrowedTableEnumAccess {
+                key,
+                value,
+                raw: self.raw,
+            })
+        }
+    }
+
+    serde_core::forward_to_deserialize_any! {
+        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string seq
+        bytes byte_buf map option unit newtype_struct
+        ignored_any unit_struct tuple_struct tuple identifier
+    }
+}
+
+impl<'de> serde_core::de::IntoDeserializer<'de, Error> for BorrowedTableDeserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+struct BorrowedTableMapAccess<'de> {
+    iter: indexmap::map::Iter<'de, crate::Key, crate::Item>,
+    raw: Option<&'de str>,
+    value: Option<(&'de crate::Key, &'de crate::Item)>,
+}
+
+impl<'de> serde_core::de::MapAccess<'de> for BorrowedTableMapAccess<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: serde_core::de::DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some((k, v)) => {
+                let key_span = k.span();
+                let ret = seed
+                    .deserialize(BorrowedKeyDeserializer::new(k, key_span.clone()))
+                    .map(Some)
+                    .map_err(|e| with_span(e, key_span));
+                self.value = Some((k, v));
+                ret
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde_core::de::DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some((k, v)) => {
+                let span = v.span().or_else(|| k.span());
+                seed.deserialize(
+                    BorrowedValueDeserializer::new(Input::Item(v))
+                        .with_raw(self.raw)
+                        .with_key(k),
+                )
+                .map_err(|e| {
+                    let mut e = with_span(e, span);
+                    e.add_key(k.get().to_owned());
+                    e
+                })
+            }
+            None => {
+                panic!("no more values in next_value_seed, internal error in ValueDeserializer")
+            }
+        }
+    }
+}
+
+struct BorrowedTableEnumAccess<'de> {
+    key: &'de crate::Key,
+    value: &'de crate::Item,
+    raw: Option<&'de str>,
+}
+
+impl<'de> serde_core::de::EnumAccess<'de> for BorrowedTableEnumAccess<'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: serde_core::de::DeserializeSeed<'de>,
+    {
+        let val = seed
+            .deserialize(BorrowedStrDeserializer::<Error>::new(self.key.get()))
+            .map_err(|e| with_span(e, self.key.span()))?;
+        Ok((val, self))
+    }
+}
+
+impl<'de> serde_core::de::VariantAccess<'de> for BorrowedTableEnumAccess<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        TableEnumDeserializer::new(self.value.clone(), None, None).unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: serde_core::de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(BorrowedValueDeserializer::new(Input::Item(self.value)).with_raw(self.raw))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        let (values, span) = match self.value {
+            crate::Item::ArrayOfTables(values) => (&values.values, values.span()),
+            crate::Item::Value(crate::Value::Array(values)) => (&values.values, values.span()),
+            // Tables with index keys are copied
+            value => {
+                return TableEnumDeserializer::new(value.clone(), None, self.raw.map(Into::into))
+                    .tuple_variant(len, visitor);
+            }
+        };
+        if values.len() == len {
+            serde_core::de::Deserializer::deserialize_seq(
+                BorrowedArrayDeserializer::new(values, span, self.raw),
+                visitor,
+            )
+        } else {
+            Err(Error::custom(
+                format!("expected tuple with length {len}"),
+                span,
+            ))
+        }
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        let span = self.value.span();
+        match self.value {
+            crate::Item::Table(values) => validate_struct_keys(&values.items, fields),
+            crate::Item::Value(crate::Value::InlineTable(values)) => {
+                validate_struct_keys(&values.items, fields)
+            }
+            _ => Ok(()),
+        }
+        .map_err(|e| with_span(e, span))?;
+        serde_core::de::Deserializer::deserialize_struct(
+            BorrowedValueDeserializer::new(Input::Item(self.value)).with_raw(self.raw),
+            "", // TODO: this should be the variant name
+            fields,
+            visitor,
+        )
+    }
+}
+
+struct BorrowedArrayDeserializer<'de> {
+    input: &'de [crate::Item],
+    span: Option<std::ops::Range<usize>>,
+    raw: Option<&'de str>,
+}
+
+impl<'de> BorrowedArrayDeserializer<'de> {
+    fn new(
+        input: &'de [crate::Item],
+        span: Option<std::ops::Range<usize>>,
+        raw: Option<&'de str>,
+    ) -> Self {
+        Self { input, span, raw }
+    }
+}
+
+impl<'de> serde_core::Deserializer<'de> for BorrowedArrayDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        visitor.visit_seq(BorrowedArraySeqAccess {
+            iter: self.input.iter(),
+            raw: self.raw,
+        })
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        if serde_spanned::de::is_spanned(name) {
+            if let Some(span) = self.span.clone() {
+                return visitor.visit_map(
+                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span),
+                );
+            } else {
+                return Err(Error::custom("value is missing a span", None));
+            }
+        }
+
+        self.deserialize_any(visitor)
+    }
+
+    serde_core::forward_to_deserialize_any! {
+        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string seq
+        bytes byte_buf map option unit newtype_struct
+        ignored_any unit_struct tuple_struct tuple enum identifier
+    }
+}
+
+impl<'de> serde_core::de::IntoDeserializer<'de, Error> for BorrowedArrayDeserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+struct BorrowedArraySeqAccess<'de> {
+    iter: std::slice::Iter<'de, crate::Item>,
+    raw: Option<&'de str>,
+}
+
+impl<'de> serde_core::de::SeqAccess<'de> for BorrowedArraySeqAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: serde_core::de::DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some(v) => seed
+                .deserialize(BorrowedValueDeserializer::new(Input::Item(v)).with_raw(self.raw))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+struct BorrowedKeyDeserializer<'de> {
+    span: Option<std::ops::Range<usize>>,
+    key: &'de crate::Key,
+}
+
+impl<'de> BorrowedKeyDeserializer<'de> {
+    fn new(key: &'de crate::Key, span: Option<std::ops::Range<usize>>) -> Self {
+        Self { span, key }
+    }
+
+    fn to_owned(&self) -> KeyDeserializer {
+        KeyDeserializer::new(self.key.clone(), self.span.clone(), None)
+    }
+}
+
+/// Non-string keys are parsed, so there is nothing to borrow
+macro_rules! forward_to_owned_key {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+            where
+                V: serde_core::de::Visitor<'de>,
+            {
+                self.to_owned().$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> serde_core::de::Deserializer<'de> for BorrowedKeyDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        visitor.visit_borrowed_str(self.key.get())
+    }
+
+    forward_to_owned_key! {
+        deserialize_bool deserialize_char
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_i128
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        visitor.visit_enum(BorrowedStrDeserializer::<Error>::new(self.key.get()))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        if serde_spanned::de::is_spanned(name) {
+            if let Some(span) = self.span.clone() {
+                return visitor.visit_map(
+                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span),
+                );
+            } else {
+                return Err(Error::custom("value is missing a span", None));
+            }
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: serde_core::de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde_core::forward_to_deserialize_any! {
+        f32 f64 str string seq
+        bytes byte_buf map option unit
+        ignored_any unit_struct tuple_struct tuple identifier
+    }
+}
+
+impl<'de> serde_core::de::IntoDeserializer<'de, Error> for BorrowedKeyDeserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+fn with_span(mut e: Error, span: Option<std::ops::Range<usize>>) -> Error {
+    if e.span().is_none() {
+        e.set_span(span);
+    }
+    e
+}
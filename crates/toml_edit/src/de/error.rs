@@ -2,6 +2,7 @@
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Error {
     inner: crate::TomlError,
+    source_id: Option<usize>,
 }
 
 impl Error {
@@ -11,6 +12,7 @@ impl Error {
     {
         Self {
             inner: crate::TomlError::custom(msg.to_string(), span),
+            source_id: None,
         }
     }
 
@@ -24,6 +26,16 @@ impl Error {
         self.inner.message()
     }
 
+    /// What went wrong, for handling the error without matching on [`Error::message`]
+    pub fn kind(&self) -> crate::ErrorKind {
+        self.inner.kind()
+    }
+
+    fn with_kind(mut self, kind: crate::ErrorKind) -> Self {
+        self.inner = self.inner.with_kind(kind);
+        self
+    }
+
     /// The start/end index into the original document where the error occurred
     pub fn span(&self) -> Option<std::ops::Range<usize>> {
         self.inner.span()
@@ -37,6 +49,37 @@ impl Error {
     pub fn set_input(&mut self, input: Option<&str>) {
         self.inner.set_input(input);
     }
+
+    /// The encoded TOML the error applies to, if known
+    pub fn input(&self) -> Option<&str> {
+        self.inner.input()
+    }
+
+    /// Identify which source [`Error::input`] is, see [`Error::source_id`]
+    pub fn set_source_id(&mut self, source_id: Option<usize>) {
+        self.source_id = source_id;
+    }
+
+    /// Which source the error applies to, if known
+    ///
+    /// This is set by [`Deserializer::with_source_id`][crate::de::Deserializer::with_source_id].
+    /// Parse errors happen before a source id can be set, so use [`Error::set_source_id`] for
+    /// those.
+    pub fn source_id(&self) -> Option<usize> {
+        self.source_id
+    }
+
+    /// Index [`Error::input`] for converting [`Error::span`] to line / column positions
+    #[cfg(feature = "parse")]
+    pub fn line_index(&self) -> Option<toml_parser::LineIndex<'_>> {
+        self.inner.line_index()
+    }
+
+    /// Point to the parts of [`Error::input`] the error applies to, for rendering
+    #[cfg(feature = "parse")]
+    pub fn diagnostic(&self) -> Option<toml_parser::Diagnostic<'_>> {
+        self.inner.diagnostic()
+    }
 }
 
 impl serde_core::de::Error for Error {
@@ -46,8 +89,85 @@ impl serde_core::de::Error for Error {
     {
         Self::custom(msg, None)
     }
+
+    fn invalid_type(
+        unexp: serde_core::de::Unexpected<'_>,
+        exp: &dyn serde_core::de::Expected,
+    ) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::invalid_type(unexp, exp);
+        Self::custom(message, None).with_kind(crate::ErrorKind::InvalidType {
+            expected: exp.to_string(),
+            found: unexp.to_string(),
+        })
+    }
+
+    fn invalid_value(
+        unexp: serde_core::de::Unexpected<'_>,
+        exp: &dyn serde_core::de::Expected,
+    ) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::invalid_value(unexp, exp);
+        Self::custom(message, None).with_kind(crate::ErrorKind::InvalidValue {
+            expected: exp.to_string(),
+            found: unexp.to_string(),
+        })
+    }
+
+    fn invalid_length(len: usize, exp: &dyn serde_core::de::Expected) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::invalid_length(len, exp);
+        Self::custom(message, None).with_kind(crate::ErrorKind::InvalidLength {
+            len,
+            expected: exp.to_string(),
+        })
+    }
+
+    fn unknown_variant(variant: &str, expected: &'static [&'static str]) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::unknown_variant(variant, expected);
+        Self::custom(message, None).with_kind(crate::ErrorKind::UnknownVariant {
+            variant: variant.to_owned(),
+            expected,
+        })
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::unknown_field(field, expected);
+        Self::custom(message, None).with_kind(crate::ErrorKind::UnknownField {
+            field: field.to_owned(),
+            expected,
+        })
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::missing_field(field);
+        Self::custom(message, None).with_kind(crate::ErrorKind::MissingField { field })
+    }
+
+    fn duplicate_field(field: &'static str) -> Self {
+        let message = <DefaultMessage as serde_core::de::Error>::duplicate_field(field);
+        Self::custom(message, None).with_kind(crate::ErrorKind::DuplicateField { field })
+    }
 }
 
+/// Renders the messages of the provided methods of [`serde_core::de::Error`]
+#[derive(Debug)]
+struct DefaultMessage(String);
+
+impl serde_core::de::Error for DefaultMessage {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self(msg.to_string())
+    }
+}
+
+impl std::fmt::Display for DefaultMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for DefaultMessage {}
+
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.inner.fmt(f)
@@ -62,7 +182,10 @@ impl std::fmt::Debug for Error {
 
 impl From<crate::TomlError> for Error {
     fn from(e: crate::TomlError) -> Self {
-        Self { inner: e }
+        Self {
+            inner: e,
+            source_id: None,
+        }
     }
 }
 
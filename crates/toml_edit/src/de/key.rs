@@ -4,12 +4,21 @@ use super::Error;
 
 pub(crate) struct KeyDeserializer {
     span: Option<std::ops::Range<usize>>,
+    source_id: Option<usize>,
     key: crate::Key,
 }
 
 impl KeyDeserializer {
-    pub(crate) fn new(key: crate::Key, span: Option<std::ops::Range<usize>>) -> Self {
-        Self { span, key }
+    pub(crate) fn new(
+        key: crate::Key,
+        span: Option<std::ops::Range<usize>>,
+        source_id: Option<usize>,
+    ) -> Self {
+        Self {
+            span,
+            source_id,
+            key,
+        }
     }
 }
 
@@ -192,7 +201,8 @@ impl<'de> serde_core::de::Deserializer<'de> for KeyDeserializer {
                     serde_spanned::de::SpannedDeserializer::<&str, Error>::new(
                         self.key.get(),
                         span,
-                    ),
+                    )
+                    .with_source_id(self.source_id),
                 );
             } else {
                 return Err(Error::custom("value is missing a span", None));
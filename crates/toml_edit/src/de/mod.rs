@@ -5,6 +5,7 @@
 use serde_core::de::DeserializeOwned;
 
 mod array;
+mod borrowed;
 mod error;
 mod key;
 mod table;
@@ -17,6 +18,8 @@ use table::TableDeserializer;
 use table_enum::TableEnumDeserializer;
 use toml_datetime::de::DatetimeDeserializer;
 
+pub use borrowed::BorrowedDeserializer;
+pub use borrowed::BorrowedValueDeserializer;
 pub use error::Error;
 pub use value::ValueDeserializer;
 
@@ -67,14 +70,16 @@ where
 /// This function will attempt to interpret `s` as a TOML document and
 /// deserialize `T` from the document.
 ///
+/// UTF-16 is transcoded when a byte order mark says so, see [`toml_parser::decode_bytes`].
+///
 /// To deserializes TOML values, instead of documents, see [`ValueDeserializer`].
 #[cfg(feature = "parse")]
 pub fn from_slice<T>(s: &'_ [u8]) -> Result<T, Error>
 where
     T: DeserializeOwned,
 {
-    let s = std::str::from_utf8(s).map_err(|e| Error::custom(e, None))?;
-    from_str(s)
+    let s = toml_parser::decode_bytes(s).map_err(|e| crate::TomlError::from_bytes(s, e))?;
+    from_str(&s)
 }
 
 /// Convert a [`DocumentMut`][crate::DocumentMut] into `T`.
@@ -86,10 +91,22 @@ where
     T::deserialize(deserializer)
 }
 
+/// Convert a borrowed [`DocumentMut`][crate::DocumentMut] into `T`, borrowing strings and keys.
+///
+/// See [`BorrowedDeserializer`].
+pub fn from_document_ref<'de, T>(d: impl Into<BorrowedDeserializer<'de>>) -> Result<T, Error>
+where
+    T: serde_core::de::Deserialize<'de>,
+{
+    let deserializer = d.into();
+    T::deserialize(deserializer)
+}
+
 /// Deserialization for TOML [documents][crate::DocumentMut].
 pub struct Deserializer<S = String> {
     root: crate::Item,
     raw: Option<S>,
+    source_id: Option<usize>,
 }
 
 #[cfg(feature = "parse")]
@@ -102,10 +119,53 @@ impl<S: AsRef<str>> Deserializer<S> {
     }
 }
 
+impl<S> Deserializer<S> {
+    /// Identify the input, e.g. when deserializing several files
+    ///
+    /// This is reported by [`Spanned::source_id`][serde_spanned::Spanned::source_id] and
+    /// [`Error::source_id`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "parse")] {
+    /// use serde::Deserialize as _;
+    /// use serde_spanned::Spanned;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     port: Spanned<u16>,
+    /// }
+    ///
+    /// let files = ["port = 80", "port = 8080"];
+    /// let configs = files
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(id, input)| {
+    ///         let deserializer = toml_edit::de::Deserializer::parse(*input)?.with_source_id(id);
+    ///         Config::deserialize(deserializer)
+    ///     })
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// let port = &configs[1].port;
+    /// assert_eq!(port.source_id(), Some(1));
+    /// assert_eq!(&files[port.source_id().unwrap()][port.span()], "8080");
+    /// # }
+    /// ```
+    pub fn with_source_id(mut self, source_id: usize) -> Self {
+        self.source_id = Some(source_id);
+        self
+    }
+}
+
 impl From<crate::DocumentMut> for Deserializer {
     fn from(doc: crate::DocumentMut) -> Self {
         let crate::DocumentMut { root, .. } = doc;
-        Self { root, raw: None }
+        Self {
+            root,
+            raw: None,
+            source_id: None,
+        }
     }
 }
 
@@ -113,7 +173,11 @@ impl<S> From<crate::Document<S>> for Deserializer<S> {
     fn from(doc: crate::Document<S>) -> Self {
         let crate::Document { root, raw, .. } = doc;
         let raw = Some(raw);
-        Self { root, raw }
+        Self {
+            root,
+            raw,
+            source_id: None,
+        }
     }
 }
 
@@ -136,11 +200,15 @@ impl<'de, S: AsRef<str>> serde_core::Deserializer<'de> for Deserializer<S> {
         V: serde_core::de::Visitor<'de>,
     {
         let raw = self.raw;
+        let source_id = self.source_id;
         ValueDeserializer::new(self.root)
+            .with_source_id(source_id)
+            .with_raw(raw.as_ref().map(|r| r.as_ref().into()))
             .deserialize_any(visitor)
             .map_err(|mut e: Self::Error| {
                 let raw = raw.as_ref().map(|r| r.as_ref());
                 e.set_input(raw);
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -152,11 +220,15 @@ impl<'de, S: AsRef<str>> serde_core::Deserializer<'de> for Deserializer<S> {
         V: serde_core::de::Visitor<'de>,
     {
         let raw = self.raw;
+        let source_id = self.source_id;
         ValueDeserializer::new(self.root)
+            .with_source_id(source_id)
+            .with_raw(raw.as_ref().map(|r| r.as_ref().into()))
             .deserialize_option(visitor)
             .map_err(|mut e: Self::Error| {
                 let raw = raw.as_ref().map(|r| r.as_ref());
                 e.set_input(raw);
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -170,11 +242,15 @@ impl<'de, S: AsRef<str>> serde_core::Deserializer<'de> for Deserializer<S> {
         V: serde_core::de::Visitor<'de>,
     {
         let raw = self.raw;
+        let source_id = self.source_id;
         ValueDeserializer::new(self.root)
+            .with_source_id(source_id)
+            .with_raw(raw.as_ref().map(|r| r.as_ref().into()))
             .deserialize_newtype_struct(name, visitor)
             .map_err(|mut e: Self::Error| {
                 let raw = raw.as_ref().map(|r| r.as_ref());
                 e.set_input(raw);
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -189,11 +265,15 @@ impl<'de, S: AsRef<str>> serde_core::Deserializer<'de> for Deserializer<S> {
         V: serde_core::de::Visitor<'de>,
     {
         let raw = self.raw;
+        let source_id = self.source_id;
         ValueDeserializer::new(self.root)
+            .with_source_id(source_id)
+            .with_raw(raw.as_ref().map(|r| r.as_ref().into()))
             .deserialize_struct(name, fields, visitor)
             .map_err(|mut e: Self::Error| {
                 let raw = raw.as_ref().map(|r| r.as_ref());
                 e.set_input(raw);
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -209,11 +289,15 @@ impl<'de, S: AsRef<str>> serde_core::Deserializer<'de> for Deserializer<S> {
         V: serde_core::de::Visitor<'de>,
     {
         let raw = self.raw;
+        let source_id = self.source_id;
         ValueDeserializer::new(self.root)
+            .with_source_id(source_id)
+            .with_raw(raw.as_ref().map(|r| r.as_ref().into()))
             .deserialize_enum(name, variants, visitor)
             .map_err(|mut e: Self::Error| {
                 let raw = raw.as_ref().map(|r| r.as_ref());
                 e.set_input(raw);
+                e.set_source_id(source_id);
                 e
             })
     }
@@ -1,9 +1,13 @@
+use std::sync::Arc;
+
 use serde_core::de::IntoDeserializer;
 
 use crate::de::Error;
 
 pub(crate) struct TableDeserializer {
     span: Option<std::ops::Range<usize>>,
+    source_id: Option<usize>,
+    raw: Option<Arc<str>>,
     items: crate::table::KeyValuePairs,
 }
 
@@ -11,8 +15,15 @@ impl TableDeserializer {
     pub(crate) fn new(
         items: crate::table::KeyValuePairs,
         span: Option<std::ops::Range<usize>>,
+        source_id: Option<usize>,
+        raw: Option<Arc<str>>,
     ) -> Self {
-        Self { span, items }
+        Self {
+            span,
+            source_id,
+            raw,
+            items,
+        }
     }
 }
 
@@ -57,8 +68,10 @@ impl<'de> serde_core::Deserializer<'de> for TableDeserializer {
     {
         if serde_spanned::de::is_spanned(name) {
             if let Some(span) = self.span.clone() {
+                let source_id = self.source_id;
                 return visitor.visit_map(
-                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span),
+                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span)
+                        .with_source_id(source_id),
                 );
             } else {
                 return Err(Error::custom("value is missing a span", None));
@@ -111,6 +124,8 @@ impl IntoDeserializer<'_, Error> for TableDeserializer {
 pub(crate) struct TableMapAccess {
     iter: indexmap::map::IntoIter<crate::Key, crate::Item>,
     span: Option<std::ops::Range<usize>>,
+    source_id: Option<usize>,
+    raw: Option<Arc<str>>,
     value: Option<(crate::Key, crate::Item)>,
 }
 
@@ -119,6 +134,8 @@ impl TableMapAccess {
         Self {
             iter: input.items.into_iter(),
             span: input.span,
+            source_id: input.source_id,
+            raw: input.raw,
             value: None,
         }
     }
@@ -135,7 +152,11 @@ impl<'de> serde_core::de::MapAccess<'de> for TableMapAccess {
             Some((k, v)) => {
                 let key_span = k.span();
                 let ret = seed
-                    .deserialize(super::KeyDeserializer::new(k.clone(), key_span.clone()))
+                    .deserialize(super::KeyDeserializer::new(
+                        k.clone(),
+                        key_span.clone(),
+                        self.source_id,
+                    ))
                     .map(Some)
                     .map_err(|mut e: Self::Error| {
                         if e.span().is_none() {
@@ -157,14 +178,19 @@ impl<'de> serde_core::de::MapAccess<'de> for TableMapAccess {
         match self.value.take() {
             Some((k, v)) => {
                 let span = v.span().or_else(|| k.span());
-                seed.deserialize(crate::de::ValueDeserializer::new(v))
-                    .map_err(|mut e: Self::Error| {
-                        if e.span().is_none() {
-                            e.set_span(span);
-                        }
-                        e.add_key(k.get().to_owned());
-                        e
-                    })
+                seed.deserialize(
+                    crate::de::ValueDeserializer::new(v)
+                        .with_key(k.clone())
+                        .with_source_id(self.source_id)
+                        .with_raw(self.raw.clone()),
+                )
+                .map_err(|mut e: Self::Error| {
+                    if e.span().is_none() {
+                        e.set_span(span);
+                    }
+                    e.add_key(k.get().to_owned());
+                    e
+                })
             }
             None => {
                 panic!("no more values in next_value_seed, internal error in ValueDeserializer")
@@ -200,7 +226,7 @@ impl<'de> serde_core::de::EnumAccess<'de> for TableMapAccess {
                 e
             })?;
 
-        let variant = super::TableEnumDeserializer::new(value);
+        let variant = super::TableEnumDeserializer::new(value, self.source_id, self.raw);
 
         Ok((val, variant))
     }
@@ -1,13 +1,21 @@
+use std::sync::Arc;
+
 use crate::de::Error;
 
 /// Deserializes table values into enum variants.
 pub(crate) struct TableEnumDeserializer {
     value: crate::Item,
+    source_id: Option<usize>,
+    raw: Option<Arc<str>>,
 }
 
 impl TableEnumDeserializer {
-    pub(crate) fn new(value: crate::Item) -> Self {
-        Self { value }
+    pub(crate) fn new(value: crate::Item, source_id: Option<usize>, raw: Option<Arc<str>>) -> Self {
+        Self {
+            value,
+            source_id,
+            raw,
+        }
     }
 }
 
@@ -55,7 +63,11 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer {
     where
         T: serde_core::de::DeserializeSeed<'de>,
     {
-        seed.deserialize(super::ValueDeserializer::new(self.value))
+        seed.deserialize(
+            super::ValueDeserializer::new(self.value)
+                .with_source_id(self.source_id)
+                .with_raw(self.raw),
+        )
     }
 
     fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
@@ -69,7 +81,12 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer {
 
                 if tuple_values.len() == len {
                     serde_core::de::Deserializer::deserialize_seq(
-                        super::ArrayDeserializer::new(tuple_values, values_span),
+                        super::ArrayDeserializer::new(
+                            tuple_values,
+                            values_span,
+                            self.source_id,
+                            self.raw,
+                        ),
                         visitor,
                     )
                 } else {
@@ -85,7 +102,12 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer {
 
                 if tuple_values.len() == len {
                     serde_core::de::Deserializer::deserialize_seq(
-                        super::ArrayDeserializer::new(tuple_values, values_span),
+                        super::ArrayDeserializer::new(
+                            tuple_values,
+                            values_span,
+                            self.source_id,
+                            self.raw,
+                        ),
                         visitor,
                     )
                 } else {
@@ -113,7 +135,12 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer {
 
                 if tuple_values.len() == len {
                     serde_core::de::Deserializer::deserialize_seq(
-                        super::ArrayDeserializer::new(tuple_values, values_span),
+                        super::ArrayDeserializer::new(
+                            tuple_values,
+                            values_span,
+                            self.source_id,
+                            self.raw,
+                        ),
                         visitor,
                     )
                 } else {
@@ -141,7 +168,12 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer {
 
                 if tuple_values.len() == len {
                     serde_core::de::Deserializer::deserialize_seq(
-                        super::ArrayDeserializer::new(tuple_values, values_span),
+                        super::ArrayDeserializer::new(
+                            tuple_values,
+                            values_span,
+                            self.source_id,
+                            self.raw,
+                        ),
                         visitor,
                     )
                 } else {
@@ -167,7 +199,10 @@ impl<'de> serde_core::de::VariantAccess<'de> for TableEnumDeserializer {
         V: serde_core::de::Visitor<'de>,
     {
         serde_core::de::Deserializer::deserialize_struct(
-            super::ValueDeserializer::new(self.value).with_struct_key_validation(),
+            super::ValueDeserializer::new(self.value)
+                .with_source_id(self.source_id)
+                .with_raw(self.raw)
+                .with_struct_key_validation(),
             "", // TODO: this should be the variant name
             fields,
             visitor,
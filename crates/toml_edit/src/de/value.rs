@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use serde_core::de::IntoDeserializer as _;
 
 use crate::de::ArrayDeserializer;
@@ -39,6 +41,9 @@ use crate::de::TableDeserializer;
 /// ```
 pub struct ValueDeserializer {
     input: crate::Item,
+    source_id: Option<usize>,
+    raw: Option<Arc<str>>,
+    key: Option<crate::Key>,
     validate_struct_keys: bool,
 }
 
@@ -46,10 +51,30 @@ impl ValueDeserializer {
     pub(crate) fn new(input: crate::Item) -> Self {
         Self {
             input,
+            source_id: None,
+            raw: None,
+            key: None,
             validate_struct_keys: false,
         }
     }
 
+    pub(crate) fn with_source_id(mut self, source_id: Option<usize>) -> Self {
+        self.source_id = source_id;
+        self
+    }
+
+    /// The source text of the document, for what the parsed values lose
+    pub(crate) fn with_raw(mut self, raw: Option<Arc<str>>) -> Self {
+        self.raw = raw;
+        self
+    }
+
+    /// The key of the table entry this value is for, see [`serde_spanned::SpannedEntry`]
+    pub(crate) fn with_key(mut self, key: crate::Key) -> Self {
+        self.key = Some(key);
+        self
+    }
+
     pub(crate) fn with_struct_key_validation(mut self) -> Self {
         self.validate_struct_keys = true;
         self
@@ -71,19 +96,23 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer {
             crate::Item::Value(crate::Value::Float(v)) => visitor.visit_f64(v.into_value()),
             crate::Item::Value(crate::Value::Boolean(v)) => visitor.visit_bool(v.into_value()),
             crate::Item::Value(crate::Value::Datetime(v)) => {
-                visitor.visit_map(DatetimeDeserializer::new(v.into_value()))
+                visitor.visit_map(datetime_deserializer(&v, self.raw.as_deref()))
             }
             crate::Item::Value(crate::Value::Array(v)) => {
-                ArrayDeserializer::new(v.values, v.span).deserialize_any(visitor)
+                ArrayDeserializer::new(v.values, v.span, self.source_id, self.raw)
+                    .deserialize_any(visitor)
             }
             crate::Item::Value(crate::Value::InlineTable(v)) => {
-                TableDeserializer::new(v.items, v.span).deserialize_any(visitor)
+                TableDeserializer::new(v.items, v.span, self.source_id, self.raw)
+                    .deserialize_any(visitor)
             }
             crate::Item::Table(v) => {
-                TableDeserializer::new(v.items, v.span).deserialize_any(visitor)
+                TableDeserializer::new(v.items, v.span, self.source_id, self.raw)
+                    .deserialize_any(visitor)
             }
             crate::Item::ArrayOfTables(v) => {
-                ArrayDeserializer::new(v.values, v.span).deserialize_any(visitor)
+                ArrayDeserializer::new(v.values, v.span, self.source_id, self.raw)
+                    .deserialize_any(visitor)
             }
         }
         .map_err(|mut e: Self::Error| {
@@ -129,7 +158,7 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer {
     }
 
     fn deserialize_struct<V>(
-        self,
+        mut self,
         name: &'static str,
         fields: &'static [&'static str],
         visitor: V,
@@ -139,19 +168,38 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer {
     {
         if serde_spanned::de::is_spanned(name) {
             if let Some(span) = self.input.span() {
+                let source_id = self.source_id;
                 return visitor.visit_map(
-                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span),
+                    serde_spanned::de::SpannedDeserializer::<Self, Error>::new(self, span)
+                        .with_source_id(source_id),
                 );
             } else {
                 return Err(Error::custom("value is missing a span", None));
             }
         }
 
+        if serde_spanned::de::is_spanned_entry(name) {
+            if let Some(key) = self.key.take() {
+                let key_span = key.span();
+                let key = super::KeyDeserializer::new(key, key_span, self.source_id);
+                return visitor.visit_map(serde_spanned::de::SpannedEntryDeserializer::<
+                    _,
+                    Self,
+                    Error,
+                >::new(key, self));
+            } else {
+                return Err(Error::custom(
+                    "value is not in a table entry",
+                    self.input.span(),
+                ));
+            }
+        }
+
         if toml_datetime::de::is_datetime(name) {
             let span = self.input.span();
             if let crate::Item::Value(crate::Value::Datetime(d)) = self.input {
                 return visitor
-                    .visit_map(DatetimeDeserializer::new(d.into_value()))
+                    .visit_map(datetime_deserializer(&d, self.raw.as_deref()))
                     .map_err(|mut e: Self::Error| {
                         if e.span().is_none() {
                             e.set_span(span);
@@ -208,12 +256,13 @@ impl<'de> serde_core::Deserializer<'de> for ValueDeserializer {
                         v.span(),
                     ))
                 } else {
-                    TableDeserializer::new(v.items, v.span)
+                    TableDeserializer::new(v.items, v.span, self.source_id, self.raw)
                         .deserialize_enum(name, variants, visitor)
                 }
             }
             crate::Item::Table(v) => {
-                TableDeserializer::new(v.items, v.span).deserialize_enum(name, variants, visitor)
+                TableDeserializer::new(v.items, v.span, self.source_id, self.raw)
+                    .deserialize_enum(name, variants, visitor)
             }
             e => Err(Error::custom("wanted string or table", e.span())),
         }
@@ -259,6 +308,25 @@ impl std::str::FromStr for ValueDeserializer {
     }
 }
 
+/// Keep fractional seconds as written in the source text of `datetime`, when it is available
+/// from `raw` or an explicit representation
+///
+/// The parsed [`Datetime`][toml_datetime::Datetime] truncates them to nanoseconds.
+pub(crate) fn datetime_deserializer(
+    datetime: &crate::Formatted<toml_datetime::Datetime>,
+    raw: Option<&str>,
+) -> DatetimeDeserializer<Error> {
+    let lossless = datetime
+        .as_repr()
+        .map(|repr| repr.as_raw().to_str_with_default(raw, ""))
+        .filter(|raw| raw.contains('.'))
+        .and_then(|raw| raw.parse::<toml_datetime::LosslessDatetime>().ok());
+    match lossless {
+        Some(lossless) => DatetimeDeserializer::new_lossless(lossless),
+        None => DatetimeDeserializer::new(*datetime.value()),
+    }
+}
+
 pub(crate) fn validate_struct_keys(
     table: &crate::table::KeyValuePairs,
     fields: &'static [&'static str],
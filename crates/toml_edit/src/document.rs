@@ -10,6 +10,7 @@ pub struct Document<S> {
     // Trailing comments and whitespaces
     pub(crate) trailing: RawString,
     pub(crate) raw: S,
+    pub(crate) bom: bool,
 }
 
 impl Document<&'static str> {
@@ -23,16 +24,44 @@ impl Document<&'static str> {
 impl<S: AsRef<str>> Document<S> {
     /// Parse a TOML document
     pub fn parse(raw: S) -> Result<Self, crate::TomlError> {
-        let source = toml_parser::Source::new(raw.as_ref());
-        let mut sink = crate::error::TomlSink::<Option<_>>::new(source);
-        let doc = crate::parser::parse_document(source, &mut sink);
+        let span = 0..raw.as_ref().len();
+        Self::parse_embedded(raw, span)
+    }
+
+    /// Parse a TOML document embedded at `span` within `host`, like front matter
+    ///
+    /// Spans, including those in errors, are relative to `host` and [`Document::raw`] is `host`.
+    /// For locating embedded TOML, see [`embed`][crate::embed].
+    ///
+    /// # Panics
+    ///
+    /// If `span` is out of bounds or not on `char` boundaries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let host = "+++\ntitle = 'Hello'\n+++\n# Hello\n";
+    /// let doc = toml_edit::Document::parse_embedded(host, 4..20).unwrap();
+    /// assert_eq!(doc["title"].span(), Some(12..19));
+    /// assert_eq!(doc.into_mut().to_string(), "title = 'Hello'\n");
+    /// ```
+    pub fn parse_embedded(host: S, span: std::ops::Range<usize>) -> Result<Self, crate::TomlError> {
+        let source = toml_parser::Source::new(&host.as_ref()[span.clone()]).with_offset(span.start);
+        let mut sink = crate::error::TomlSink::<Option<_>>::new(host.as_ref());
+        let Document {
+            root,
+            trailing,
+            bom,
+            ..
+        } = crate::parser::parse_document(source, &mut sink);
         if let Some(err) = sink.into_inner() {
             Err(err)
         } else {
             Ok(Self {
-                root: doc.root,
-                trailing: doc.trailing,
-                raw,
+                root,
+                trailing,
+                raw: host,
+                bom,
             })
         }
     }
@@ -80,6 +109,11 @@ impl<S> Document<S> {
     pub fn trailing(&self) -> &RawString {
         &self.trailing
     }
+
+    /// Whether the document started with a byte order mark
+    pub fn has_bom(&self) -> bool {
+        self.bom
+    }
 }
 
 impl<S: AsRef<str>> Document<S> {
@@ -96,6 +130,7 @@ impl<S: AsRef<str>> Document<S> {
         DocumentMut {
             root: self.root,
             trailing: self.trailing,
+            bom: self.bom,
         }
     }
 }
@@ -106,6 +141,7 @@ impl Default for Document<&'static str> {
             root: Item::Table(Table::with_pos(Some(0))),
             trailing: Default::default(),
             raw: "",
+            bom: false,
         }
     }
 }
@@ -134,6 +170,7 @@ pub struct DocumentMut {
     pub(crate) root: Item,
     // Trailing comments and whitespaces
     pub(crate) trailing: RawString,
+    pub(crate) bom: bool,
 }
 
 impl DocumentMut {
@@ -190,6 +227,18 @@ impl DocumentMut {
     pub fn trailing(&self) -> &RawString {
         &self.trailing
     }
+
+    /// Set whether to start the document with a byte order mark
+    ///
+    /// This is preserved from the parsed document.
+    pub fn set_bom(&mut self, yes: bool) {
+        self.bom = yes;
+    }
+
+    /// Whether the document starts with a byte order mark
+    pub fn has_bom(&self) -> bool {
+        self.bom
+    }
 }
 
 impl Default for DocumentMut {
@@ -197,6 +246,7 @@ impl Default for DocumentMut {
         Self {
             root: Item::Table(Table::with_pos(Some(0))),
             trailing: Default::default(),
+            bom: false,
         }
     }
 }
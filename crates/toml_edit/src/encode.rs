@@ -204,6 +204,9 @@ pub(crate) fn encode_value(
 
 impl Display for DocumentMut {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.has_bom() {
+            write!(f, "\u{FEFF}")?;
+        }
         let decor = self.decor();
         decor.prefix_encode(f, None, DEFAULT_ROOT_DECOR.0)?;
 
@@ -447,4 +450,62 @@ key:
             });
         }
     }
+
+    fn escapes(v1_1: bool, ascii_only: bool) -> toml_writer::TomlEscapes {
+        let version = if v1_1 {
+            toml_writer::TomlVersion::V1_1
+        } else {
+            toml_writer::TomlVersion::V1_0
+        };
+        toml_writer::TomlEscapes::new()
+            .with_version(version)
+            .with_ascii_only(ascii_only)
+    }
+
+    proptest! {
+        #[test]
+        #[cfg(feature = "parse")]
+        fn escaped_string_round_trip(string in any::<String>(), v1_1: bool, ascii_only: bool) {
+            let mut formatted = Formatted::new(string.clone());
+            formatted.fmt_with(escapes(v1_1, ascii_only));
+            let value = Value::String(formatted);
+            let encoded = value.to_string();
+            if ascii_only {
+                prop_assert!(encoded.is_ascii(), "{encoded}");
+            }
+            let decoded: Value = encoded.parse().unwrap_or_else(|err| {
+                panic!("error: {err}
+
+value:
+```
+{value}
+```
+")
+            });
+            prop_assert_eq!(decoded.as_str(), Some(string.as_str()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        #[cfg(feature = "parse")]
+        fn escaped_key_round_trip(string in any::<String>(), v1_1: bool, ascii_only: bool) {
+            let mut key = Key::new(string.clone());
+            key.fmt_with(escapes(v1_1, ascii_only));
+            let encoded = key.to_string();
+            if ascii_only {
+                prop_assert!(encoded.is_ascii(), "{encoded}");
+            }
+            let decoded: Key = encoded.parse().unwrap_or_else(|err| {
+                panic!("error: {err}
+
+key:
+```
+{key}
+```
+")
+            });
+            prop_assert_eq!(decoded.get(), string.as_str());
+        }
+    }
 }
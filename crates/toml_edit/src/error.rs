@@ -2,9 +2,12 @@
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct TomlError {
     message: String,
+    kind: ErrorKind,
     input: Option<std::sync::Arc<str>>,
     keys: Vec<String>,
     span: Option<std::ops::Range<usize>>,
+    #[cfg(feature = "parse")]
+    parse: Option<toml_parser::ParseError>,
 }
 
 impl TomlError {
@@ -33,22 +36,41 @@ impl TomlError {
         }
 
         let span = error.unexpected().map(|span| span.start()..span.end());
+        let kind = parse_error_kind(&input, &error);
 
         Self {
             message,
+            kind,
             input: Some(input),
             keys: Vec::new(),
             span,
+            parse: Some(error),
+        }
+    }
+
+    /// Report a failure from [`toml_parser::decode_bytes`]
+    #[cfg(feature = "parse")]
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_bytes(input: &[u8], error: toml_parser::ParseError) -> Self {
+        let is_utf16 = input.starts_with(b"\xFF\xFE") || input.starts_with(b"\xFE\xFF");
+        let mut error = Self::new(String::from_utf8_lossy(input).into(), error);
+        if is_utf16 {
+            // Byte offsets do not line up with any text to render
+            error.input = None;
         }
+        error
     }
 
     #[cfg(feature = "serde")]
     pub(crate) fn custom(message: String, span: Option<std::ops::Range<usize>>) -> Self {
         Self {
             message,
+            kind: ErrorKind::Custom,
             input: None,
             keys: Vec::new(),
             span,
+            #[cfg(feature = "parse")]
+            parse: None,
         }
     }
 
@@ -57,16 +79,106 @@ impl TomlError {
         self.keys.insert(0, key);
     }
 
+    #[cfg(feature = "serde")]
+    pub(crate) fn with_kind(mut self, kind: ErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
     /// What went wrong
     pub fn message(&self) -> &str {
         &self.message
     }
 
+    /// What went wrong, for handling the error without matching on [`TomlError::message`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "parse")] {
+    /// use toml_edit::ErrorKind;
+    ///
+    /// let err = "[a]\n[a]".parse::<toml_edit::DocumentMut>().unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     ErrorKind::TableRedefinition {
+    ///         key: "a".to_owned(),
+    ///         original_span: Some(1..2),
+    ///     }
+    /// );
+    /// # }
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        self.kind.clone()
+    }
+
     /// The start/end index into the original document where the error occurred
     pub fn span(&self) -> Option<std::ops::Range<usize>> {
         self.span.clone()
     }
 
+    /// The encoded TOML the error applies to, if known
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+
+    /// Index [`TomlError::input`] for converting [`TomlError::span`] to line / column positions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let err = "a = 1\nb = ".parse::<toml_edit::DocumentMut>().unwrap_err();
+    /// let index = err.line_index().unwrap();
+    /// let position = index.line_col(err.span().unwrap().start).unwrap();
+    /// assert_eq!(position, toml_edit::LineCol::new(1, 4));
+    /// ```
+    #[cfg(feature = "parse")]
+    pub fn line_index(&self) -> Option<toml_parser::LineIndex<'_>> {
+        self.input().map(toml_parser::LineIndex::new)
+    }
+
+    /// Point to the parts of [`TomlError::input`] the error applies to, for rendering
+    ///
+    /// This is what [`TomlError`] displays when the [`TomlError::input`] and [`TomlError::span`]
+    /// are known.
+    /// Related locations, like where a table was originally defined, are secondary labels.
+    /// The keys of the value the error is for are a note and a suggested fix is help.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let err = "[a]\n[a]".parse::<toml_edit::DocumentMut>().unwrap_err();
+    /// let diagnostic = err.diagnostic().unwrap();
+    /// assert_eq!(
+    ///     diagnostic.to_string(),
+    ///     "\
+    /// TOML parse error at line 2, column 2
+    ///   |
+    /// 1 | [a]
+    ///   |  - originally defined here
+    /// 2 | [a]
+    ///   |  ^
+    /// duplicate key
+    /// "
+    /// );
+    /// ```
+    #[cfg(feature = "parse")]
+    pub fn diagnostic(&self) -> Option<toml_parser::Diagnostic<'_>> {
+        let input = self.input()?;
+        let span = self.span()?;
+        let span = toml_parser::Span::new_unchecked(span.start, span.end);
+
+        let mut diagnostic = toml_parser::Diagnostic::new(input, self.message.clone())
+            .with_label(toml_parser::Label::primary(span));
+        if let Some(error) = &self.parse {
+            diagnostic = diagnostic.with_parse_error(error);
+        }
+        if !self.keys.is_empty() {
+            diagnostic = diagnostic.with_note(format!("in `{}`", self.keys.join(".")));
+        }
+        Some(diagnostic)
+    }
+
     #[cfg(feature = "serde")]
     pub(crate) fn set_span(&mut self, span: Option<std::ops::Range<usize>>) {
         self.span = span;
@@ -78,6 +190,25 @@ impl TomlError {
     }
 }
 
+#[cfg(feature = "parse")]
+fn parse_error_kind(input: &str, error: &toml_parser::ParseError) -> ErrorKind {
+    let span = |span: toml_parser::Span| span.start()..span.end();
+    let key = || error.key(input).unwrap_or_default();
+    match error.kind() {
+        toml_parser::ParseErrorKind::InvalidEscape => ErrorKind::InvalidEscape,
+        toml_parser::ParseErrorKind::RecursionLimit => ErrorKind::RecursionLimit,
+        toml_parser::ParseErrorKind::DuplicateKey => ErrorKind::DuplicateKey {
+            key: key(),
+            first_span: error.context().map(span),
+        },
+        toml_parser::ParseErrorKind::TableRedefinition => ErrorKind::TableRedefinition {
+            key: key(),
+            original_span: error.context().map(span),
+        },
+        _ => ErrorKind::Syntax,
+    }
+}
+
 fn render_literal(literal: &str) -> String {
     match literal {
         "\n" => "newline".to_owned(),
@@ -103,48 +234,12 @@ fn render_literal(literal: &str) -> String {
 /// While parsing a Date-Time
 impl std::fmt::Display for TomlError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut context = false;
-        if let (Some(input), Some(span)) = (&self.input, self.span()) {
-            context = true;
-
-            let (line, column) = translate_position(input.as_bytes(), span.start);
-            let line_num = line + 1;
-            let col_num = column + 1;
-            let gutter = line_num.to_string().len();
-            let content = input.split('\n').nth(line).expect("valid line number");
-            let highlight_len = span.end - span.start;
-            // Allow highlight to go one past the line
-            let highlight_len = highlight_len.min(content.len().saturating_sub(column));
-
-            writeln!(f, "TOML parse error at line {line_num}, column {col_num}")?;
-            //   |
-            for _ in 0..=gutter {
-                write!(f, " ")?;
-            }
-            writeln!(f, "|")?;
-
-            // 1 | 00:32:00.a999999
-            write!(f, "{line_num} | ")?;
-            writeln!(f, "{content}")?;
-
-            //   |          ^
-            for _ in 0..=gutter {
-                write!(f, " ")?;
-            }
-            write!(f, "|")?;
-            for _ in 0..=column {
-                write!(f, " ")?;
-            }
-            // The span will be empty at eof, so we need to make sure we always print at least
-            // one `^`
-            write!(f, "^")?;
-            for _ in 1..highlight_len {
-                write!(f, "^")?;
-            }
-            writeln!(f)?;
+        #[cfg(feature = "parse")]
+        if let Some(diagnostic) = self.diagnostic() {
+            return diagnostic.fmt(f);
         }
         writeln!(f, "{}", self.message)?;
-        if !context && !self.keys.is_empty() {
+        if !self.keys.is_empty() {
             writeln!(f, "in `{}`", self.keys.join("."))?;
         }
 
@@ -154,47 +249,105 @@ impl std::fmt::Display for TomlError {
 
 impl std::error::Error for TomlError {}
 
-fn translate_position(input: &[u8], index: usize) -> (usize, usize) {
-    if input.is_empty() {
-        return (0, index);
-    }
-
-    let safe_index = index.min(input.len() - 1);
-    let column_offset = index - safe_index;
-    let index = safe_index;
-
-    let nl = input[0..index]
-        .iter()
-        .rev()
-        .enumerate()
-        .find(|(_, b)| **b == b'\n')
-        .map(|(nl, _)| index - nl - 1);
-    let line_start = match nl {
-        Some(nl) => nl + 1,
-        None => 0,
-    };
-    let line = input[0..line_start].iter().filter(|b| **b == b'\n').count();
-
-    let column = std::str::from_utf8(&input[line_start..=index])
-        .map(|s| s.chars().count() - 1)
-        .unwrap_or_else(|_| index - line_start);
-    let column = column + column_offset;
-
-    (line, column)
+/// What went wrong, for handling [`TomlError`]s, [`de::Error`][crate::de::Error]s, and
+/// [`ser::Error`][crate::ser::Error]s without matching on their messages
+///
+/// Spans are byte ranges into [`TomlError::input`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The TOML is malformed
+    Syntax,
+    /// A string has an invalid escape sequence
+    InvalidEscape,
+    /// Values or keys are nested deeper than allowed
+    RecursionLimit,
+    /// A key is defined more than once
+    DuplicateKey {
+        /// The key being redefined
+        key: String,
+        /// Where the key was first defined, if known
+        first_span: Option<std::ops::Range<usize>>,
+    },
+    /// A table is defined more than once, or is extended after being defined as another value
+    TableRedefinition {
+        /// The key of the table
+        key: String,
+        /// Where the key was first defined, if known
+        original_span: Option<std::ops::Range<usize>>,
+    },
+    /// A value has a different type than expected
+    InvalidType {
+        /// What was expected
+        expected: String,
+        /// The type that was found
+        found: String,
+    },
+    /// A value has the expected type but not an expected value
+    InvalidValue {
+        /// What was expected
+        expected: String,
+        /// The value that was found
+        found: String,
+    },
+    /// An array or table has the wrong number of elements
+    InvalidLength {
+        /// The number of elements found
+        len: usize,
+        /// What was expected
+        expected: String,
+    },
+    /// An enum variant is not recognized
+    UnknownVariant {
+        /// The variant that was found
+        variant: String,
+        /// The variants that are recognized
+        expected: &'static [&'static str],
+    },
+    /// A struct field is not recognized
+    UnknownField {
+        /// The field that was found
+        field: String,
+        /// The fields that are recognized
+        expected: &'static [&'static str],
+    },
+    /// A struct field is missing
+    MissingField {
+        /// The missing field
+        field: &'static str,
+    },
+    /// A struct field is present more than once
+    DuplicateField {
+        /// The repeated field
+        field: &'static str,
+    },
+    /// A Rust type cannot be represented in TOML
+    UnsupportedType(Option<&'static str>),
+    /// `None` cannot be represented in TOML
+    UnsupportedNone,
+    /// A map key is not a string
+    KeyNotString,
+    /// A value is out of range for TOML
+    OutOfRange(Option<&'static str>),
+    /// A serialized datetime is invalid
+    DateInvalid,
+    /// Any other error, see its message
+    Custom,
 }
 
 #[cfg(feature = "parse")]
 pub(crate) struct TomlSink<'i, S> {
-    source: toml_parser::Source<'i>,
+    host: &'i str,
     input: Option<std::sync::Arc<str>>,
     sink: S,
 }
 
 #[cfg(feature = "parse")]
 impl<'i, S: Default> TomlSink<'i, S> {
-    pub(crate) fn new(source: toml_parser::Source<'i>) -> Self {
+    /// `host` is the document that reported spans are relative to
+    pub(crate) fn new(host: &'i str) -> Self {
         Self {
-            source,
+            host,
             input: None,
             sink: Default::default(),
         }
@@ -211,7 +364,7 @@ impl<'i> toml_parser::ErrorSink for TomlSink<'i, Option<TomlError>> {
         if self.sink.is_none() {
             let input = self
                 .input
-                .get_or_insert_with(|| std::sync::Arc::from(self.source.input()));
+                .get_or_insert_with(|| std::sync::Arc::from(self.host));
             let error = TomlError::new(input.clone(), error);
             self.sink = Some(error);
         }
@@ -223,77 +376,8 @@ impl<'i> toml_parser::ErrorSink for TomlSink<'i, Vec<TomlError>> {
     fn report_error(&mut self, error: toml_parser::ParseError) {
         let input = self
             .input
-            .get_or_insert_with(|| std::sync::Arc::from(self.source.input()));
+            .get_or_insert_with(|| std::sync::Arc::from(self.host));
         let error = TomlError::new(input.clone(), error);
         self.sink.push(error);
     }
 }
-
-#[cfg(test)]
-mod test_translate_position {
-    use super::*;
-
-    #[test]
-    fn empty() {
-        let input = b"";
-        let index = 0;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, 0));
-    }
-
-    #[test]
-    fn start() {
-        let input = b"Hello";
-        let index = 0;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, 0));
-    }
-
-    #[test]
-    fn end() {
-        let input = b"Hello";
-        let index = input.len() - 1;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, input.len() - 1));
-    }
-
-    #[test]
-    fn after() {
-        let input = b"Hello";
-        let index = input.len();
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, input.len()));
-    }
-
-    #[test]
-    fn first_line() {
-        let input = b"Hello\nWorld\n";
-        let index = 2;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, 2));
-    }
-
-    #[test]
-    fn end_of_line() {
-        let input = b"Hello\nWorld\n";
-        let index = 5;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (0, 5));
-    }
-
-    #[test]
-    fn start_of_second_line() {
-        let input = b"Hello\nWorld\n";
-        let index = 6;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (1, 0));
-    }
-
-    #[test]
-    fn second_line() {
-        let input = b"Hello\nWorld\n";
-        let index = 8;
-        let position = translate_position(&input[..], index);
-        assert_eq!(position, (1, 2));
-    }
-}
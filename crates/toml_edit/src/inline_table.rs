@@ -19,6 +19,11 @@ pub struct InlineTable {
     pub(crate) span: Option<std::ops::Range<usize>>,
     // whether this is a proxy for dotted keys
     dotted: bool,
+    // whether to keep this inline when values are made into tables, see `ser::Inline`
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    pub(crate) keep_inline: bool,
+    // comments for entries, or the table itself without a key, see `ser::Commented`
+    pub(crate) comments: Vec<(Option<String>, Decor)>,
     pub(crate) items: KeyValuePairs,
 }
 
@@ -42,6 +47,7 @@ impl InlineTable {
     pub fn into_table(self) -> Table {
         let mut t = Table::with_pairs(self.items);
         t.fmt();
+        t.comments = self.comments;
         t
     }
 }
@@ -510,7 +516,7 @@ impl<'s> IntoIterator for &'s InlineTable {
     }
 }
 
-fn decorate_inline_table(table: &mut InlineTable) {
+pub(crate) fn decorate_inline_table(table: &mut InlineTable) {
     use indexmap::map::MutableKeys;
     for (mut key, value) in table
         .items
@@ -150,10 +150,26 @@ impl Key {
         self.dotted_decor.clear();
     }
 
+    /// Formats the key, escaping it according to `escapes`
+    ///
+    /// ```rust
+    /// let mut key = toml_edit::Key::new("café");
+    /// key.fmt_with(toml_edit::TomlEscapes::new().with_ascii_only(true));
+    /// assert_eq!(key.display_repr(), r#""caf\u00E9""#);
+    /// ```
+    #[cfg(feature = "display")]
+    pub fn fmt_with(&mut self, escapes: toml_writer::TomlEscapes) {
+        let output = toml_writer::TomlKeyBuilder::new(&self.key)
+            .with_escapes(escapes)
+            .as_default()
+            .to_toml_key();
+        self.repr = Some(Repr::new_unchecked(output));
+    }
+
     #[cfg(feature = "parse")]
     fn try_parse_simple(s: &str) -> Result<Self, crate::TomlError> {
         let source = toml_parser::Source::new(s);
-        let mut sink = crate::error::TomlSink::<Option<_>>::new(source);
+        let mut sink = crate::error::TomlSink::<Option<_>>::new(s);
         let mut key = crate::parser::parse_key(source, &mut sink);
         if let Some(err) = sink.into_inner() {
             Err(err)
@@ -166,7 +182,7 @@ impl Key {
     #[cfg(feature = "parse")]
     fn try_parse_path(s: &str) -> Result<Vec<Self>, crate::TomlError> {
         let source = toml_parser::Source::new(s);
-        let mut sink = crate::error::TomlSink::<Option<_>>::new(source);
+        let mut sink = crate::error::TomlSink::<Option<_>>::new(s);
         let mut keys = crate::parser::parse_key_path(source, &mut sink);
         if let Some(err) = sink.into_inner() {
             Err(err)
@@ -351,6 +367,12 @@ impl KeyMut<'_> {
     pub fn fmt(&mut self) {
         self.key.fmt();
     }
+
+    /// Formats the key, escaping it according to `escapes`
+    #[cfg(feature = "display")]
+    pub fn fmt_with(&mut self, escapes: toml_writer::TomlEscapes) {
+        self.key.fmt_with(escapes);
+    }
 }
 
 impl std::ops::Deref for KeyMut<'_> {
@@ -0,0 +1,245 @@
+use indexmap::map::MutableKeys as _;
+use toml_writer::LayoutValue;
+use toml_writer::TomlLayout;
+use toml_writer::TomlVersion;
+
+use crate::array::decorate_array;
+use crate::inline_table::decorate_inline_table;
+use crate::{Array, InlineTable, Item, Key, Table, Value};
+
+/// Formatting
+impl Table {
+    /// Auto formats the table and its sub-tables, fitting arrays and inline tables within
+    /// [`TomlLayout::max_width`]
+    ///
+    /// Arrays that don't fit on one line are broken into one element per line, as are inline
+    /// tables when targeting TOML 1.1.
+    /// See [`TomlLayout`] for details.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")] {
+    /// let mut doc = r#"authors = [ "Alex Crichton",   "Ed Page" ]
+    /// keywords = ["encoding", "toml", "no_std"]
+    /// "#.parse::<toml_edit::DocumentMut>().unwrap();
+    /// doc.fmt_layout(toml_edit::TomlLayout::new().with_max_width(40));
+    /// assert_eq!(doc.to_string(), r#"authors = ["Alex Crichton", "Ed Page"]
+    /// keywords = [
+    ///     "encoding",
+    ///     "toml",
+    ///     "no_std",
+    /// ]
+    /// "#);
+    /// # }
+    /// ```
+    pub fn fmt_layout(&mut self, layout: TomlLayout) {
+        layout_table(self, layout, 0);
+    }
+}
+
+/// Formatting
+impl Array {
+    /// Auto formats the array to fit within [`TomlLayout::max_width`] when starting at `column`
+    ///
+    /// See [`TomlLayout`] for details.
+    pub fn fmt_layout(&mut self, layout: TomlLayout, column: usize) {
+        layout_array(self, layout, 0, column, 0);
+    }
+}
+
+/// Formatting
+impl InlineTable {
+    /// Auto formats the table to fit within [`TomlLayout::max_width`] when starting at `column`
+    ///
+    /// Breaking inline tables across lines requires TOML 1.1, so the table is only broken when
+    /// opted into with [`TomlLayout::with_version`].
+    /// See [`TomlLayout`] for details.
+    pub fn fmt_layout(&mut self, layout: TomlLayout, column: usize) {
+        layout_inline_table(self, layout, 0, column, 0);
+    }
+}
+
+fn layout_table(table: &mut Table, layout: TomlLayout, prefix_width: usize) {
+    for (key, item) in table.items.iter_mut2() {
+        let key_width = prefix_width + key_width(key);
+        match item {
+            Item::None => {}
+            Item::Value(value) => {
+                key.leaf_decor_mut().clear();
+                key.dotted_decor_mut().clear();
+                value.decor_mut().clear();
+                // `key = `
+                layout_value(value, layout, 0, key_width + 3, 0);
+            }
+            Item::Table(table) if table.is_dotted() => {
+                key.dotted_decor_mut().clear();
+                layout_table(table, layout, key_width + 1);
+            }
+            Item::Table(table) => {
+                layout_table(table, layout, 0);
+            }
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter_mut() {
+                    layout_table(table, layout, 0);
+                }
+            }
+        }
+    }
+}
+
+fn layout_value(
+    value: &mut Value,
+    layout: TomlLayout,
+    indent: usize,
+    column: usize,
+    trailing: usize,
+) {
+    match value {
+        Value::Array(array) => layout_array(array, layout, indent, column, trailing),
+        Value::InlineTable(table) => layout_inline_table(table, layout, indent, column, trailing),
+        Value::String(_)
+        | Value::Integer(_)
+        | Value::Float(_)
+        | Value::Boolean(_)
+        | Value::Datetime(_) => {}
+    }
+}
+
+fn layout_array(
+    array: &mut Array,
+    layout: TomlLayout,
+    indent: usize,
+    column: usize,
+    trailing: usize,
+) {
+    if array.is_empty() || layout.fits(&to_layout_array(array), column, trailing) {
+        flatten_array(array);
+        return;
+    }
+
+    let inner_indent = indent + layout.indent();
+    for value in array.iter_mut() {
+        value.decorate(format!("\n{:inner_indent$}", ""), "");
+        layout_value(value, layout, inner_indent, inner_indent, 1);
+    }
+    array.set_trailing_comma(true);
+    array.set_trailing(format!("\n{:indent$}", ""));
+}
+
+fn layout_inline_table(
+    table: &mut InlineTable,
+    layout: TomlLayout,
+    indent: usize,
+    column: usize,
+    trailing: usize,
+) {
+    if table.is_empty()
+        || layout.version() < TomlVersion::V1_1
+        || layout.fits(&to_layout_inline_table(table), column, trailing)
+    {
+        flatten_inline_table(table);
+        return;
+    }
+
+    let inner_indent = indent + layout.indent();
+    for_each_leaf_mut(table, 0, &mut |key, key_width, value| {
+        key.leaf_decor_mut()
+            .set_prefix(format!("\n{:inner_indent$}", ""));
+        key.leaf_decor_mut().set_suffix(" ");
+        value.decorate(" ", "");
+        // `key = `
+        let column = inner_indent + key_width + 3;
+        layout_value(value, layout, inner_indent, column, 1);
+    });
+    table.set_trailing_comma(true);
+    table.set_trailing(format!("\n{:indent$}", ""));
+}
+
+fn flatten_value(value: &mut Value) {
+    match value {
+        Value::Array(array) => flatten_array(array),
+        Value::InlineTable(table) => flatten_inline_table(table),
+        Value::String(_)
+        | Value::Integer(_)
+        | Value::Float(_)
+        | Value::Boolean(_)
+        | Value::Datetime(_) => {}
+    }
+}
+
+fn flatten_array(array: &mut Array) {
+    decorate_array(array);
+    for value in array.iter_mut() {
+        flatten_value(value);
+    }
+}
+
+fn flatten_inline_table(table: &mut InlineTable) {
+    decorate_inline_table(table);
+    table.set_trailing_comma(false);
+    table.set_trailing("");
+    for_each_leaf_mut(table, 0, &mut |key, _, value| {
+        key.leaf_decor_mut().clear();
+        value.decor_mut().clear();
+        flatten_value(value);
+    });
+}
+
+/// Visit the key-value pairs of `table`, including those within dotted keys
+///
+/// The callback is passed the width of the full key path.
+fn for_each_leaf_mut(
+    table: &mut InlineTable,
+    prefix_width: usize,
+    f: &mut dyn FnMut(&mut Key, usize, &mut Value),
+) {
+    for (key, item) in table.items.iter_mut2() {
+        let Some(value) = item.as_value_mut() else {
+            continue;
+        };
+        let key_width = prefix_width + key_width(key);
+        match value {
+            Value::InlineTable(table) if table.is_dotted() => {
+                key.dotted_decor_mut().clear();
+                for_each_leaf_mut(table, key_width + 1, f);
+            }
+            _ => f(key, key_width, value),
+        }
+    }
+}
+
+fn key_width(key: &Key) -> usize {
+    key.display_repr().chars().count()
+}
+
+fn to_layout(value: &Value) -> LayoutValue {
+    match value {
+        Value::String(f) => LayoutValue::Scalar(f.display_repr().into_owned()),
+        Value::Integer(f) => LayoutValue::Scalar(f.display_repr().into_owned()),
+        Value::Float(f) => LayoutValue::Scalar(f.display_repr().into_owned()),
+        Value::Boolean(f) => LayoutValue::Scalar(f.display_repr().into_owned()),
+        Value::Datetime(f) => LayoutValue::Scalar(f.display_repr().into_owned()),
+        Value::Array(array) => to_layout_array(array),
+        Value::InlineTable(table) => to_layout_inline_table(table),
+    }
+}
+
+fn to_layout_array(array: &Array) -> LayoutValue {
+    LayoutValue::Array(array.iter().map(to_layout).collect())
+}
+
+fn to_layout_inline_table(table: &InlineTable) -> LayoutValue {
+    LayoutValue::InlineTable(
+        table
+            .get_values()
+            .into_iter()
+            .map(|(path, value)| {
+                let key = path
+                    .iter()
+                    .map(|key| key.display_repr())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                (key, to_layout(value))
+            })
+            .collect(),
+    )
+}
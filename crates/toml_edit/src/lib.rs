@@ -87,6 +87,8 @@ mod index;
 mod inline_table;
 mod item;
 mod key;
+#[cfg(feature = "display")]
+mod layout;
 #[cfg(feature = "parse")]
 mod parser;
 mod raw_string;
@@ -111,6 +113,7 @@ pub use crate::document::DocumentMut;
 #[deprecated(since = "0.23.0", note = "Replaced with `Document`")]
 pub type ImDocument<S> = Document<S>;
 pub use crate::document::Document;
+pub use crate::error::ErrorKind;
 pub use crate::error::TomlError;
 pub use crate::inline_table::{
     InlineEntry, InlineOccupiedEntry, InlineTable, InlineTableIntoIter, InlineTableIter,
@@ -125,6 +128,18 @@ pub use crate::table::{
 };
 pub use crate::value::Value;
 pub use toml_datetime::*;
+#[cfg(feature = "parse")]
+pub use toml_parser::{Diagnostic, Label, LineCol, LineIndex, embed};
+#[cfg(feature = "display")]
+pub use toml_writer::TomlEscapes;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlFloatFormat;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlIntegerFormat;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlLayout;
+#[cfg(feature = "display")]
+pub use toml_writer::TomlVersion;
 
 // Prevent users from some traits.
 pub(crate) mod private {
@@ -123,6 +123,7 @@ pub(crate) fn document<'s>(
         root: Item::Table(state.root),
         trailing,
         raw: source.input(),
+        bom: source.input().starts_with(BOM),
     }
 }
 
@@ -320,7 +321,11 @@ impl State {
                 anstyle::AnsiColor::Red.on_default(),
             );
             let key_span = get_key_span(&key).expect("all keys have spans");
-            errors.report_error(ParseError::new("duplicate key").with_unexpected(key_span));
+            errors.report_error(
+                ParseError::new("duplicate key")
+                    .with_unexpected(key_span)
+                    .with_kind(ParseErrorKind::TableRedefinition),
+            );
             return;
         }
         let key_span = get_key_span(&key).expect("all keys have spans");
@@ -335,7 +340,8 @@ impl State {
                 errors.report_error(
                     ParseError::new("duplicate key")
                         .with_unexpected(key_span)
-                        .with_context(old_span),
+                        .with_context(old_span)
+                        .with_kind(ParseErrorKind::DuplicateKey),
                 );
             }
         }
@@ -378,7 +384,8 @@ impl State {
                     errors.report_error(
                         ParseError::new("duplicate key")
                             .with_unexpected(key_span)
-                            .with_context(old_span),
+                            .with_context(old_span)
+                            .with_kind(ParseErrorKind::TableRedefinition),
                     );
                     return;
                 };
@@ -440,7 +447,8 @@ impl State {
                             errors.report_error(
                                 ParseError::new("duplicate key")
                                     .with_unexpected(key_span)
-                                    .with_context(old_span),
+                                    .with_context(old_span)
+                                    .with_kind(ParseErrorKind::TableRedefinition),
                             );
 
                             if let Item::Table(t) = old_value {
@@ -537,7 +545,9 @@ fn descend_path<'t>(
                             );
                             let key_span = get_key_span(key).expect("all keys have spans");
                             errors.report_error(
-                                ParseError::new("duplicate key").with_unexpected(key_span),
+                                ParseError::new("duplicate key")
+                                    .with_unexpected(key_span)
+                                    .with_kind(ParseErrorKind::TableRedefinition),
                             );
                             return None;
                         }
@@ -554,7 +564,8 @@ fn descend_path<'t>(
                                 existing.type_name()
                             ))
                             .with_unexpected(key_span)
-                            .with_context(old_span),
+                            .with_context(old_span)
+                            .with_kind(ParseErrorKind::TableRedefinition),
                         );
                         return None;
                     }
@@ -571,3 +582,5 @@ fn get_key_span(key: &Key) -> Option<toml_parser::Span> {
         .and_then(|r| r.span())
         .map(|s| toml_parser::Span::new_unchecked(s.start, s.end))
 }
+
+const BOM: &str = "\u{FEFF}";
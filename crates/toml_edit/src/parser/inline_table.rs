@@ -210,7 +210,11 @@ impl State {
                     anstyle::AnsiColor::Red.on_default(),
                 );
                 let key_span = get_key_span(&key).unwrap_or_else(|| event.span());
-                errors.report_error(ParseError::new("duplicate key").with_unexpected(key_span));
+                errors.report_error(
+                    ParseError::new("duplicate key")
+                        .with_unexpected(key_span)
+                        .with_kind(ParseErrorKind::TableRedefinition),
+                );
             } else {
                 let key_span = get_key_span(&key).unwrap_or_else(|| event.span());
                 match table.items.entry(key) {
@@ -222,7 +226,8 @@ impl State {
                         errors.report_error(
                             ParseError::new("duplicate key")
                                 .with_unexpected(key_span)
-                                .with_context(old_span),
+                                .with_context(old_span)
+                                .with_kind(ParseErrorKind::DuplicateKey),
                         );
                     }
                 }
@@ -311,7 +316,9 @@ fn descend_path<'a>(
                             );
                             let key_span = get_key_span(key).expect("all keys have spans");
                             errors.report_error(
-                                ParseError::new("duplicate key").with_unexpected(key_span),
+                                ParseError::new("duplicate key")
+                                    .with_unexpected(key_span)
+                                    .with_kind(ParseErrorKind::TableRedefinition),
                             );
                             return None;
                         }
@@ -324,7 +331,8 @@ fn descend_path<'a>(
                                 "cannot extend value of type {} with a dotted key",
                                 existing.type_name()
                             ))
-                            .with_unexpected(key_span),
+                            .with_unexpected(key_span)
+                            .with_kind(ParseErrorKind::TableRedefinition),
                         );
                         return None;
                     }
@@ -89,9 +89,11 @@ pub(crate) fn on_scalar(
             let value = match decoded.parse::<toml_datetime::Datetime>() {
                 Ok(value) => value,
                 Err(err) => {
-                    errors.report_error(
-                        ParseError::new(err.to_string()).with_unexpected(event.span()),
-                    );
+                    let mut error = ParseError::new(err.to_string()).with_unexpected(event.span());
+                    if let Some(fix) = raw.datetime_padding_fix() {
+                        error = error.with_fix(fix);
+                    }
+                    errors.report_error(error);
                     toml_datetime::Datetime {
                         date: None,
                         time: None,
@@ -91,6 +91,67 @@ where
     }
 }
 
+#[cfg(feature = "display")]
+impl Formatted<String> {
+    /// Formats the value, escaping it according to `escapes`
+    ///
+    /// ```rust
+    /// let mut value = toml_edit::Formatted::new(String::from("\u{1b}[1mbold\u{1b}[0m"));
+    /// value.fmt_with(toml_edit::TomlEscapes::new().with_version(toml_edit::TomlVersion::V1_1));
+    /// assert_eq!(value.display_repr(), r#""\e[1mbold\e[0m""#);
+    /// ```
+    pub fn fmt_with(&mut self, escapes: toml_writer::TomlEscapes) {
+        use toml_writer::ToTomlValue as _;
+
+        let repr = toml_writer::TomlStringBuilder::new(&self.value)
+            .with_escapes(escapes)
+            .as_default()
+            .to_toml_value();
+        self.repr = Some(Repr::new_unchecked(repr));
+    }
+}
+
+#[cfg(feature = "display")]
+impl Formatted<i64> {
+    /// Formats the value according to `format`, e.g. to group digits with `_`
+    ///
+    /// Negative values are written in decimal.
+    ///
+    /// ```rust
+    /// let mut value = toml_edit::Formatted::new(10_000_000);
+    /// value.fmt_with(toml_edit::TomlIntegerFormat::new().with_grouping(true));
+    /// assert_eq!(value.display_repr(), "10_000_000");
+    /// ```
+    pub fn fmt_with(&mut self, format: toml_writer::TomlIntegerFormat) {
+        use toml_writer::ToTomlValue as _;
+
+        let value = self.value;
+        let repr = format
+            .format(value)
+            .or_else(|| format.as_decimal().format(value))
+            .expect("decimal supports all integers")
+            .to_toml_value();
+        self.repr = Some(Repr::new_unchecked(repr));
+    }
+}
+
+#[cfg(feature = "display")]
+impl Formatted<f64> {
+    /// Formats the value according to `format`, e.g. to group digits with `_`
+    ///
+    /// ```rust
+    /// let mut value = toml_edit::Formatted::new(1234567.5);
+    /// value.fmt_with(toml_edit::TomlFloatFormat::new().with_grouping(true));
+    /// assert_eq!(value.display_repr(), "1_234_567.5");
+    /// ```
+    pub fn fmt_with(&mut self, format: toml_writer::TomlFloatFormat) {
+        use toml_writer::ToTomlValue as _;
+
+        let repr = format.format(self.value).to_toml_value();
+        self.repr = Some(Repr::new_unchecked(repr));
+    }
+}
+
 impl<T> std::fmt::Debug for Formatted<T>
 where
     T: std::fmt::Debug,
@@ -0,0 +1,268 @@
+//! Comments written alongside values
+
+use serde_spanned::ser::Comments;
+
+use crate::{Decor, DocumentMut, InlineTable, Item, KeyMut, Table, Value};
+
+/// The comment lines as the prefix and the trailing comment, without leading whitespace, as the
+/// suffix
+pub(crate) fn decor(comments: &Comments) -> Decor {
+    let mut decor = Decor::default();
+    if let Some(comment) = comments.comment() {
+        let mut prefix = String::new();
+        for line in comment.lines() {
+            prefix.push('#');
+            if !line.is_empty() {
+                prefix.push(' ');
+                prefix.push_str(line);
+            }
+            prefix.push('\n');
+        }
+        decor.set_prefix(prefix);
+    }
+    if let Some(comment) = comments.trailing_comment() {
+        if comment.is_empty() {
+            decor.set_suffix("#");
+        } else {
+            decor.set_suffix(format!("# {comment}"));
+        }
+    }
+    decor
+}
+
+/// Write the comments recorded while serializing as the decor of `document`
+///
+/// Comments go in the prefix of keys and table headers, and trailing comments in the suffix of
+/// values and table headers.  Comments within inline tables and arrays are dropped.
+pub(crate) fn decorate_document(document: &mut DocumentMut) {
+    let root = document.as_table_mut();
+    let own = take_own(&mut root.comments);
+    // Without a header, the trailing comment gets its own line
+    let mut prefix = raw(own.prefix()).to_owned();
+    if let Some(suffix) = own.suffix() {
+        prefix.push_str(raw(Some(suffix)));
+        prefix.push('\n');
+    }
+    let has_comment = !prefix.is_empty();
+    if has_comment {
+        root.decor_mut().set_prefix(prefix);
+    }
+    decorate_table(root);
+
+    if !root.get_values().is_empty() {
+        return;
+    }
+    // Like the first table of a document, there is no blank line before the first header, unless
+    // it separates the document's comment
+    let mut path = Vec::new();
+    if !first_table_path(root, &mut path) {
+        return;
+    }
+    let Some(table) = table_at_mut(root, &path) else {
+        return;
+    };
+    let prefix = raw(table.decor().prefix());
+    let prefix = prefix.strip_prefix('\n').unwrap_or(prefix).to_owned();
+    if has_comment {
+        table.decor_mut().set_prefix(format!("\n{prefix}"));
+    } else if table.decor().prefix().is_some() {
+        table.decor_mut().set_prefix(prefix);
+    }
+}
+
+fn decorate_table(table: &mut Table) {
+    for (key, decor) in std::mem::take(&mut table.comments) {
+        // The table's own comments are for its header
+        let Some(key) = key else {
+            continue;
+        };
+        let Some((key, item)) = table.get_key_value_mut(&key) else {
+            continue;
+        };
+        match item {
+            Item::Value(value) => decorate_key_value(key, value, decor),
+            Item::Table(child) if child.is_dotted() => {
+                append_to_first_dotted_key(child, &decor);
+            }
+            Item::Table(child) => child.comments.insert(0, (None, decor)),
+            Item::ArrayOfTables(tables) => {
+                if let Some(first) = tables.get_mut(0) {
+                    first.comments.insert(0, (None, decor));
+                }
+            }
+            Item::None => {}
+        }
+    }
+
+    for (_, item) in table.iter_mut() {
+        match item {
+            Item::Table(child) => {
+                if !child.is_dotted() {
+                    decorate_header(child);
+                }
+                decorate_table(child);
+            }
+            Item::ArrayOfTables(tables) => {
+                for child in tables.iter_mut() {
+                    decorate_header(child);
+                    decorate_table(child);
+                }
+            }
+            Item::Value(Value::InlineTable(child)) if child.is_dotted() => {
+                decorate_dotted_inline_table(child);
+            }
+            Item::Value(_) | Item::None => {}
+        }
+    }
+}
+
+fn decorate_dotted_inline_table(table: &mut InlineTable) {
+    for (key, decor) in std::mem::take(&mut table.comments) {
+        let Some(key) = key else {
+            continue;
+        };
+        if let Some((key, Item::Value(value))) = table.get_key_value_mut(&key) {
+            decorate_key_value(key, value, decor);
+        }
+    }
+
+    for (_, value) in table.iter_mut() {
+        if let Value::InlineTable(child) = value {
+            if child.is_dotted() {
+                decorate_dotted_inline_table(child);
+            }
+        }
+    }
+}
+
+fn decorate_header(table: &mut Table) {
+    let own = take_own(&mut table.comments);
+    if let Some(prefix) = own.prefix() {
+        let prefix = format!("\n{}", raw(Some(prefix)));
+        table.decor_mut().set_prefix(prefix);
+    }
+    if let Some(suffix) = own.suffix() {
+        let suffix = format!(" {}", raw(Some(suffix)));
+        table.decor_mut().set_suffix(suffix);
+    }
+}
+
+fn decorate_key_value(mut key: KeyMut<'_>, value: &mut Value, decor: Decor) {
+    if let Value::InlineTable(table) = value {
+        if table.is_dotted() {
+            // There is no line for a trailing comment
+            append_to_first_dotted_inline_key(table, &decor);
+            return;
+        }
+    }
+    append_prefix(key.leaf_decor_mut(), &decor);
+    if let Some(suffix) = decor.suffix() {
+        let suffix = format!(" {}", raw(Some(suffix)));
+        value.decor_mut().set_suffix(suffix);
+    }
+}
+
+/// Add the comment above the first key-value pair of a table written as dotted keys
+fn append_to_first_dotted_key(table: &mut Table, decor: &Decor) -> bool {
+    for (mut key, item) in table.iter_mut() {
+        let appended = match item {
+            Item::Value(Value::InlineTable(child)) if child.is_dotted() => {
+                append_to_first_dotted_inline_key(child, decor)
+            }
+            Item::Table(child) if child.is_dotted() => append_to_first_dotted_key(child, decor),
+            Item::Value(_) => {
+                append_prefix(key.leaf_decor_mut(), decor);
+                true
+            }
+            Item::Table(_) | Item::ArrayOfTables(_) | Item::None => false,
+        };
+        if appended {
+            return true;
+        }
+    }
+    false
+}
+
+fn append_to_first_dotted_inline_key(table: &mut InlineTable, decor: &Decor) -> bool {
+    for (mut key, value) in table.iter_mut() {
+        let appended = match value {
+            Value::InlineTable(child) if child.is_dotted() => {
+                append_to_first_dotted_inline_key(child, decor)
+            }
+            _ => {
+                append_prefix(key.leaf_decor_mut(), decor);
+                true
+            }
+        };
+        if appended {
+            return true;
+        }
+    }
+    false
+}
+
+/// Add the comment lines of `decor` after those already in `to`, closer to the key
+fn append_prefix(to: &mut Decor, decor: &Decor) {
+    if let Some(prefix) = decor.prefix() {
+        let prefix = format!("{}{}", raw(to.prefix()), raw(Some(prefix)));
+        to.set_prefix(prefix);
+    }
+}
+
+/// Take the comments for the table itself, in the order they were added
+fn take_own(comments: &mut Vec<(Option<String>, Decor)>) -> Decor {
+    let mut own = Decor::default();
+    comments.retain(|(key, decor)| {
+        if key.is_some() {
+            return true;
+        }
+        append_prefix(&mut own, decor);
+        if let Some(suffix) = decor.suffix() {
+            own.set_suffix(suffix.clone());
+        }
+        false
+    });
+    own
+}
+
+fn raw(raw: Option<&crate::RawString>) -> &str {
+    raw.and_then(|raw| raw.as_str()).unwrap_or_default()
+}
+
+/// Find the first table with a header, in the order they are written
+fn first_table_path(table: &Table, path: &mut Vec<(String, Option<usize>)>) -> bool {
+    for (key, item) in table.iter() {
+        match item {
+            Item::Table(child) if !child.is_dotted() => {
+                path.push((key.to_owned(), None));
+                let is_visible = !child.implicit || !child.get_values().is_empty();
+                if is_visible || first_table_path(child, path) {
+                    return true;
+                }
+                path.pop();
+            }
+            Item::ArrayOfTables(tables) => {
+                if !tables.is_empty() {
+                    path.push((key.to_owned(), Some(0)));
+                    return true;
+                }
+            }
+            Item::Table(_) | Item::Value(_) | Item::None => {}
+        }
+    }
+    false
+}
+
+fn table_at_mut<'t>(
+    mut table: &'t mut Table,
+    path: &[(String, Option<usize>)],
+) -> Option<&'t mut Table> {
+    for (key, index) in path {
+        let item = table.get_mut(key)?;
+        table = match index {
+            Some(index) => item.as_array_of_tables_mut()?.get_mut(*index)?,
+            None => item.as_table_mut()?,
+        };
+    }
+    Some(table)
+}
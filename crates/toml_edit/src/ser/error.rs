@@ -43,6 +43,18 @@ impl Error {
     pub(crate) fn date_invalid() -> Self {
         Self::DateInvalid
     }
+
+    /// What went wrong, for handling the error consistently with [`TomlError::kind`][crate::TomlError::kind]
+    pub fn kind(&self) -> crate::ErrorKind {
+        match self {
+            Self::UnsupportedType(t) => crate::ErrorKind::UnsupportedType(*t),
+            Self::OutOfRange(t) => crate::ErrorKind::OutOfRange(*t),
+            Self::UnsupportedNone => crate::ErrorKind::UnsupportedNone,
+            Self::KeyNotString => crate::ErrorKind::KeyNotString,
+            Self::DateInvalid => crate::ErrorKind::DateInvalid,
+            Self::Custom(_) => crate::ErrorKind::Custom,
+        }
+    }
 }
 
 impl serde_core::ser::Error for Error {
@@ -77,7 +89,7 @@ impl From<crate::TomlError> for Error {
 
 impl From<Error> for crate::TomlError {
     fn from(e: Error) -> Self {
-        Self::custom(e.to_string(), None)
+        Self::custom(e.to_string(), None).with_kind(e.kind())
     }
 }
 
@@ -0,0 +1,392 @@
+//! Wrappers that control how a value is formatted as TOML
+//!
+//! Other serializers see the wrapped value unchanged.
+
+use crate::{Array, InlineTable, Value};
+
+const INLINE: &str = "$__toml_private_Inline";
+const MULTILINE: &str = "$__toml_private_Multiline";
+const LITERAL_STRING: &str = "$__toml_private_LiteralString";
+const MULTILINE_STRING: &str = "$__toml_private_MultilineString";
+const HEX: &str = "$__toml_private_Hex";
+const DOTTED: &str = "$__toml_private_Dotted";
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Hint {
+    Inline,
+    Multiline,
+    LiteralString,
+    MultilineString,
+    Hex,
+    Dotted,
+}
+
+impl Hint {
+    /// The formatting hint for a newtype struct `name`, if any
+    pub(crate) fn from_name(name: &'static str) -> Option<Self> {
+        match name {
+            INLINE => Some(Self::Inline),
+            MULTILINE => Some(Self::Multiline),
+            LITERAL_STRING => Some(Self::LiteralString),
+            MULTILINE_STRING => Some(Self::MultilineString),
+            HEX => Some(Self::Hex),
+            DOTTED => Some(Self::Dotted),
+            _ => None,
+        }
+    }
+
+    /// Format a serialized value according to this hint
+    ///
+    /// Values already formatted, e.g. by a nested hint, are left as-is.
+    pub(crate) fn apply(self, value: &mut Value) {
+        match self {
+            Self::Inline => match value {
+                Value::InlineTable(table) => table.keep_inline = true,
+                Value::Array(array) => {
+                    for value in array.iter_mut() {
+                        if let Value::InlineTable(table) = value {
+                            table.keep_inline = true;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Self::Dotted => {
+                if let Value::InlineTable(table) = value {
+                    dotted(table);
+                }
+            }
+            Self::Multiline => multiline(value),
+            Self::LiteralString | Self::MultilineString | Self::Hex => {
+                #[cfg(feature = "display")]
+                restyle(self, value);
+            }
+        }
+    }
+}
+
+fn dotted(table: &mut InlineTable) {
+    // Without any keys, the table would be dropped
+    if table.is_empty() {
+        return;
+    }
+    table.set_dotted(true);
+    for (_, value) in table.iter_mut() {
+        if let Value::InlineTable(table) = value {
+            dotted(table);
+        }
+    }
+}
+
+fn multiline(value: &mut Value) {
+    match value {
+        Value::Array(array) => {
+            for value in array.iter_mut() {
+                multiline(value);
+            }
+            if 2 <= array.len() && is_default_layout(array) {
+                for value in array.iter_mut() {
+                    value.decor_mut().set_prefix("\n    ");
+                }
+                array.set_trailing("\n");
+                array.set_trailing_comma(true);
+            }
+        }
+        Value::InlineTable(table) => {
+            for (_, value) in table.iter_mut() {
+                multiline(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_default_layout(array: &Array) -> bool {
+    array.trailing().as_str() == Some("")
+        && array.iter().all(|value| value.decor().prefix().is_none())
+}
+
+#[cfg(feature = "display")]
+fn restyle(hint: Hint, value: &mut Value) {
+    use toml_writer::ToTomlValue as _;
+
+    match value {
+        Value::String(string) if string.as_repr().is_none() => {
+            let builder = toml_writer::TomlStringBuilder::new(string.value());
+            let repr = match hint {
+                Hint::LiteralString if string.value().contains('\n') => builder
+                    .as_ml_literal()
+                    .unwrap_or_else(|| builder.as_default()),
+                Hint::LiteralString => builder.as_literal().unwrap_or_else(|| builder.as_default()),
+                Hint::MultilineString => builder
+                    .as_ml_basic_pretty()
+                    .or_else(|| builder.as_ml_literal())
+                    .unwrap_or_else(|| builder.as_ml_basic()),
+                _ => return,
+            };
+            string.set_repr_unchecked(crate::Repr::new_unchecked(repr.to_toml_value()));
+        }
+        Value::Integer(integer) if integer.as_repr().is_none() && hint == Hint::Hex => {
+            integer.fmt_with(toml_writer::TomlIntegerFormat::new().as_hex_upper());
+        }
+        Value::Array(array) => {
+            for value in array.iter_mut() {
+                restyle(hint, value);
+            }
+        }
+        Value::InlineTable(table) => {
+            for (_, value) in table.iter_mut() {
+                restyle(hint, value);
+            }
+        }
+        _ => {}
+    }
+}
+
+macro_rules! hint_type {
+    ($(#[$attr:meta])* $ty:ident, $name:ident) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub struct $ty<T>(pub T);
+
+        impl<T> $ty<T>
+        where
+            T: serde_core::ser::Serialize,
+        {
+            /// Serialize `value` with this hint
+            ///
+            /// This is intended for `#[serde(serialize_with = "...")]`.
+            pub fn serialize<S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde_core::ser::Serializer,
+            {
+                serializer.serialize_newtype_struct($name, value)
+            }
+        }
+
+        impl<T> serde_core::ser::Serialize for $ty<T>
+        where
+            T: serde_core::ser::Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde_core::ser::Serializer,
+            {
+                Self::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de, T> serde_core::de::Deserialize<'de> for $ty<T>
+        where
+            T: serde_core::de::Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde_core::de::Deserializer<'de>,
+            {
+                T::deserialize(deserializer).map(Self)
+            }
+        }
+    };
+}
+
+hint_type!(
+    /// Write a table, or an array of tables, inline
+    ///
+    /// This keeps it inline in [`to_string_pretty`][super::to_string_pretty] and when adding it to a
+    /// document with [`update_document`][super::update_document].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "display")] {
+    /// use toml_edit::ser::Inline;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Package {
+    ///     name: &'static str,
+    ///     metadata: Inline<Metadata>,
+    /// }
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Metadata {
+    ///     msrv: &'static str,
+    /// }
+    ///
+    /// let package = Package {
+    ///     name: "toml",
+    ///     metadata: Inline(Metadata { msrv: "1.85" }),
+    /// };
+    /// assert_eq!(
+    ///     toml_edit::ser::to_string_pretty(&package).unwrap(),
+    ///     "name = \"toml\"\nmetadata = { msrv = \"1.85\" }\n"
+    /// );
+    /// # }
+    /// ```
+    Inline,
+    INLINE
+);
+
+hint_type!(
+    /// Write arrays with each element on its own line, like [`to_string_pretty`][super::to_string_pretty]
+    ///
+    /// This applies to arrays of more than one element, including those nested in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "display")] {
+    /// use toml_edit::ser::Multiline;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Package {
+    ///     #[serde(serialize_with = "Multiline::serialize")]
+    ///     authors: Vec<&'static str>,
+    /// }
+    ///
+    /// let package = Package {
+    ///     authors: vec!["Alex Crichton", "Ed Page"],
+    /// };
+    /// assert_eq!(
+    ///     toml_edit::ser::to_string(&package).unwrap(),
+    ///     "authors = [\n    \"Alex Crichton\",\n    \"Ed Page\",\n]\n"
+    /// );
+    /// # }
+    /// ```
+    Multiline,
+    MULTILINE
+);
+
+hint_type!(
+    /// Write strings as literal strings, like `'C:\Users'`, when they can be
+    ///
+    /// Strings with newlines are written as multi-line literal strings and strings that can't be
+    /// literal, like `it's`, are written as basic strings.
+    /// This applies to all strings in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "display")] {
+    /// use toml_edit::ser::LiteralString;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Config {
+    ///     path: LiteralString<&'static str>,
+    /// }
+    ///
+    /// let config = Config {
+    ///     path: LiteralString(r"C:\Users"),
+    /// };
+    /// assert_eq!(toml_edit::ser::to_string(&config).unwrap(), "path = 'C:\\Users'\n");
+    /// # }
+    /// ```
+    LiteralString,
+    LITERAL_STRING
+);
+
+hint_type!(
+    /// Write strings as multi-line strings, like `"""..."""`
+    ///
+    /// This applies to all strings in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "display")] {
+    /// use toml_edit::ser::MultilineString;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Config {
+    ///     script: MultilineString<&'static str>,
+    /// }
+    ///
+    /// let config = Config {
+    ///     script: MultilineString("cargo build\ncargo test\n"),
+    /// };
+    /// assert_eq!(
+    ///     toml_edit::ser::to_string(&config).unwrap(),
+    ///     "script = \"\"\"\ncargo build\ncargo test\n\"\"\"\n"
+    /// );
+    /// # }
+    /// ```
+    MultilineString,
+    MULTILINE_STRING
+);
+
+hint_type!(
+    /// Write integers in hexadecimal, like `0xFF`
+    ///
+    /// Negative integers are always written in decimal.
+    /// This applies to all integers in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "display")] {
+    /// use toml_edit::ser::Hex;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Config {
+    ///     mask: Hex<u32>,
+    /// }
+    ///
+    /// let config = Config {
+    ///     mask: Hex(0xFFFF_0000),
+    /// };
+    /// assert_eq!(toml_edit::ser::to_string(&config).unwrap(), "mask = 0xFFFF0000\n");
+    /// # }
+    /// ```
+    Hex,
+    HEX
+);
+
+hint_type!(
+    /// Write a table as dotted keys in its parent table, like `a.b = 1`
+    ///
+    /// Tables nested in the value are also dotted.
+    /// Within arrays, this is written as an inline table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "display")] {
+    /// use toml_edit::ser::Dotted;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Package {
+    ///     name: &'static str,
+    ///     metadata: Dotted<Metadata>,
+    /// }
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Metadata {
+    ///     msrv: &'static str,
+    ///     docs: Docs,
+    /// }
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Docs {
+    ///     all_features: bool,
+    /// }
+    ///
+    /// let package = Package {
+    ///     name: "toml",
+    ///     metadata: Dotted(Metadata {
+    ///         msrv: "1.85",
+    ///         docs: Docs { all_features: true },
+    ///     }),
+    /// };
+    /// assert_eq!(
+    ///     toml_edit::ser::to_string(&package).unwrap(),
+    ///     "\
+    /// name = \"toml\"
+    /// metadata.msrv = \"1.85\"
+    /// metadata.docs.all_features = true
+    /// "
+    /// );
+    /// # }
+    /// ```
+    Dotted,
+    DOTTED
+);
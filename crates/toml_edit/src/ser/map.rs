@@ -1,6 +1,12 @@
+use serde_spanned::ser::CommentedSerializer;
+use serde_spanned::ser::Comments;
+use serde_spanned::ser::is_commented;
+
 use super::Error;
 use super::array::SerializeTupleVariant;
 use super::array::SerializeValueArray;
+use super::comment;
+use super::hint::Hint;
 use super::key::KeySerializer;
 use super::value::ValueSerializer;
 
@@ -134,7 +140,7 @@ impl serde_core::ser::SerializeStruct for SerializeDatetime {
     }
 }
 
-fn dt_err(err: toml_datetime::ser::SerializerError) -> Error {
+pub(crate) fn dt_err(err: toml_datetime::ser::SerializerError) -> Error {
     match err {
         toml_datetime::ser::SerializerError::InvalidFormat(err) => Error::custom(err),
         _ => Error::date_invalid(),
@@ -145,6 +151,7 @@ fn dt_err(err: toml_datetime::ser::SerializerError) -> Error {
 pub struct SerializeInlineTable {
     items: crate::table::KeyValuePairs,
     key: Option<crate::Key>,
+    comments: Vec<(Option<String>, crate::Decor)>,
 }
 
 impl SerializeInlineTable {
@@ -154,7 +161,11 @@ impl SerializeInlineTable {
         if let Some(len) = len {
             items.reserve(len);
         }
-        Self { items, key }
+        Self {
+            items,
+            key,
+            comments: Vec::new(),
+        }
     }
 }
 
@@ -175,11 +186,16 @@ impl serde_core::ser::SerializeMap for SerializeInlineTable {
         T: serde_core::ser::Serialize + ?Sized,
     {
         let mut is_none = false;
-        let value_serializer = MapValueSerializer::new(&mut is_none);
+        let mut comments = Comments::default();
+        let value_serializer = MapValueSerializer::new(&mut is_none, &mut comments);
         let res = value.serialize(value_serializer);
         match res {
             Ok(item) => {
                 let key = self.key.take().unwrap();
+                if !comments.is_empty() {
+                    let key = key.get().to_owned();
+                    self.comments.push((Some(key), comment::decor(&comments)));
+                }
                 let item = crate::Item::Value(item);
                 self.items.insert(key, item);
             }
@@ -193,7 +209,9 @@ impl serde_core::ser::SerializeMap for SerializeInlineTable {
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        Ok(crate::InlineTable::with_pairs(self.items))
+        let mut table = crate::InlineTable::with_pairs(self.items);
+        table.comments = self.comments;
+        Ok(table)
     }
 }
 
@@ -206,10 +224,15 @@ impl serde_core::ser::SerializeStruct for SerializeInlineTable {
         T: serde_core::ser::Serialize + ?Sized,
     {
         let mut is_none = false;
-        let value_serializer = MapValueSerializer::new(&mut is_none);
+        let mut comments = Comments::default();
+        let value_serializer = MapValueSerializer::new(&mut is_none, &mut comments);
         let res = value.serialize(value_serializer);
         match res {
             Ok(item) => {
+                if !comments.is_empty() {
+                    self.comments
+                        .push((Some(key.to_owned()), comment::decor(&comments)));
+                }
                 let item = crate::Item::Value(item);
                 self.items.insert(crate::Key::new(key), item);
             }
@@ -223,17 +246,20 @@ impl serde_core::ser::SerializeStruct for SerializeInlineTable {
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        Ok(crate::InlineTable::with_pairs(self.items))
+        let mut table = crate::InlineTable::with_pairs(self.items);
+        table.comments = self.comments;
+        Ok(table)
     }
 }
 
 struct MapValueSerializer<'d> {
     is_none: &'d mut bool,
+    comments: &'d mut Comments,
 }
 
 impl<'d> MapValueSerializer<'d> {
-    fn new(is_none: &'d mut bool) -> Self {
-        Self { is_none }
+    fn new(is_none: &'d mut bool, comments: &'d mut Comments) -> Self {
+        Self { is_none, comments }
     }
 }
 
@@ -335,12 +361,27 @@ impl serde_core::ser::Serializer for MapValueSerializer<'_> {
 
     fn serialize_newtype_struct<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
+        #[cfg(feature = "portable")]
+        if toml_datetime::ser::is_datetime_string(name) {
+            return ValueSerializer::new().serialize_newtype_struct(name, value);
+        }
+        if let Some(hint) = Hint::from_name(name) {
+            let mut value = value.serialize(self)?;
+            hint.apply(&mut value);
+            return Ok(value);
+        }
+        if is_commented(name) {
+            return value.serialize(CommentedSerializer::new(move |comments| {
+                *self.comments = comments;
+                Ok(self)
+            }));
+        }
         value.serialize(self)
     }
 
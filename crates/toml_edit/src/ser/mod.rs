@@ -3,10 +3,13 @@
 //! This module contains all the Serde support for serializing Rust structures into TOML.
 
 mod array;
+mod comment;
 mod error;
+mod hint;
 mod key;
 mod map;
 mod pretty;
+mod update;
 mod value;
 
 use crate::visit_mut::VisitMut as _;
@@ -16,6 +19,14 @@ use array::*;
 use map::*;
 
 pub use error::Error;
+pub use hint::Dotted;
+pub use hint::Hex;
+pub use hint::Inline;
+pub use hint::LiteralString;
+pub use hint::Multiline;
+pub use hint::MultilineString;
+pub use serde_spanned::Commented;
+pub use update::UpdateOptions;
 pub use value::ValueSerializer;
 
 /// Serialize the given data structure as a TOML byte vector.
@@ -84,8 +95,9 @@ pub fn to_string_pretty<T>(value: &T) -> Result<String, Error>
 where
     T: serde_core::ser::Serialize + ?Sized,
 {
-    let mut document = to_document(value)?;
+    let mut document = serialize_document(value)?;
     pretty::Pretty::new().visit_document_mut(&mut document);
+    comment::decorate_document(&mut document);
     Ok(document.to_string())
 }
 
@@ -93,6 +105,16 @@ where
 ///
 /// This would allow custom formatting to be applied, mixing with format preserving edits, etc.
 pub fn to_document<T>(value: &T) -> Result<crate::DocumentMut, Error>
+where
+    T: serde_core::ser::Serialize + ?Sized,
+{
+    let mut document = serialize_document(value)?;
+    comment::decorate_document(&mut document);
+    Ok(document)
+}
+
+/// Serialize into a document, without the comments of [`Commented`] values written yet
+fn serialize_document<T>(value: &T) -> Result<crate::DocumentMut, Error>
 where
     T: serde_core::ser::Serialize + ?Sized,
 {
@@ -103,3 +125,69 @@ where
         .map_err(|_| Error::UnsupportedType(None))?;
     Ok(root.into())
 }
+
+/// Serialize the given data structure into an existing TOML document, preserving its formatting.
+///
+/// Unchanged values are left as-is, including comments and whitespace.  Changed values keep their
+/// decor and, where possible, their style, e.g. a literal string stays literal and a hex integer
+/// stays hex.  New keys follow the indentation of their siblings.  Keys the serialized value
+/// doesn't have are removed; see [`update_document_with`] to keep them.  The comments of
+/// [`Commented`] values are dropped in favor of those of the document.
+///
+/// The document is unchanged if serialization fails.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse")] {
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Config {
+///     name: String,
+///     port: u16,
+/// }
+///
+/// let mut document = r#"
+/// ## The name of the service
+/// name = 'old'
+/// port = 0x1F90  # hex, for reasons
+/// "#.parse::<toml_edit::DocumentMut>().unwrap();
+///
+/// let config = Config {
+///     name: "new".to_owned(),
+///     port: 8081,
+/// };
+/// toml_edit::ser::update_document(&mut document, &config).unwrap();
+/// assert_eq!(document.to_string(), r#"
+/// ## The name of the service
+/// name = 'new'
+/// port = 0x1F91  # hex, for reasons
+/// "#);
+/// # }
+/// ```
+pub fn update_document<T>(document: &mut crate::DocumentMut, value: &T) -> Result<(), Error>
+where
+    T: serde_core::ser::Serialize + ?Sized,
+{
+    update_document_with(document, value, UpdateOptions::new())
+}
+
+/// Serialize the given data structure into an existing TOML document, according to `options`
+///
+/// See [`update_document`].
+pub fn update_document_with<T>(
+    document: &mut crate::DocumentMut,
+    value: &T,
+    options: UpdateOptions,
+) -> Result<(), Error>
+where
+    T: serde_core::ser::Serialize + ?Sized,
+{
+    let value = value.serialize(ValueSerializer::new())?;
+    let crate::Value::InlineTable(root) = value else {
+        return Err(Error::UnsupportedType(None));
+    };
+    update::update_table(document.as_table_mut(), root, options);
+    Ok(())
+}
@@ -15,7 +15,7 @@ impl crate::visit_mut::VisitMut for Pretty {
 
     fn visit_item_mut(&mut self, node: &mut crate::Item) {
         if !self.in_value {
-            node.make_item();
+            super::update::make_item(node);
         }
 
         crate::visit_mut::visit_item_mut(self, node);
@@ -29,7 +29,11 @@ impl crate::visit_mut::VisitMut for Pretty {
             node.set_implicit(true);
         }
 
+        // Keep the entries of dotted tables as dotted keys
+        let old_in_value = self.in_value;
+        self.in_value |= node.is_dotted();
         crate::visit_mut::visit_table_mut(self, node);
+        self.in_value = old_in_value;
     }
 
     fn visit_value_mut(&mut self, node: &mut crate::Value) {
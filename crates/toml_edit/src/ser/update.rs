@@ -0,0 +1,387 @@
+use crate::{Array, ArrayOfTables, Decor, InlineTable, Item, Key, RawString, Table, Value};
+
+/// Controls how [`update_document_with`][super::update_document_with] merges into a document
+#[derive(Copy, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateOptions {
+    keep_missing: bool,
+}
+
+impl UpdateOptions {
+    /// Default options: keys missing from the serialized value are removed
+    pub fn new() -> Self {
+        Self {
+            keep_missing: false,
+        }
+    }
+
+    /// Sets whether to keep keys that the serialized value doesn't have
+    ///
+    /// This is useful when the document has entries the serialized type doesn't know about.
+    pub fn with_keep_missing(mut self, yes: bool) -> Self {
+        self.keep_missing = yes;
+        self
+    }
+}
+
+pub(crate) fn update_table(table: &mut Table, new: InlineTable, options: UpdateOptions) {
+    if !options.keep_missing {
+        table.retain(|key, _| new.contains_key(key));
+    }
+
+    for (key, value) in new {
+        if let Some(item) = table.get_mut(&key) {
+            update_item(item, value, options);
+            continue;
+        }
+
+        let mut key = Key::new(key);
+        let mut item = new_item(table, value);
+        if let (Some(value), Some((last_key, last))) = (item.as_value_mut(), last_key_value(table))
+        {
+            // Follow the indentation and alignment of the other key-value pairs
+            copy_decor(last_key.leaf_decor(), key.leaf_decor_mut(), false);
+            align(last_key, &mut key);
+            if let Some(prefix) = whitespace(last.decor().prefix(), false) {
+                value.decor_mut().set_prefix(prefix);
+            }
+        }
+        table.insert_formatted(&key, item);
+    }
+}
+
+fn update_item(item: &mut Item, new: Value, options: UpdateOptions) {
+    match item {
+        Item::Table(table) => match new {
+            Value::InlineTable(new) => update_table(table, new, options),
+            new => *item = Item::Value(new),
+        },
+        Item::ArrayOfTables(tables) => match new {
+            Value::Array(new) if is_array_of_tables(&new) => {
+                update_array_of_tables(tables, new, options);
+            }
+            new => *item = Item::Value(new),
+        },
+        Item::Value(value) => update_value(value, new, options),
+        Item::None => *item = Item::Value(new),
+    }
+}
+
+fn update_array_of_tables(tables: &mut ArrayOfTables, new: Array, options: UpdateOptions) {
+    let len = new.len();
+    for (i, value) in new.into_iter().enumerate() {
+        let Value::InlineTable(new) = value else {
+            unreachable!("checked by `is_array_of_tables`");
+        };
+        if let Some(table) = tables.get_mut(i) {
+            update_table(table, new, options);
+        } else {
+            let mut item = Item::Value(Value::InlineTable(new));
+            make_items(&mut item);
+            if let Item::Table(table) = item {
+                tables.push(table);
+            }
+        }
+    }
+    while len < tables.len() {
+        tables.remove(tables.len() - 1);
+    }
+}
+
+fn update_value(value: &mut Value, new: Value, options: UpdateOptions) {
+    match (value, new) {
+        (Value::Array(array), Value::Array(new)) => update_array(array, new, options),
+        (Value::InlineTable(table), Value::InlineTable(new)) => {
+            update_inline_table(table, new, options);
+        }
+        (value, new) => update_scalar(value, new),
+    }
+}
+
+fn update_array(array: &mut Array, new: Array, options: UpdateOptions) {
+    let len = new.len();
+    for (i, mut value) in new.into_iter().enumerate() {
+        if let Some(old) = array.get_mut(i) {
+            update_value(old, value, options);
+        } else if 2 <= array.len() {
+            // Follow the layout of the elements after the first, e.g. one element per line
+            let last = array.get_mut(array.len() - 1).expect("not empty");
+            *value.decor_mut() = take_last_decor(last.decor_mut());
+            array.push_formatted(value);
+        } else {
+            array.push(value);
+        }
+    }
+    while len < array.len() {
+        let removed = array.remove(array.len() - 1);
+        let last = array.len().checked_sub(1).and_then(|i| array.get_mut(i));
+        if let (Some(last), Some(suffix)) = (last, whitespace(removed.decor().suffix(), true)) {
+            last.decor_mut().set_suffix(suffix);
+        }
+    }
+}
+
+fn update_inline_table(table: &mut InlineTable, new: InlineTable, options: UpdateOptions) {
+    if !options.keep_missing {
+        table.retain(|key, _| new.contains_key(key));
+    }
+
+    for (key, mut value) in new {
+        if let Some(old) = table.get_mut(&key) {
+            update_value(old, value, options);
+            continue;
+        }
+
+        let mut key = Key::new(key);
+        let last = table
+            .items
+            .iter_mut()
+            .rev()
+            .find_map(|(key, item)| Some((key, item.as_value_mut()?)));
+        if let Some((last_key, last)) = last {
+            copy_decor(last_key.leaf_decor(), key.leaf_decor_mut(), true);
+            *value.decor_mut() = take_last_decor(last.decor_mut());
+            table.insert_formatted(&key, value);
+        } else {
+            table.insert(key.get(), value);
+        }
+    }
+}
+
+fn update_scalar(value: &mut Value, mut new: Value) {
+    if is_same_scalar(value, &new) {
+        return;
+    }
+
+    #[cfg(feature = "display")]
+    restyle(value, &mut new);
+    *new.decor_mut() = value.decor().clone();
+    *value = new;
+}
+
+fn is_same_scalar(old: &Value, new: &Value) -> bool {
+    match (old, new) {
+        (Value::String(old), Value::String(new)) => old.value() == new.value(),
+        (Value::Integer(old), Value::Integer(new)) => old.value() == new.value(),
+        (Value::Float(old), Value::Float(new)) => old.value().to_bits() == new.value().to_bits(),
+        (Value::Boolean(old), Value::Boolean(new)) => old.value() == new.value(),
+        (Value::Datetime(old), Value::Datetime(new)) => old.value() == new.value(),
+        _ => false,
+    }
+}
+
+/// Format `new` like the existing `old` value, e.g. keeping literal strings literal
+#[cfg(feature = "display")]
+fn restyle(old: &Value, new: &mut Value) {
+    use toml_writer::ToTomlValue as _;
+
+    let repr = match old {
+        Value::String(old) => old.as_repr(),
+        Value::Integer(old) => old.as_repr(),
+        Value::Float(old) => old.as_repr(),
+        _ => None,
+    };
+    let Some(raw) = repr.and_then(|repr| repr.as_raw().as_str()) else {
+        return;
+    };
+    match new {
+        Value::String(new) if old.is_str() => {
+            let builder = toml_writer::TomlStringBuilder::new(new.value());
+            let string = if raw.starts_with("'''") {
+                builder.as_ml_literal()
+            } else if raw.starts_with('\'') {
+                builder.as_literal()
+            } else if raw.starts_with(r#"""""#) {
+                Some(builder.as_ml_basic())
+            } else {
+                Some(builder.as_basic())
+            };
+            if let Some(string) = string {
+                let repr = crate::Repr::new_unchecked(string.to_toml_value());
+                new.set_repr_unchecked(repr);
+            }
+        }
+        Value::Integer(new) if old.is_integer() => {
+            let digits = raw.trim_start_matches(['+', '-']);
+            let format = toml_writer::TomlIntegerFormat::new();
+            let format = if digits.starts_with("0x") {
+                if digits.contains(|c: char| c.is_ascii_uppercase()) {
+                    format.as_hex_upper()
+                } else {
+                    format.as_hex_lower()
+                }
+            } else if digits.starts_with("0o") {
+                format.as_octal()
+            } else if digits.starts_with("0b") {
+                format.as_binary()
+            } else {
+                format
+            };
+            new.fmt_with(format.with_grouping(raw.contains('_')));
+        }
+        Value::Float(new) if old.is_float() => {
+            if !new.value().is_finite() {
+                return;
+            }
+            let mut format = toml_writer::TomlFloatFormat::new().with_grouping(raw.contains('_'));
+            if raw.contains(['e', 'E']) {
+                format = format.with_exponent_above(0.0);
+            }
+            new.fmt_with(format);
+        }
+        _ => {}
+    }
+}
+
+/// Convert `value` to the form new entries of `parent` should take
+fn new_item(parent: &Table, value: Value) -> Item {
+    let mut item = Item::Value(value);
+    if !parent.is_dotted() && !prefers_inline(parent) {
+        make_items(&mut item);
+    }
+    item
+}
+
+/// Whether `table` uses inline tables rather than sub-tables for its entries
+fn prefers_inline(table: &Table) -> bool {
+    let mut has_inline = false;
+    for (_, item) in table.iter() {
+        match item {
+            Item::Table(table) if table.is_dotted() => {}
+            Item::Table(_) | Item::ArrayOfTables(_) => return false,
+            Item::Value(Value::InlineTable(_)) => has_inline = true,
+            Item::Value(Value::Array(array)) if is_array_of_tables(array) => has_inline = true,
+            Item::Value(_) | Item::None => {}
+        }
+    }
+    has_inline
+}
+
+fn make_items(item: &mut Item) {
+    make_item(item);
+    match item {
+        Item::Table(table) => {
+            // Empty tables could be semantically meaningful, so make sure they are not implicit
+            if !table.is_empty() {
+                table.set_implicit(true);
+            }
+            // Keep the entries of dotted tables as dotted keys
+            if table.is_dotted() {
+                return;
+            }
+            for (_, child) in table.iter_mut() {
+                make_items(child);
+            }
+        }
+        Item::ArrayOfTables(tables) => {
+            for table in tables.iter_mut() {
+                for (_, child) in table.iter_mut() {
+                    make_items(child);
+                }
+            }
+        }
+        Item::Value(_) | Item::None => {}
+    }
+}
+
+/// Keep the `=` of `key` lined up with `last`'s, if they are aligned with extra spaces
+fn align(last: &Key, key: &mut Key) {
+    let Some(suffix) = last
+        .leaf_decor()
+        .suffix()
+        .and_then(|suffix| suffix.as_str())
+    else {
+        return;
+    };
+    if suffix.len() <= 1 || !suffix.chars().all(|c| c == ' ') {
+        return;
+    }
+    let column = key_width(last) + suffix.len();
+    let padding = column.saturating_sub(key_width(key)).max(1);
+    key.leaf_decor_mut().set_suffix(" ".repeat(padding));
+}
+
+fn key_width(key: &Key) -> usize {
+    key.as_repr()
+        .and_then(|repr| repr.as_raw().as_str())
+        .unwrap_or(key.get())
+        .chars()
+        .count()
+}
+
+/// Make `item` into a table or array of tables, unless a [`Inline`][super::Inline] hint keeps it
+/// inline
+///
+/// Dotted inline tables, e.g. from a [`Dotted`][super::Dotted] hint, stay dotted.
+pub(crate) fn make_item(item: &mut Item) {
+    let keep_inline = match &*item {
+        Item::Value(Value::InlineTable(table)) => table.keep_inline,
+        Item::Value(Value::Array(array)) => array.iter().any(|value| {
+            value
+                .as_inline_table()
+                .is_some_and(|table| table.keep_inline)
+        }),
+        _ => false,
+    };
+    if keep_inline {
+        return;
+    }
+    let is_dotted = item.as_inline_table().is_some_and(InlineTable::is_dotted);
+    item.make_item();
+    if let Some(table) = item.as_table_mut() {
+        table.set_dotted(is_dotted);
+    }
+}
+
+fn is_array_of_tables(array: &Array) -> bool {
+    !array.is_empty() && array.iter().all(Value::is_inline_table)
+}
+
+fn last_key_value(table: &Table) -> Option<(&Key, &Value)> {
+    table
+        .items
+        .iter()
+        .rev()
+        .find_map(|(key, item)| Some((key, item.as_value()?)))
+}
+
+/// Copy the decor of the last element for a new element after it
+///
+/// The last element's trailing whitespace, e.g. the newline before a closing bracket, moves to the
+/// new element.
+fn take_last_decor(last: &mut Decor) -> Decor {
+    let mut decor = Decor::default();
+    copy_decor(last, &mut decor, true);
+    if decor.suffix().is_some() {
+        last.set_suffix("");
+    }
+    decor
+}
+
+fn copy_decor(from: &Decor, to: &mut Decor, keep_newline: bool) {
+    if let Some(prefix) = whitespace(from.prefix(), keep_newline) {
+        to.set_prefix(prefix);
+    }
+    if let Some(suffix) = whitespace(from.suffix(), keep_newline) {
+        to.set_suffix(suffix);
+    }
+}
+
+/// The whitespace of `raw` that applies to a new entry, dropping comments
+///
+/// Returns `None` to use the default decor.
+fn whitespace(raw: Option<&RawString>, keep_newline: bool) -> Option<String> {
+    let raw = raw?.as_str()?;
+    let (newline, indent) = match raw.rsplit_once('\n') {
+        Some((_, indent)) => (keep_newline, indent),
+        None => (false, raw),
+    };
+    if !indent.chars().all(|c| c == ' ' || c == '\t') {
+        return None;
+    }
+    if newline {
+        Some(format!("\n{indent}"))
+    } else {
+        Some(indent.to_owned())
+    }
+}
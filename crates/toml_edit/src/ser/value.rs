@@ -1,8 +1,14 @@
+use serde_spanned::ser::CommentedSerializer;
+use serde_spanned::ser::Comments;
+use serde_spanned::ser::is_commented;
+
 use super::Error;
 use super::SerializeMap;
 use super::SerializeStructVariant;
 use super::SerializeTupleVariant;
 use super::SerializeValueArray;
+use super::comment;
+use super::hint::Hint;
 
 /// Serialization for TOML [values][crate::Value].
 ///
@@ -174,12 +180,38 @@ impl serde_core::ser::Serializer for ValueSerializer {
 
     fn serialize_newtype_struct<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: serde_core::ser::Serialize + ?Sized,
     {
+        #[cfg(feature = "portable")]
+        if toml_datetime::ser::is_datetime_string(name) {
+            let datetime =
+                toml_datetime::ser::datetime_from_string(value).map_err(super::dt_err)?;
+            return serde_core::ser::Serialize::serialize(&datetime, self);
+        }
+        if let Some(hint) = Hint::from_name(name) {
+            let mut value = value.serialize(self)?;
+            hint.apply(&mut value);
+            return Ok(value);
+        }
+        if is_commented(name) {
+            let mut comments = Comments::default();
+            let mut value = value.serialize(CommentedSerializer::new(|c| {
+                comments = c;
+                Ok(self)
+            }))?;
+            // Only tables can be written with comments outside of a key-value pair, e.g. the
+            // document or an array of tables
+            if let crate::Value::InlineTable(table) = &mut value {
+                if !comments.is_empty() {
+                    table.comments.push((None, comment::decor(&comments)));
+                }
+            }
+            return Ok(value);
+        }
         value.serialize(self)
     }
 
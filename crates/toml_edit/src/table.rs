@@ -22,6 +22,8 @@ pub struct Table {
     // `None` for user created tables (can be overridden with `set_position`)
     doc_position: Option<isize>,
     pub(crate) span: Option<std::ops::Range<usize>>,
+    // comments for entries, or the table itself without a key, see `ser::Commented`
+    pub(crate) comments: Vec<(Option<String>, Decor)>,
     pub(crate) items: KeyValuePairs,
 }
 
@@ -55,6 +57,7 @@ impl Table {
         }
         let mut t = InlineTable::with_pairs(self.items);
         t.fmt();
+        t.comments = self.comments;
         t
     }
 }
@@ -242,7 +242,7 @@ impl FromStr for Value {
     /// Parses a value from a &str
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let source = toml_parser::Source::new(s);
-        let mut sink = crate::error::TomlSink::<Option<_>>::new(source);
+        let mut sink = crate::error::TomlSink::<Option<_>>::new(s);
         let mut value = crate::parser::parse_value(source, &mut sink);
         if let Some(err) = sink.into_inner() {
             Err(err)
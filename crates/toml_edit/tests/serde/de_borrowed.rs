@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::de::IntoDeserializer as _;
+use snapbox::assert_data_eq;
+use snapbox::prelude::*;
+use snapbox::str;
+use toml_edit::Document;
+use toml_edit::DocumentMut;
+use toml_edit::de::from_document_ref;
+
+use crate::Spanned;
+use crate::SpannedEntry;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Package<'a> {
+    name: &'a str,
+    #[serde(borrow)]
+    description: Cow<'a, str>,
+    #[serde(borrow)]
+    dependencies: BTreeMap<&'a str, Dependency<'a>>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+enum Dependency<'a> {
+    Version(&'a str),
+    Full {
+        version: &'a str,
+        features: Vec<&'a str>,
+    },
+}
+
+const INPUT: &str = r#"
+name = "foo"
+description = "Escaped \"description\""
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+"toml" = '0.9'
+"#;
+
+#[test]
+fn document_mut() {
+    let document = INPUT.parse::<DocumentMut>().unwrap();
+    let package: Package<'_> = from_document_ref(&document).unwrap();
+    assert_eq!(
+        package,
+        Package {
+            name: "foo",
+            description: Cow::Borrowed(r#"Escaped "description""#),
+            dependencies: [
+                (
+                    "serde",
+                    Dependency::Full {
+                        version: "1.0",
+                        features: vec!["derive"],
+                    }
+                ),
+                ("toml", Dependency::Version("0.9")),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    );
+    assert!(matches!(package.description, Cow::Borrowed(_)));
+}
+
+#[test]
+fn document() {
+    let document = Document::parse(INPUT).unwrap();
+    let package: Package<'_> = from_document_ref(&document).unwrap();
+    assert_eq!(package.name, "foo");
+    assert_eq!(package.dependencies.len(), 2);
+}
+
+#[test]
+fn value() {
+    let value = r#"{ name = "foo", tags = ["a", "b"] }"#.parse::<toml_edit::Value>().unwrap();
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Value<'a> {
+        name: &'a str,
+        tags: Vec<&'a str>,
+    }
+
+    let value = Value::deserialize((&value).into_deserializer()).unwrap();
+    assert_eq!(
+        value,
+        Value {
+            name: "foo",
+            tags: vec!["a", "b"],
+        }
+    );
+}
+
+#[test]
+fn spanned() {
+    #[derive(Debug, Deserialize)]
+    struct Spans<'a> {
+        #[serde(borrow)]
+        name: Spanned<&'a str>,
+        keys: BTreeMap<Spanned<&'a str>, i64>,
+    }
+
+    let input = "name = 'foo'\nkeys = { a = 1 }\n";
+    let document = Document::parse(input).unwrap();
+    let spans: Spans<'_> = from_document_ref(&document).unwrap();
+    assert_eq!(&input[spans.name.span()], "'foo'");
+    let (key, _) = spans.keys.first_key_value().unwrap();
+    assert_eq!(&input[key.span()], "a");
+}
+
+#[test]
+fn spanned_entry() {
+    #[derive(Debug, Deserialize)]
+    struct Spans<'a> {
+        #[serde(borrow)]
+        name: SpannedEntry<&'a str, &'a str>,
+    }
+
+    let input = "name = 'foo'\n";
+    let document = Document::parse(input).unwrap();
+    let spans: Spans<'_> = from_document_ref(&document).unwrap();
+    assert_eq!(&input[spans.name.key().span()], "name");
+    assert_eq!(&input[spans.name.value().span()], "'foo'");
+    assert_eq!(*spans.name.get_ref(), "foo");
+}
+
+#[test]
+fn enums() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Mode<'a> {
+        Fast,
+        Named(&'a str),
+        Slow { factor: i64 },
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Modes<'a> {
+        #[serde(borrow)]
+        modes: Vec<Mode<'a>>,
+    }
+
+    let document = r#"modes = ["Fast", { Named = "x" }, { Slow = { factor = 2 } }]"#
+        .parse::<DocumentMut>()
+        .unwrap();
+    let modes: Modes<'_> = from_document_ref(&document).unwrap();
+    assert_eq!(
+        modes.modes,
+        vec![Mode::Fast, Mode::Named("x"), Mode::Slow { factor: 2 }]
+    );
+}
+
+#[test]
+fn error() {
+    let input = "name = 'foo'\ndescription = 1\n";
+    let document = Document::parse(input).unwrap();
+    let err = from_document_ref::<BTreeMap<&str, &str>>(&document).unwrap_err();
+    assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+TOML parse error at line 2, column 15
+  |
+2 | description = 1
+  |               ^
+invalid type: integer `1`, expected a borrowed string
+note: in `description`
+
+"#]]
+        .raw()
+    );
+}
@@ -89,6 +89,7 @@ TOML parse error at line 2, column 19
 2 |             p_a = ''
   |                   ^^
 invalid length 0, expected a non-empty string
+note: in `p_a`
 
 "#]],
     );
@@ -122,6 +123,7 @@ TOML parse error at line 3, column 19
 3 |             p_b = 1
   |                   ^
 invalid type: integer `1`, expected a sequence
+note: in `p_b`
 
 "#]],
     );
@@ -141,6 +143,7 @@ TOML parse error at line 4, column 17
 4 |                 {c_a = 'a'}
   |                 ^^^^^^^^^^^
 missing field `c_b`
+note: in `p_b`
 
 "#]],
     );
@@ -160,6 +163,7 @@ TOML parse error at line 4, column 35
 4 |                 {c_a = 'a', c_b = '*'}
   |                                   ^^^
 invalid value: string "*", expected all lowercase or all uppercase
+note: in `p_b.c_b`
 
 "#]],
     );
@@ -180,6 +184,7 @@ TOML parse error at line 5, column 17
 5 |                 {c_a = 'aa'}
   |                 ^^^^^^^^^^^^
 missing field `c_b`
+note: in `p_b`
 
 "#]],
     );
@@ -201,6 +206,7 @@ TOML parse error at line 5, column 17
 5 |                 {c_a = 'aa'},
   |                 ^^^^^^^^^^^^
 missing field `c_b`
+note: in `p_b`
 
 "#]],
     );
@@ -222,6 +228,7 @@ TOML parse error at line 5, column 36
 5 |                 {c_a = 'aa', c_b = 1},
   |                                    ^
 invalid type: integer `1`, expected a string
+note: in `p_b.c_b`
 
 "#]],
     );
@@ -244,6 +251,7 @@ TOML parse error at line 5, column 42
 5 |                 {c_a = 'aa', c_b = 'bb', c_d = 'd'},
   |                                          ^^^
 unknown field `c_d`, expected `c_a` or `c_b`
+note: in `p_b`
 
 "#]],
     );
@@ -273,6 +281,7 @@ TOML parse error at line 6, column 13
 6 |             [[p_b]]
   |             ^^^^^^^
 missing field `c_b`
+note: in `p_b`
 
 "#]],
     );
@@ -298,6 +307,7 @@ TOML parse error at line 8, column 19
 8 |             c_b = '*'
   |                   ^^^
 invalid value: string "*", expected all lowercase or all uppercase
+note: in `p_b.c_b`
 
 "#]],
     );
@@ -326,6 +336,7 @@ TOML parse error at line 8, column 13
 8 |             c_d = 'dd' # unknown field
   |             ^^^
 unknown field `c_d`, expected `c_a` or `c_b`
+note: in `p_b`
 
 "#]],
     );
@@ -362,6 +373,7 @@ TOML parse error at line 4, column 17
 4 |                 {c_a = ''}
   |                 ^^^^^^^^^^
 missing field `c_b`
+note: in `p_b`
 
 "#]],
     );
@@ -380,6 +392,7 @@ TOML parse error at line 4, column 34
 4 |                 {c_a = '', c_b = 1}
   |                                  ^
 invalid type: integer `1`, expected a string
+note: in `p_b.c_b`
 
 "#]],
     );
@@ -399,6 +412,7 @@ TOML parse error at line 4, column 38
 4 |                 {c_a = '', c_b = '', c_d = ''},
   |                                      ^^^
 unknown field `c_d`, expected `c_a` or `c_b`
+note: in `p_b`
 
 "#]],
     );
@@ -417,7 +431,150 @@ TOML parse error at line 4, column 34
 4 |                 {c_a = '', c_b = 1, c_d = ''},
   |                                  ^
 invalid type: integer `1`, expected a string
+note: in `p_b.c_b`
 
 "#]],
     );
 }
+
+#[test]
+fn invalid_encoding() {
+    #[track_caller]
+    fn bad_slice(toml: &[u8], msg: impl IntoData) {
+        match toml_edit::de::from_slice::<crate::SerdeTable>(toml) {
+            Ok(s) => panic!("parsed to: {s:#?}"),
+            Err(e) => assert_data_eq!(e.to_string(), msg.raw()),
+        }
+    }
+
+    bad_slice(
+        b"a = 1\nb = '\xE2\x82'\n",
+        str![[r#"
+TOML parse error at line 2, column 6
+  |
+2 | b = '�'
+  |      ^^
+invalid UTF-8
+
+"#]],
+    );
+    bad_slice(
+        b"\xFF\xFEa\0 \0=\0 \0\x3D\xD8",
+        str![[r#"
+unpaired UTF-16 surrogate
+
+"#]],
+    );
+    bad_slice(
+        b"\xFF\xFEa\0 ",
+        str![[r#"
+incomplete UTF-16 code unit
+
+"#]],
+    );
+
+    let utf16_le = b"\xFF\xFEa\0 \0=\0 \0";
+    let err = toml_edit::de::from_slice::<crate::SerdeTable>(utf16_le).unwrap_err();
+    assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+TOML parse error at line 1, column 5
+  |
+1 | a = 
+  |     ^
+string values must be quoted, expected literal string
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn error_kinds() {
+    use toml_edit::ErrorKind;
+
+    let err = crate::from_str::<crate::SerdeTable>("a = 1\na = 2").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::DuplicateKey {
+            key: "a".to_owned(),
+            first_span: Some(0..1),
+        }
+    );
+
+    let err = crate::from_str::<crate::SerdeTable>("[\"a b\"]\nx = 1\n[\"a b\"]").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::TableRedefinition {
+            key: "a b".to_owned(),
+            original_span: Some(1..6),
+        }
+    );
+
+    let err = crate::from_str::<crate::SerdeTable>(r#"a = "\q""#).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+
+    let err = crate::from_str::<crate::SerdeTable>("a = ").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Syntax);
+
+    let err = crate::from_str::<Parent<i64>>("p_a = 1\np_b = [{ c_a = 1, c_b = 2, c_c = 3 }]")
+        .unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnknownField {
+            field: "c_c".to_owned(),
+            expected: &["c_a", "c_b"],
+        }
+    );
+
+    let err = crate::from_str::<Parent<i64>>("p_a = 'a'\np_b = []").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::InvalidType {
+            expected: "i64".to_owned(),
+            found: "string \"a\"".to_owned(),
+        }
+    );
+
+    let err = crate::from_str::<Parent<i64>>("p_b = []").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MissingField { field: "p_a" });
+
+    let err = crate::to_string(&Some(())).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnsupportedType(Some("unit")));
+}
+
+#[test]
+fn diagnostic_help_and_note() {
+    let err = crate::from_str::<crate::SerdeTable>("[a\nb = 1").unwrap_err();
+    let diagnostic = err.diagnostic().unwrap();
+    assert_eq!(diagnostic.help(), ["insert `]`"]);
+    assert_data_eq!(
+        diagnostic.to_string(),
+        str![[r#"
+TOML parse error at line 1, column 3
+  |
+1 | [a
+  |   ^
+unclosed table, expected `]`
+help: insert `]`
+
+"#]]
+    );
+
+    let err =
+        crate::from_str::<Parent<i64>>("p_a = 1\np_b = [{ c_a = 'a', c_b = 2 }]").unwrap_err();
+    let diagnostic = err.diagnostic().unwrap();
+    assert_eq!(diagnostic.notes(), ["in `p_b.c_a`"]);
+    assert_data_eq!(
+        diagnostic.to_string(),
+        str![[r#"
+TOML parse error at line 2, column 16
+  |
+2 | p_b = [{ c_a = 'a', c_b = 2 }]
+  |                ^^^
+invalid type: string "a", expected i64
+note: in `p_b.c_a`
+
+"#]]
+    );
+}
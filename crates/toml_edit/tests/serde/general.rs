@@ -261,6 +261,7 @@ TOML parse error at line 1, column 7
 1 | bar = "a"
   |       ^^^
 invalid type: string "a", expected isize
+note: in `bar`
 
 "#]],
         str![[r#"
@@ -283,6 +284,7 @@ TOML parse error at line 1, column 15
 1 | foo = { bar = "a" }
   |               ^^^
 invalid type: string "a", expected isize
+note: in `foo.bar`
 
 "#]],
         str![[r#"
@@ -887,6 +889,7 @@ TOML parse error at line 8, column 9
 8 | debug = 'a'
   |         ^^^
 expected a boolean or an integer
+note: in `profile.dev.debug`
 
 "#]]
     );
@@ -911,6 +914,7 @@ TOML parse error at line 8, column 17
 8 | dev = { debug = 'a' }
   |                 ^^^
 expected a boolean or an integer
+note: in `profile.dev.debug`
 
 "#]]
     );
@@ -2012,3 +2016,103 @@ Some(
 "#]]
     );
 }
+
+#[test]
+fn from_slice_encodings() {
+    type Table = BTreeMap<String, String>;
+
+    let utf8_bom = b"\xEF\xBB\xBFkey = 'value'";
+    let utf16_le = b"\xFF\xFEk\0e\0y\0 \0=\0 \0'\0v\0a\0l\0u\0e\0'\0";
+    let utf16_be = b"\xFE\xFF\0k\0e\0y\0 \0=\0 \0'\0v\0a\0l\0u\0e\0'";
+    for input in [&utf8_bom[..], &utf16_le[..], &utf16_be[..]] {
+        let table = toml_edit::de::from_slice::<Table>(input).unwrap();
+        assert_data_eq!(
+            table.to_debug(),
+            str![[r#"
+{
+    "key": "value",
+}
+
+"#]]
+        );
+    }
+}
+
+#[test]
+fn datetime_fraction_into_value() {
+    fn datetimes(table: &crate::SerdeTable) -> Vec<String> {
+        table
+            .values()
+            .map(|value| match value {
+                crate::SerdeValue::Datetime(datetime) => datetime.to_string(),
+                value => panic!("expected datetime, found {value:?}"),
+            })
+            .collect()
+    }
+
+    let input = "a = 07:32:00.500\nb = 1979-05-27T07:32:00.1234567891Z\n";
+    let expected = ["07:32:00.500", "1979-05-27T07:32:00.1234567891Z"];
+
+    let table = crate::from_str::<crate::SerdeTable>(input).unwrap();
+    assert_eq!(datetimes(&table), expected);
+
+    let document = input.parse::<toml_edit::Document<String>>().unwrap();
+    let table = toml_edit::de::from_document_ref::<crate::SerdeTable>(&document).unwrap();
+    assert_eq!(datetimes(&table), expected);
+
+    let document = input.parse::<toml_edit::DocumentMut>().unwrap();
+    let table = toml_edit::de::from_document::<crate::SerdeTable>(document).unwrap();
+    assert_eq!(datetimes(&table), expected);
+}
+
+#[test]
+#[cfg(feature = "portable")]
+fn datetime_as_string() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Release {
+        #[serde(with = "toml_edit::as_string")]
+        published: crate::Datetime,
+        #[serde(default, with = "toml_edit::as_string::option")]
+        yanked: Option<crate::Datetime>,
+        updated: toml_edit::PortableDatetime,
+    }
+
+    let release = Release {
+        published: "1979-05-27T07:32:00Z".parse().unwrap(),
+        yanked: Some("1979-05-28".parse().unwrap()),
+        updated: toml_edit::PortableDatetime("1979-05-27T00:32:00-07:00".parse().unwrap()),
+    };
+
+    let toml = crate::to_string(&release).unwrap();
+    assert_data_eq!(
+        &toml,
+        str![[r#"
+published = 1979-05-27T07:32:00Z
+yanked = 1979-05-28
+updated = 1979-05-27T00:32:00-07:00
+
+"#]]
+    );
+    assert_eq!(crate::from_str::<Release>(&toml).unwrap(), release);
+
+    let json = serde_json::to_string(&release).unwrap();
+    assert_data_eq!(
+        &json,
+        str![[
+            r#"{"published":"1979-05-27T07:32:00Z","yanked":"1979-05-28","updated":"1979-05-27T00:32:00-07:00"}"#
+        ]]
+    );
+    assert_eq!(serde_json::from_str::<Release>(&json).unwrap(), release);
+
+    let toml = "published = '1979-05-27T07:32:00Z'\nupdated = '1979-05-27T00:32:00-07:00'\n";
+    let release = crate::from_str::<Release>(toml).unwrap();
+    assert_eq!(release.yanked, None);
+    assert_data_eq!(
+        crate::to_string(&release).unwrap(),
+        str![[r#"
+published = 1979-05-27T07:32:00Z
+updated = 1979-05-27T00:32:00-07:00
+
+"#]]
+    );
+}
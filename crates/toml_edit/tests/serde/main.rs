@@ -10,18 +10,23 @@ macro_rules! t {
     };
 }
 
+mod de_borrowed;
 mod de_enum;
 mod de_errors;
 mod de_key;
 mod general;
+mod ser_comments;
 mod ser_enum;
+mod ser_hints;
 mod ser_key;
 mod ser_tables_last;
 mod ser_to_string;
 mod ser_to_string_pretty;
+mod ser_update_document;
 mod spanned;
 
 use serde_spanned::Spanned;
+use serde_spanned::SpannedEntry;
 use toml_edit::Date;
 use toml_edit::Datetime;
 use toml_edit::Time;
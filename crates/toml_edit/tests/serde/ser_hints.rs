@@ -0,0 +1,228 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use snapbox::assert_data_eq;
+use snapbox::prelude::*;
+use snapbox::str;
+use toml_edit::ser::Dotted;
+use toml_edit::ser::Hex;
+use toml_edit::ser::Inline;
+use toml_edit::ser::LiteralString;
+use toml_edit::ser::Multiline;
+use toml_edit::ser::MultilineString;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Package {
+    name: LiteralString<String>,
+    description: MultilineString<String>,
+    #[serde(serialize_with = "Multiline::serialize")]
+    authors: Vec<String>,
+    flags: Hex<u32>,
+    metadata: Dotted<Metadata>,
+    badges: Inline<BTreeMap<String, String>>,
+    targets: Inline<Vec<Target>>,
+    features: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Metadata {
+    msrv: String,
+    docs: Docs,
+    empty: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skipped: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Docs {
+    all_features: bool,
+    targets: Vec<Target>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Target {
+    name: String,
+}
+
+fn package() -> Package {
+    Package {
+        name: LiteralString(r"C:\toml".to_owned()),
+        description: MultilineString("A TOML parser\nwith serde support\n".to_owned()),
+        authors: vec!["Alex Crichton".to_owned(), "Ed Page".to_owned()],
+        flags: Hex(0xFF),
+        metadata: Dotted(Metadata {
+            msrv: "1.85".to_owned(),
+            docs: Docs {
+                all_features: true,
+                targets: vec![Target {
+                    name: "x86_64".to_owned(),
+                }],
+            },
+            empty: BTreeMap::new(),
+            skipped: None,
+        }),
+        badges: Inline(BTreeMap::from([(
+            "maintenance".to_owned(),
+            "actively-developed".to_owned(),
+        )])),
+        targets: Inline(vec![
+            Target {
+                name: "lib".to_owned(),
+            },
+            Target {
+                name: "bin".to_owned(),
+            },
+        ]),
+        features: BTreeMap::from([("default".to_owned(), vec!["std".to_owned()])]),
+    }
+}
+
+#[test]
+fn to_string() {
+    let package = package();
+    let toml = crate::to_string(&package).unwrap();
+    assert_data_eq!(
+        &toml,
+        str![[r#"
+name = 'C:\toml'
+description = """
+A TOML parser
+with serde support
+"""
+authors = [
+    "Alex Crichton",
+    "Ed Page",
+]
+flags = 0xFF
+metadata.msrv = "1.85"
+metadata.docs.all_features = true
+metadata.docs.targets = [{ name = "x86_64" }]
+metadata.empty = {}
+badges = { maintenance = "actively-developed" }
+targets = [{ name = "lib" }, { name = "bin" }]
+features = { default = ["std"] }
+
+"#]]
+        .raw()
+    );
+    let roundtrip: Package = crate::from_str(&toml).unwrap();
+    assert_eq!(roundtrip, package);
+}
+
+#[test]
+fn to_string_pretty() {
+    let package = package();
+    let toml = crate::to_string_pretty(&package).unwrap();
+    assert_data_eq!(
+        &toml,
+        str![[r#"
+name = 'C:\toml'
+description = """
+A TOML parser
+with serde support
+"""
+authors = [
+    "Alex Crichton",
+    "Ed Page",
+]
+flags = 0xFF
+metadata.msrv = "1.85"
+metadata.docs.all_features = true
+metadata.docs.targets = [{ name = "x86_64" }]
+metadata.empty = {}
+badges = { maintenance = "actively-developed" }
+targets = [
+    { name = "lib" },
+    { name = "bin" },
+]
+
+[features]
+default = ["std"]
+
+"#]]
+        .raw()
+    );
+    let roundtrip: Package = crate::from_str(&toml).unwrap();
+    assert_eq!(roundtrip, package);
+}
+
+#[test]
+fn to_string_value() {
+    let value = BTreeMap::from([
+        ("path", Dotted(LiteralString("it's"))),
+        ("lines", Dotted(LiteralString("a\nb"))),
+    ]);
+    assert_data_eq!(
+        crate::to_string_value(&value).unwrap(),
+        str![[r#"
+{ lines = '''
+a
+b''', path = "it's" }
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn nested_hints() {
+    #[derive(Serialize)]
+    struct Config {
+        values: LiteralString<BTreeMap<&'static str, MultilineString<&'static str>>>,
+        integers: Hex<Vec<i64>>,
+    }
+
+    let config = Config {
+        values: LiteralString(BTreeMap::from([("script", MultilineString("build"))])),
+        integers: Hex(vec![255, -255]),
+    };
+    assert_data_eq!(
+        crate::to_string(&config).unwrap(),
+        str![[r#"
+values = { script = """build""" }
+integers = [0xFF, -255]
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn other_serializers() {
+    let package = package();
+    let json = serde_json::to_value(&package).unwrap();
+    assert_eq!(json["name"], r"C:\toml");
+    assert_eq!(json["flags"], 255);
+    assert_eq!(json["metadata"]["msrv"], "1.85");
+}
+
+#[test]
+fn update_document() {
+    #[derive(Serialize)]
+    struct Manifest {
+        package: BTreeMap<&'static str, &'static str>,
+        badges: Inline<BTreeMap<&'static str, &'static str>>,
+        metadata: Dotted<BTreeMap<&'static str, &'static str>>,
+    }
+
+    let mut document = "[package]\nname = \"foo\"\n"
+        .parse::<toml_edit::DocumentMut>()
+        .unwrap();
+    let manifest = Manifest {
+        package: BTreeMap::from([("name", "foo")]),
+        badges: Inline(BTreeMap::from([("maintenance", "passively-maintained")])),
+        metadata: Dotted(BTreeMap::from([("msrv", "1.85")])),
+    };
+    toml_edit::ser::update_document(&mut document, &manifest).unwrap();
+    assert_data_eq!(
+        document.to_string(),
+        str![[r#"
+badges = { maintenance = "passively-maintained" }
+metadata.msrv = "1.85"
+[package]
+name = "foo"
+
+"#]]
+        .raw()
+    );
+}
@@ -0,0 +1,197 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use snapbox::assert_data_eq;
+use snapbox::prelude::*;
+use snapbox::str;
+use toml_edit::DocumentMut;
+use toml_edit::ser::UpdateOptions;
+use toml_edit::ser::update_document;
+use toml_edit::ser::update_document_with;
+
+#[derive(Serialize)]
+struct Package {
+    package: Metadata,
+    dependencies: BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct Metadata {
+    name: String,
+    version: String,
+    authors: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license: Option<String>,
+}
+
+const MANIFEST: &str = r#"
+# The package
+[package]
+name = "foo"  # not yet published
+version = '0.1.0'
+authors = [
+    "Alice",
+    "Bob",
+]
+edition = "2024"
+
+[dependencies]
+serde   = "1.0"   # for derive
+toml    = "0.9"
+"#;
+
+fn package() -> Package {
+    Package {
+        package: Metadata {
+            name: "foo".to_owned(),
+            version: "0.2.0".to_owned(),
+            authors: vec!["Alice".to_owned(), "Bob".to_owned(), "Carol".to_owned()],
+            license: Some("MIT".to_owned()),
+        },
+        dependencies: [
+            ("serde".to_owned(), "1.0".to_owned()),
+            ("snapbox".to_owned(), "0.6".to_owned()),
+            ("toml".to_owned(), "1.0".to_owned()),
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+#[test]
+fn preserves_formatting() {
+    let mut document = MANIFEST.parse::<DocumentMut>().unwrap();
+    update_document(&mut document, &package()).unwrap();
+    assert_data_eq!(
+        document.to_string(),
+        str![[r#"
+
+# The package
+[package]
+name = "foo"  # not yet published
+version = '0.2.0'
+authors = [
+    "Alice",
+    "Bob",
+    "Carol",
+]
+license = "MIT"
+
+[dependencies]
+serde   = "1.0"   # for derive
+toml    = "1.0"
+snapbox = "0.6"
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn keep_missing() {
+    let mut document = MANIFEST.parse::<DocumentMut>().unwrap();
+    let mut package = package();
+    package.package.authors.truncate(1);
+    package.package.license = None;
+    update_document_with(
+        &mut document,
+        &package,
+        UpdateOptions::new().with_keep_missing(true),
+    )
+    .unwrap();
+    assert_data_eq!(
+        document.to_string(),
+        str![[r#"
+
+# The package
+[package]
+name = "foo"  # not yet published
+version = '0.2.0'
+authors = [
+    "Alice",
+]
+edition = "2024"
+
+[dependencies]
+serde   = "1.0"   # for derive
+toml    = "1.0"
+snapbox = "0.6"
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn new_tables() {
+    #[derive(Serialize)]
+    struct Document {
+        title: String,
+        owner: Owner,
+        servers: Vec<Server>,
+    }
+
+    #[derive(Serialize)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(Serialize)]
+    struct Server {
+        ip: String,
+        port: u16,
+    }
+
+    let mut document = r#"title = "Example"
+
+[[servers]]
+ip = "10.0.0.1"
+port = 0x1F90
+"#
+    .parse::<DocumentMut>()
+    .unwrap();
+    let value = Document {
+        title: "Example".to_owned(),
+        owner: Owner {
+            name: "Tom".to_owned(),
+        },
+        servers: vec![
+            Server {
+                ip: "10.0.0.1".to_owned(),
+                port: 8081,
+            },
+            Server {
+                ip: "10.0.0.2".to_owned(),
+                port: 8080,
+            },
+        ],
+    };
+    update_document(&mut document, &value).unwrap();
+    assert_data_eq!(
+        document.to_string(),
+        str![[r#"
+title = "Example"
+
+[[servers]]
+ip = "10.0.0.1"
+port = 0x1F91
+
+[[servers]]
+ip = "10.0.0.2"
+port = 8080
+
+[owner]
+name = "Tom"
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn unsupported_root() {
+    let mut document = MANIFEST.parse::<DocumentMut>().unwrap();
+    let err = update_document(&mut document, &1).unwrap_err();
+    assert_data_eq!(err.to_string(), str!["unsupported rust type"]);
+    assert_eq!(document.to_string(), MANIFEST);
+}
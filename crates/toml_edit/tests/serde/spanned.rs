@@ -13,6 +13,7 @@ use snapbox::str;
 
 use crate::Datetime;
 use crate::Spanned;
+use crate::SpannedEntry;
 
 #[test]
 fn test_spanned_field() {
@@ -782,6 +783,161 @@ Map(
     assert_data_eq!(&INPUT[two.1.span()], str![[r#""qux""#]]);
 }
 
+#[test]
+fn spanned_key() {
+    #[derive(Debug, Deserialize)]
+    struct Manifest {
+        features: BTreeMap<Spanned<String>, Spanned<Vec<String>>>,
+    }
+
+    const INPUT: &str = r#"
+[features]
+default = ["std"]
+"std" = []
+"#;
+
+    let manifest = crate::from_str::<Manifest>(INPUT).unwrap();
+    let (key, value) = manifest.features.get_key_value("default").unwrap();
+    assert_data_eq!(&INPUT[key.span()], str!["default"]);
+    assert_data_eq!(&INPUT[value.span()], str![[r#"["std"]"#]]);
+    let (key, value) = manifest.features.get_key_value("std").unwrap();
+    assert_data_eq!(&INPUT[key.span()], str![[r#""std""#]]);
+    assert_data_eq!(&INPUT[value.span()], str!["[]"]);
+}
+
+#[test]
+fn spanned_entry() {
+    #[derive(Debug, Deserialize)]
+    struct Manifest {
+        package: SpannedEntry<String, Package>,
+        features: BTreeMap<String, SpannedEntry<String, Vec<String>>>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Package {
+        name: SpannedEntry<String, String>,
+        metadata: SpannedEntry<String, BTreeMap<String, SpannedEntry<String, String>>>,
+    }
+
+    const INPUT: &str = r#"
+[package]
+name = "toml"
+metadata.msrv = "1.85"
+
+[features]
+"default" = ["std"]
+"#;
+
+    let manifest = crate::from_str::<Manifest>(INPUT).unwrap();
+    let package = &manifest.package;
+    assert_data_eq!(&INPUT[package.key().span()], str!["package"]);
+    assert_data_eq!(&INPUT[package.value().span()], str!["[package]"]);
+    let name = &package.get_ref().name;
+    assert_data_eq!(&INPUT[name.key().span()], str!["name"]);
+    assert_data_eq!(&INPUT[name.value().span()], str![[r#""toml""#]]);
+    assert_eq!(name.get_ref(), "toml");
+    let metadata = &package.get_ref().metadata;
+    assert_data_eq!(&INPUT[metadata.key().span()], str!["metadata"]);
+    let msrv = &metadata.get_ref()["msrv"];
+    assert_data_eq!(&INPUT[msrv.key().span()], str!["msrv"]);
+    assert_data_eq!(&INPUT[msrv.value().span()], str![[r#""1.85""#]]);
+    let default = &manifest.features["default"];
+    assert_data_eq!(&INPUT[default.key().span()], str![[r#""default""#]]);
+    assert_eq!(default.key().get_ref(), "default");
+    assert_data_eq!(&INPUT[default.value().span()], str![[r#"["std"]"#]]);
+}
+
+#[test]
+fn spanned_entry_outside_table() {
+    #[derive(Debug, Deserialize)]
+    struct Foo {
+        #[allow(dead_code)]
+        foo: Vec<SpannedEntry<String, u32>>,
+    }
+
+    let error = crate::from_str::<Foo>("foo = [1]").unwrap_err();
+    assert_data_eq!(
+        error.to_string(),
+        str![[r#"
+TOML parse error at line 1, column 8
+  |
+1 | foo = [1]
+  |        ^
+value is not in a table entry
+note: in `foo`
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn source_id() {
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        name: Spanned<String>,
+        features: BTreeMap<Spanned<String>, Vec<Spanned<String>>>,
+        package: SpannedEntry<String, Package>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Package {
+        version: Spanned<String>,
+    }
+
+    const INPUT: &str = r#"
+name = "toml"
+features = { default = ["std"] }
+package = { version = "1.0.0" }
+"#;
+
+    let deserializer = toml_edit::de::Deserializer::parse(INPUT)
+        .unwrap()
+        .with_source_id(3);
+    let config = Config::deserialize(deserializer).unwrap();
+    assert_eq!(config.name.source_id(), Some(3));
+    let (key, values) = config.features.first_key_value().unwrap();
+    assert_eq!(key.source_id(), Some(3));
+    assert_eq!(values[0].source_id(), Some(3));
+    assert_eq!(config.package.key().source_id(), Some(3));
+    assert_eq!(config.package.value().source_id(), Some(3));
+    assert_eq!(config.package.get_ref().version.source_id(), Some(3));
+    assert_data_eq!(
+        &INPUT[config.package.get_ref().version.span()],
+        str![[r#""1.0.0""#]]
+    );
+
+    let config = crate::from_str::<Config>(INPUT).unwrap();
+    assert_eq!(config.name.source_id(), None);
+}
+
+#[test]
+fn source_id_error() {
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[allow(dead_code)]
+        port: u16,
+    }
+
+    let files = ["port = 80", "port = 'http'"];
+    let errors = files
+        .iter()
+        .enumerate()
+        .filter_map(|(id, input)| {
+            let deserializer = toml_edit::de::Deserializer::parse(*input)
+                .unwrap()
+                .with_source_id(id);
+            Config::deserialize(deserializer).err()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].source_id(), Some(1));
+    assert_eq!(errors[0].input(), Some(files[1]));
+
+    let error = toml_edit::de::Deserializer::parse("port = ").err().unwrap();
+    assert_eq!(error.source_id(), None);
+}
+
 #[test]
 fn deny_unknown_fields() {
     #[derive(Debug, serde::Deserialize)]
@@ -810,3 +966,47 @@ unknown field `fake`, expected `real`
         .raw()
     );
 }
+
+#[test]
+fn embedded() {
+    #[derive(Debug, serde::Deserialize)]
+    struct FrontMatter {
+        title: Spanned<String>,
+    }
+
+    const HOST: &str = "+++
+title = 'Hello'
++++
+# Hello
+";
+    let embedded = toml_edit::embed::plus_front_matter(HOST).unwrap();
+    let span = embedded.span().start()..embedded.span().end();
+    let doc = toml_edit::Document::parse_embedded(HOST, span).unwrap();
+    let front_matter = FrontMatter::deserialize(toml_edit::de::Deserializer::from(doc)).unwrap();
+    assert_data_eq!(&HOST[front_matter.title.span()], str!["'Hello'"]);
+}
+
+#[test]
+fn embedded_error() {
+    const HOST: &str = "# Hello
+
+```toml
+title = 
+```
+";
+    let embedded = toml_edit::embed::markdown_code_blocks(HOST).next().unwrap();
+    let span = embedded.span().start()..embedded.span().end();
+    let error = toml_edit::Document::parse_embedded(HOST, span).unwrap_err();
+    assert_data_eq!(
+        error.to_string(),
+        str![[r#"
+TOML parse error at line 4, column 9
+  |
+4 | title = 
+  |         ^
+string values must be quoted, expected literal string
+
+"#]]
+        .raw()
+    );
+}
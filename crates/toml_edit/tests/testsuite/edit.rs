@@ -91,6 +91,7 @@ DocumentMut {
             dotted: false,
             doc_position: None,
             span: None,
+            comments: [],
             items: {
                 Key {
                     key: "grandparent",
@@ -115,6 +116,7 @@ DocumentMut {
                         dotted: false,
                         doc_position: None,
                         span: None,
+                        comments: [],
                         items: {
                             Key {
                                 key: "parent",
@@ -141,6 +143,7 @@ DocumentMut {
                                         1,
                                     ),
                                     span: None,
+                                    comments: [],
                                     items: {
                                         Key {
                                             key: "key",
@@ -165,6 +168,7 @@ DocumentMut {
                                                 dotted: true,
                                                 doc_position: None,
                                                 span: None,
+                                                comments: [],
                                                 items: {
                                                     Key {
                                                         key: "child",
@@ -216,6 +220,8 @@ DocumentMut {
                                                                 },
                                                                 span: None,
                                                                 dotted: false,
+                                                                keep_inline: false,
+                                                                comments: [],
                                                                 items: {},
                                                             },
                                                         ),
@@ -245,6 +251,8 @@ DocumentMut {
                                                                 },
                                                                 span: None,
                                                                 dotted: false,
+                                                                keep_inline: false,
+                                                                comments: [],
                                                                 items: {
                                                                     Key {
                                                                         key: "inline",
@@ -271,6 +279,8 @@ DocumentMut {
                                                                                 },
                                                                                 span: None,
                                                                                 dotted: true,
+                                                                                keep_inline: false,
+                                                                                comments: [],
                                                                                 items: {
                                                                                     Key {
                                                                                         key: "child",
@@ -416,6 +426,7 @@ DocumentMut {
                         dotted: false,
                         doc_position: None,
                         span: None,
+                        comments: [],
                         items: {
                             Key {
                                 key: "table",
@@ -442,6 +453,7 @@ DocumentMut {
                                         2,
                                     ),
                                     span: None,
+                                    comments: [],
                                     items: {},
                                 },
                             ),
@@ -452,6 +464,7 @@ DocumentMut {
         },
     ),
     trailing: " # final comment\n",
+    bom: false,
 }
 
 "#]]
@@ -1899,3 +1912,147 @@ fn array_of_tables_replace_out_of_bounds() {
     let t = Table::new();
     array.replace(0, t);
 }
+
+#[test]
+fn byte_order_mark() {
+    let mut doc = "\u{FEFF}a = 1\n".parse::<DocumentMut>().unwrap();
+    assert!(doc.has_bom());
+    assert_eq!(doc.to_string(), "\u{FEFF}a = 1\n");
+
+    doc.set_bom(false);
+    assert_eq!(doc.to_string(), "a = 1\n");
+
+    let doc = "a = 1\n".parse::<DocumentMut>().unwrap();
+    assert!(!doc.has_bom());
+}
+
+#[test]
+fn test_format_layout() {
+    given(
+        r#"
+name = "toml"
+a = [ 1, 2 ]
+b.c = [
+  "encoding", "toml",
+    "no_std" ]
+d = { version = "1.0", features = [ "std" ] }
+e = { version = "2.0", features = ["std", "serde"], optional.yes = true }
+
+[[f]]
+g = [[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]
+"#,
+    )
+    .running(|root| {
+        root.fmt_layout(toml_edit::TomlLayout::new().with_max_width(30));
+    })
+    .produces_display(str![[r#"
+name = "toml"
+a = [1, 2]
+b.c = [
+    "encoding",
+    "toml",
+    "no_std",
+]
+d = { version = "1.0", features = ["std"] }
+e = { version = "2.0", features = ["std", "serde"], optional.yes = true }
+
+[[f]]
+g = [
+    [1, 2, 3],
+    [4, 5, 6],
+    [7, 8, 9],
+    [10, 11, 12],
+]
+
+"#]]);
+}
+
+#[test]
+fn test_format_layout_v1_1() {
+    given(
+        r#"
+name = "toml"
+a = [ 1, 2 ]
+b.c = [
+  "encoding", "toml",
+    "no_std" ]
+d = { version = "1.0", features = [ "std" ] }
+e = { version = "2.0", features = ["std", "serde"], optional.yes = true }
+
+[[f]]
+g = [[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]
+"#,
+    )
+    .running(|root| {
+        let layout = toml_edit::TomlLayout::new()
+            .with_max_width(30)
+            .with_version(toml_edit::TomlVersion::V1_1);
+        root.fmt_layout(layout);
+    })
+    .produces_display(str![[r#"
+name = "toml"
+a = [1, 2]
+b.c = [
+    "encoding",
+    "toml",
+    "no_std",
+]
+d = {
+    version = "1.0",
+    features = ["std"],
+}
+e = {
+    version = "2.0",
+    features = [
+        "std",
+        "serde",
+    ],
+    optional.yes = true,
+}
+
+[[f]]
+g = [
+    [1, 2, 3],
+    [4, 5, 6],
+    [7, 8, 9],
+    [10, 11, 12],
+]
+
+"#]]);
+}
+
+#[test]
+fn test_format_number_grouping() {
+    given(
+        r#"
+max_size = 10000000 # bytes
+min_size = -2048
+mask = 0xdeadbeef
+rate = 1234567.5
+small = 0.001
+"#,
+    )
+    .running(|root| {
+        let integer = toml_edit::TomlIntegerFormat::new().with_grouping(true);
+        let float = toml_edit::TomlFloatFormat::new().with_grouping(true);
+        for (_, item) in root.iter_mut() {
+            match item.as_value_mut() {
+                Some(Value::Integer(value)) if value.display_repr().starts_with("0x") => {
+                    value.fmt_with(integer.as_hex_lower());
+                }
+                Some(Value::Integer(value)) => value.fmt_with(integer),
+                Some(Value::Float(value)) => value.fmt_with(float),
+                _ => {}
+            }
+        }
+    })
+    .produces_display(str![[r#"
+
+max_size = 10_000_000 # bytes
+min_size = -2_048
+mask = 0xdead_beef
+rate = 1_234_567.5
+small = 0.001
+
+"#]]);
+}
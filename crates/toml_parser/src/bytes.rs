@@ -0,0 +1,112 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::ParseError;
+use crate::Span;
+
+/// Decode TOML encoded bytes into a `&str` for [`Source`][crate::Source]
+///
+/// - UTF-8 is passed through, leaving any byte order mark for the lexer to skip so [`Span`]s
+///   remain byte offsets into `input`
+/// - UTF-16 LE and BE are transcoded to UTF-8 when a byte order mark says so, dropping the
+///   byte order mark.
+///   [`Span`]s from parsing the result are relative to the transcoded `String`
+///
+/// On failure, [`ParseError::unexpected`] is the span of the invalid bytes within `input`.
+///
+/// # Example
+///
+/// ```
+/// let input = b"key = '\xFF'";
+/// let error = toml_parser::decode_bytes(input).unwrap_err();
+/// assert_eq!(error.description(), "invalid UTF-8");
+/// assert_eq!(error.unexpected(), Some(toml_parser::Span::new_unchecked(7, 8)));
+/// ```
+pub fn decode_bytes(input: &[u8]) -> Result<Cow<'_, str>, ParseError> {
+    if let Some(rest) = input.strip_prefix(UTF16_LE_BOM) {
+        decode_utf16(rest, UTF16_LE_BOM.len(), u16::from_le_bytes).map(Cow::Owned)
+    } else if let Some(rest) = input.strip_prefix(UTF16_BE_BOM) {
+        decode_utf16(rest, UTF16_BE_BOM.len(), u16::from_be_bytes).map(Cow::Owned)
+    } else {
+        core::str::from_utf8(input)
+            .map(Cow::Borrowed)
+            .map_err(|err| {
+                let start = err.valid_up_to();
+                let end = err
+                    .error_len()
+                    .map(|len| start + len)
+                    .unwrap_or(input.len());
+                ParseError::new("invalid UTF-8").with_unexpected(Span::new_unchecked(start, end))
+            })
+    }
+}
+
+fn decode_utf16(
+    input: &[u8],
+    offset: usize,
+    from_bytes: fn([u8; 2]) -> u16,
+) -> Result<String, ParseError> {
+    let units = input.chunks_exact(2);
+    if !units.remainder().is_empty() {
+        let start = offset + input.len() - units.remainder().len();
+        return Err(ParseError::new("incomplete UTF-16 code unit")
+            .with_unexpected(Span::new_unchecked(start, offset + input.len())));
+    }
+    let units = units.map(|unit| from_bytes([unit[0], unit[1]]));
+
+    let mut output = String::with_capacity(input.len() / 2);
+    let mut start = offset;
+    for c in char::decode_utf16(units) {
+        let Ok(c) = c else {
+            return Err(ParseError::new("unpaired UTF-16 surrogate")
+                .with_unexpected(Span::new_unchecked(start, start + 2)));
+        };
+        start += c.len_utf16() * 2;
+        output.push(c);
+    }
+    Ok(output)
+}
+
+const UTF16_LE_BOM: &[u8] = b"\xFF\xFE";
+const UTF16_BE_BOM: &[u8] = b"\xFE\xFF";
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn utf8() {
+        assert_eq!(decode_bytes(b"a = 1"), Ok(Cow::Borrowed("a = 1")));
+        assert_eq!(
+            decode_bytes(b"\xEF\xBB\xBFa = 1"),
+            Ok(Cow::Borrowed("\u{FEFF}a = 1"))
+        );
+
+        let err = decode_bytes(b"a = '\xE2\x82'\n").unwrap_err();
+        assert_eq!(err.unexpected(), Some(Span::new_unchecked(5, 7)));
+        let err = decode_bytes(b"a = '\xE2\x82").unwrap_err();
+        assert_eq!(err.unexpected(), Some(Span::new_unchecked(5, 7)));
+    }
+
+    #[test]
+    fn utf16() {
+        let le = b"\xFF\xFEa\0 \0=\0 \0'\0=\xD8\x00\xDE'\0";
+        assert_eq!(
+            decode_bytes(le),
+            Ok(Cow::Owned("a = '\u{1F600}'".to_owned()))
+        );
+        let be = b"\xFE\xFF\0a\0 \0=\0 \0'\xD8\x3D\xDE\x00\0'";
+        assert_eq!(
+            decode_bytes(be),
+            Ok(Cow::Owned("a = '\u{1F600}'".to_owned()))
+        );
+
+        let err = decode_bytes(b"\xFF\xFEa\0\x3D\xD8 \0").unwrap_err();
+        assert_eq!(err.description(), "unpaired UTF-16 surrogate");
+        assert_eq!(err.unexpected(), Some(Span::new_unchecked(4, 6)));
+        let err = decode_bytes(b"\xFF\xFEa\0 ").unwrap_err();
+        assert_eq!(err.description(), "incomplete UTF-16 code unit");
+        assert_eq!(err.unexpected(), Some(Span::new_unchecked(4, 5)));
+    }
+}
@@ -5,6 +5,7 @@ use winnow::stream::Stream as _;
 
 use crate::ErrorSink;
 use crate::Expected;
+use crate::Fix;
 use crate::ParseError;
 use crate::Raw;
 use crate::Span;
@@ -425,6 +426,70 @@ fn decode_datetime_or_float_or_integer<'i>(
     }
 }
 
+/// Suggest zero-padding date-time fields that are missing leading zeroes, like `1979-5-27`
+///
+/// Date-times are validated by the caller which should attach this to its error.
+///
+/// ```abnf
+/// date-fullyear  = 4DIGIT
+/// date-month     = 2DIGIT  ; 01-12
+/// date-mday      = 2DIGIT  ; 01-28, 01-29, 01-30, 01-31 based on month/year
+/// time-hour      = 2DIGIT  ; 00-23
+/// time-minute    = 2DIGIT  ; 00-59
+/// time-second    = 2DIGIT  ; 00-58, 00-59, 00-60 based on leap second rules
+/// time-secfrac   = "." 1*DIGIT
+/// ```
+#[allow(unused_qualifications)]
+pub(crate) fn datetime_padding_fix(raw: Raw<'_>) -> Option<Fix> {
+    const PADDING: &str = "000";
+
+    let bytes = raw.as_bytes();
+    let mut first = None;
+    let mut count = 0;
+    #[cfg(feature = "alloc")]
+    let mut padded = alloc::string::String::with_capacity(bytes.len() + PADDING.len());
+    #[cfg(feature = "alloc")]
+    let mut copied = 0;
+    let mut start = 0;
+    while start < bytes.len() {
+        let len = bytes[start..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or(bytes.len() - start);
+        if len == 0 {
+            start += 1;
+            continue;
+        }
+        let end = start + len;
+        let is_year = start == 0 && bytes.get(end) == Some(&b'-');
+        let is_secfrac = start != 0 && bytes[start - 1] == b'.';
+        let width = if is_year { 4 } else { 2 };
+        if !is_secfrac && len < width {
+            let padding = &PADDING[..width - len];
+            first.get_or_insert(Fix::insert(start, padding));
+            count += 1;
+            #[cfg(feature = "alloc")]
+            {
+                padded.push_str(&raw.as_str()[copied..start]);
+                padded.push_str(padding);
+                copied = start;
+            }
+        }
+        start = end;
+    }
+
+    match count {
+        0 | 1 => first,
+        #[cfg(feature = "alloc")]
+        _ => {
+            padded.push_str(&raw.as_str()[copied..]);
+            Some(Fix::new(Span::new_unchecked(0, bytes.len()), padded))
+        }
+        #[cfg(not(feature = "alloc"))]
+        _ => None,
+    }
+}
+
 /// ```abnf
 /// ;; Float
 ///
@@ -1,3 +1,5 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::ops::RangeInclusive;
 
 use winnow::stream::ContainsToken as _;
@@ -6,7 +8,9 @@ use winnow::stream::Stream as _;
 
 use crate::ErrorSink;
 use crate::Expected;
+use crate::Fix;
 use crate::ParseError;
+use crate::ParseErrorKind;
 use crate::Raw;
 use crate::Span;
 use crate::decoder::StringBuilder;
@@ -342,7 +346,8 @@ fn escape_seq_char(stream: &mut &str, raw: Raw<'_>, error: &mut dyn ErrorSink) -
             ParseError::new("missing escaped value")
                 .with_context(Span::new_unchecked(0, raw.len()))
                 .with_expected(EXPECTED_ESCAPES)
-                .with_unexpected(Span::new_unchecked(offset, offset)),
+                .with_unexpected(Span::new_unchecked(offset, offset))
+                .with_kind(ParseErrorKind::InvalidEscape),
         );
         return '\\';
     };
@@ -365,7 +370,9 @@ fn escape_seq_char(stream: &mut &str, raw: Raw<'_>, error: &mut dyn ErrorSink) -
                 ParseError::new("missing escaped value")
                     .with_context(Span::new_unchecked(0, raw.len()))
                     .with_expected(EXPECTED_ESCAPES)
-                    .with_unexpected(Span::new_unchecked(offset, offset)),
+                    .with_unexpected(Span::new_unchecked(offset, offset))
+                    .with_fix(Fix::insert(offset, "\\"))
+                    .with_kind(ParseErrorKind::InvalidEscape),
             );
             '\\'
         }
@@ -394,7 +401,8 @@ fn hexescape(
             ParseError::new("too few unicode value digits")
                 .with_context(Span::new_unchecked(0, raw.len()))
                 .with_expected(&[Expected::Description("unicode hexadecimal value")])
-                .with_unexpected(Span::new_unchecked(offset, offset)),
+                .with_unexpected(Span::new_unchecked(offset, offset))
+                .with_kind(ParseErrorKind::InvalidEscape),
         );
         return '�';
     }
@@ -405,7 +413,8 @@ fn hexescape(
             ParseError::new("invalid value")
                 .with_context(Span::new_unchecked(0, raw.len()))
                 .with_expected(&[Expected::Description("unicode hexadecimal value")])
-                .with_unexpected(Span::new_unchecked(offset, offset)),
+                .with_unexpected(Span::new_unchecked(offset, offset))
+                .with_kind(ParseErrorKind::InvalidEscape),
         );
         return '�';
     };
@@ -702,6 +711,25 @@ pub(crate) fn decode_unquoted_key<'i>(
         );
     }
 
+    // Only the first error gets the fix, built lazily to keep valid keys allocation-free
+    let mut needs_fix = true;
+    let mut report = |start: usize, end: usize| {
+        let mut err = ParseError::new("invalid unquoted key")
+            .with_context(Span::new_unchecked(0, s.len()))
+            .with_expected(&[
+                Expected::Description("letters"),
+                Expected::Description("numbers"),
+                Expected::Literal("-"),
+                Expected::Literal("_"),
+            ])
+            .with_unexpected(Span::new_unchecked(start, end));
+        if core::mem::take(&mut needs_fix) {
+            if let Some(fix) = unquoted_key_fix(s) {
+                err = err.with_fix(fix);
+            }
+        }
+        error.report_error(err);
+    };
     let mut span = None;
     for (i, _b) in s
         .as_bytes()
@@ -713,17 +741,7 @@ pub(crate) fn decode_unquoted_key<'i>(
             if i == end {
                 span = Some((start, i + 1));
             } else {
-                error.report_error(
-                    ParseError::new("invalid unquoted key")
-                        .with_context(Span::new_unchecked(0, s.len()))
-                        .with_expected(&[
-                            Expected::Description("letters"),
-                            Expected::Description("numbers"),
-                            Expected::Literal("-"),
-                            Expected::Literal("_"),
-                        ])
-                        .with_unexpected(Span::new_unchecked(start, end)),
-                );
+                report(start, end);
                 span = Some((i, i + 1));
             }
         } else {
@@ -731,17 +749,7 @@ pub(crate) fn decode_unquoted_key<'i>(
         }
     }
     if let Some((start, end)) = span {
-        error.report_error(
-            ParseError::new("invalid unquoted key")
-                .with_context(Span::new_unchecked(0, s.len()))
-                .with_expected(&[
-                    Expected::Description("letters"),
-                    Expected::Description("numbers"),
-                    Expected::Literal("-"),
-                    Expected::Literal("_"),
-                ])
-                .with_unexpected(Span::new_unchecked(start, end)),
-        );
+        report(start, end);
     }
 
     if !output.push_str(s) {
@@ -751,6 +759,50 @@ pub(crate) fn decode_unquoted_key<'i>(
     }
 }
 
+/// Suggest how to turn an invalid unquoted key into a valid one
+///
+/// A trailing `:` is likely a key-value separator from another format, otherwise the key is quoted
+fn unquoted_key_fix(s: &str) -> Option<Fix> {
+    if let Some(key) = s.strip_suffix(':') {
+        if !key.is_empty()
+            && key
+                .as_bytes()
+                .iter()
+                .all(|b| UNQUOTED_CHAR.contains_token(b))
+        {
+            return Some(Fix::new(Span::new_unchecked(key.len(), s.len()), " ="));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut quoted = String::with_capacity(s.len() + 2);
+        quoted.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => quoted.push_str("\\\""),
+                '\\' => quoted.push_str("\\\\"),
+                '\u{8}' => quoted.push_str("\\b"),
+                '\t' => quoted.push_str("\\t"),
+                '\n' => quoted.push_str("\\n"),
+                '\u{c}' => quoted.push_str("\\f"),
+                '\r' => quoted.push_str("\\r"),
+                c if c.is_ascii_control() => {
+                    use core::fmt::Write as _;
+                    let _ = write!(quoted, "\\u{:04X}", c as u32);
+                }
+                c => quoted.push(c),
+            }
+        }
+        quoted.push('"');
+        Some(Fix::new(Span::new_unchecked(0, s.len()), quoted))
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        None
+    }
+}
+
 /// ```abnf
 /// unquoted-key = 1*( ALPHA / DIGIT / %x2D / %x5F ) ; A-Z / a-z / 0-9 / - / _
 /// ```
@@ -951,6 +1003,8 @@ trimmed in raw strings.
         unexpected: Some(
             9..9,
         ),
+        fix: None,
+        kind: InvalidEscape,
     },
 ]
 
@@ -985,6 +1039,8 @@ trailing
         unexpected: Some(
             8..9,
         ),
+        fix: None,
+        kind: Syntax,
     },
 ]
 
@@ -1123,6 +1179,8 @@ The quick brown \
         unexpected: Some(
             7..7,
         ),
+        fix: None,
+        kind: Syntax,
     },
 ]
 
@@ -1176,6 +1234,8 @@ The quick brown \
         unexpected: Some(
             6..6,
         ),
+        fix: None,
+        kind: InvalidEscape,
     },
 ]
 
@@ -1282,6 +1342,8 @@ The quick brown \
         unexpected: Some(
             0..0,
         ),
+        fix: None,
+        kind: Syntax,
     },
 ]
 
@@ -0,0 +1,537 @@
+use alloc::string::String;
+use alloc::string::ToString as _;
+use alloc::vec::Vec;
+
+use crate::Fix;
+use crate::ParseError;
+use crate::ParseErrorKind;
+use crate::Span;
+
+/// A message about a TOML document, pointing to the relevant parts of it
+///
+/// When displayed, this renders a snippet of the source for each [`Label`], followed by the message
+/// and any notes and help:
+///
+/// ```text
+/// TOML parse error at line 3, column 1
+///   |
+/// 1 | a = 1
+///   | - first defined here
+/// 2 | b = 2
+/// 3 | a = 3
+///   | ^
+/// duplicate key
+/// help: remove one of the definitions
+/// ```
+///
+/// For rendering with ANSI colors, see [`Diagnostic::styled`].
+///
+/// # Example
+///
+/// ```
+/// use toml_parser::Diagnostic;
+/// use toml_parser::Label;
+/// use toml_parser::Span;
+///
+/// let input = "a = 1\nb = 2\na = 3\n";
+/// let diagnostic = Diagnostic::new(input, "duplicate key")
+///     .with_label(Label::primary(Span::new_unchecked(12, 13)))
+///     .with_label(Label::secondary(Span::new_unchecked(0, 1)).with_message("first defined here"))
+///     .with_help("remove one of the definitions");
+/// assert_eq!(
+///     diagnostic.to_string(),
+///     "\
+/// TOML parse error at line 3, column 1
+///   |
+/// 1 | a = 1
+///   | - first defined here
+/// 2 | b = 2
+/// 3 | a = 3
+///   | ^
+/// duplicate key
+/// help: remove one of the definitions
+/// "
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic<'i> {
+    input: &'i str,
+    message: String,
+    labels: Vec<Label>,
+    notes: Vec<String>,
+    help: Vec<String>,
+}
+
+impl<'i> Diagnostic<'i> {
+    /// Report `message` for the TOML encoded `input`
+    pub fn new(input: &'i str, message: impl Into<String>) -> Self {
+        Self {
+            input,
+            message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: Vec::new(),
+        }
+    }
+
+    /// Point to a part of [`Diagnostic::input`]
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    /// Add context for the message
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Suggest how to resolve the problem
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help.push(help.into());
+        self
+    }
+
+    /// Point to the earlier definition and suggest the fix for `error`, from parsing
+    /// [`Diagnostic::input`]
+    ///
+    /// The primary label, usually [`ParseError::unexpected`], is left to the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use toml_parser::Diagnostic;
+    /// use toml_parser::Label;
+    /// use toml_parser::ParseError;
+    /// use toml_parser::ParseErrorKind;
+    /// use toml_parser::Span;
+    ///
+    /// let input = "a = 1\na = 2\n";
+    /// let error = ParseError::new("duplicate key")
+    ///     .with_unexpected(Span::new_unchecked(6, 7))
+    ///     .with_context(Span::new_unchecked(0, 1))
+    ///     .with_kind(ParseErrorKind::DuplicateKey);
+    /// let diagnostic = Diagnostic::new(input, error.description())
+    ///     .with_label(Label::primary(error.unexpected().unwrap()))
+    ///     .with_parse_error(&error);
+    /// assert_eq!(diagnostic.labels()[1].message(), Some("first defined here"));
+    /// ```
+    pub fn with_parse_error(mut self, error: &ParseError) -> Self {
+        let context = match error.kind() {
+            ParseErrorKind::DuplicateKey => Some("first defined here"),
+            ParseErrorKind::TableRedefinition => Some("originally defined here"),
+            _ => None,
+        };
+        if let (Some(span), Some(message)) = (error.context(), context) {
+            self = self.with_label(Label::secondary(span).with_message(message));
+        }
+        if let Some(fix) = error.fix() {
+            let help = fix_help(self.input, fix);
+            self = self.with_help(help);
+        }
+        self
+    }
+
+    /// The TOML encoded `&str` that [`Diagnostic::labels`] point into
+    pub fn input(&self) -> &'i str {
+        self.input
+    }
+
+    /// What went wrong
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The parts of [`Diagnostic::input`] that are relevant
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// Context for the message
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// Suggestions for how to resolve the problem
+    pub fn help(&self) -> &[String] {
+        &self.help
+    }
+
+    /// Render with ANSI colors, like for a terminal
+    ///
+    /// To strip the colors when they are not supported, write this with `anstream`.
+    #[cfg(feature = "color")]
+    pub fn styled(&self) -> impl core::fmt::Display + '_ {
+        Rendered {
+            diagnostic: self,
+            styled: true,
+        }
+    }
+
+    /// Convert to an error for rendering [`Diagnostic::input`] as the file `file_id`
+    ///
+    /// Help is converted to notes starting with `help: `.
+    #[cfg(feature = "codespan-reporting")]
+    pub fn to_codespan<F: Clone>(
+        &self,
+        file_id: F,
+    ) -> codespan_reporting::diagnostic::Diagnostic<F> {
+        use codespan_reporting::diagnostic;
+
+        let labels = self.labels.iter().map(|label| {
+            let range = label.span.start()..label.span.end();
+            let style = if label.primary {
+                diagnostic::LabelStyle::Primary
+            } else {
+                diagnostic::LabelStyle::Secondary
+            };
+            let codespan = diagnostic::Label::new(style, file_id.clone(), range);
+            match &label.message {
+                Some(message) => codespan.with_message(message),
+                None => codespan,
+            }
+        });
+        let notes = self
+            .notes
+            .iter()
+            .cloned()
+            .chain(self.help.iter().map(|help| alloc::format!("help: {help}")));
+        diagnostic::Diagnostic::error()
+            .with_message(&self.message)
+            .with_labels_iter(labels)
+            .with_notes_iter(notes)
+    }
+}
+
+impl core::fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Rendered {
+            diagnostic: self,
+            styled: false,
+        }
+        .fmt(f)
+    }
+}
+
+/// A part of [`Diagnostic::input`] that is relevant to the [`Diagnostic`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Label {
+    span: Span,
+    message: Option<String>,
+    primary: bool,
+}
+
+impl Label {
+    /// Where the problem is
+    ///
+    /// The position of the first primary label is reported in the [`Diagnostic`]'s header.
+    pub fn primary(span: Span) -> Self {
+        Self {
+            span,
+            message: None,
+            primary: true,
+        }
+    }
+
+    /// Related to the problem
+    pub fn secondary(span: Span) -> Self {
+        Self {
+            span,
+            message: None,
+            primary: false,
+        }
+    }
+
+    /// Describe this part of the input
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// The byte offsets into [`Diagnostic::input`]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// What this part of the input is
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Whether this is where the problem is
+    pub fn is_primary(&self) -> bool {
+        self.primary
+    }
+}
+
+/// Describe applying `fix` to `input`
+fn fix_help(input: &str, fix: &Fix) -> String {
+    let span = fix.span();
+    let replacement = fix.replacement();
+    match input.get(span.start()..span.end()) {
+        Some("") | None => alloc::format!("insert `{replacement}`"),
+        Some(original) if replacement.is_empty() => alloc::format!("remove `{original}`"),
+        Some(original) => alloc::format!("replace `{original}` with `{replacement}`"),
+    }
+}
+
+struct Rendered<'d, 'i> {
+    diagnostic: &'d Diagnostic<'i>,
+    styled: bool,
+}
+
+impl Rendered<'_, '_> {
+    fn paint(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        role: Role,
+        text: impl core::fmt::Display,
+    ) -> core::fmt::Result {
+        #[cfg(feature = "color")]
+        if self.styled {
+            let style = role.style();
+            return write!(f, "{style}{text}{style:#}");
+        }
+        #[cfg(not(feature = "color"))]
+        let _ = (self.styled, role);
+        write!(f, "{text}")
+    }
+}
+
+impl core::fmt::Display for Rendered<'_, '_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let input = self.diagnostic.input;
+
+        let mut labels = self
+            .diagnostic
+            .labels
+            .iter()
+            .map(|label| {
+                let (line, column) = translate_position(input.as_bytes(), label.span.start());
+                (line, column, label)
+            })
+            .collect::<Vec<_>>();
+        let header = labels
+            .iter()
+            .find(|(_, _, label)| label.primary)
+            .or_else(|| labels.first());
+        if let Some((line, column, _)) = header {
+            let line_num = line + 1;
+            let col_num = column + 1;
+            self.paint(
+                f,
+                Role::Header,
+                format_args!("TOML parse error at line {line_num}, column {col_num}"),
+            )?;
+            writeln!(f)?;
+        }
+        labels.sort_by_key(|(line, column, _)| (*line, *column));
+
+        if let Some((last_line, _, _)) = labels.last() {
+            let gutter = (last_line + 1).to_string().len();
+            let lines = input.split('\n').collect::<Vec<_>>();
+
+            //   |
+            self.paint(f, Role::Gutter, format_args!("{:gutter$} |", ""))?;
+            writeln!(f)?;
+
+            let mut prev_line = None;
+            for (line, column, label) in &labels {
+                let line = *line;
+                let column = *column;
+                let content = lines.get(line).copied().unwrap_or_default();
+                if prev_line != Some(line) {
+                    if let Some(prev_line) = prev_line {
+                        // Show the lines between nearby labels, eliding the rest
+                        if line - prev_line <= CONTEXT_LINES + 1 {
+                            for (between, content) in
+                                lines.iter().enumerate().take(line).skip(prev_line + 1)
+                            {
+                                self.render_line(f, gutter, between, content)?;
+                            }
+                        } else {
+                            self.paint(f, Role::Gutter, "...")?;
+                            writeln!(f)?;
+                        }
+                    }
+                    // 1 | 00:32:00.a999999
+                    self.render_line(f, gutter, line, content)?;
+                    prev_line = Some(line);
+                }
+
+                //   |          ^
+                let (role, marker) = if label.primary {
+                    (Role::Primary, "^")
+                } else {
+                    (Role::Secondary, "-")
+                };
+                let highlight_len = label.span.len();
+                // Allow highlight to go one past the line
+                let highlight_len = highlight_len.min(content.len().saturating_sub(column));
+                // The span will be empty at eof, so we need to make sure we always print at least
+                // one marker
+                let highlight_len = highlight_len.max(1);
+                self.paint(f, Role::Gutter, format_args!("{:gutter$} |", ""))?;
+                write!(f, "{:column$} ", "")?;
+                self.paint(f, role, marker.repeat(highlight_len))?;
+                if let Some(message) = &label.message {
+                    write!(f, " ")?;
+                    self.paint(f, role, message)?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        self.paint(f, Role::Message, &self.diagnostic.message)?;
+        writeln!(f)?;
+        for note in &self.diagnostic.notes {
+            self.paint(f, Role::Note, "note")?;
+            writeln!(f, ": {note}")?;
+        }
+        for help in &self.diagnostic.help {
+            self.paint(f, Role::Help, "help")?;
+            writeln!(f, ": {help}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Rendered<'_, '_> {
+    fn render_line(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        gutter: usize,
+        line: usize,
+        content: &str,
+    ) -> core::fmt::Result {
+        let line_num = line + 1;
+        self.paint(f, Role::Gutter, format_args!("{line_num:>gutter$} |"))?;
+        writeln!(f, " {content}")
+    }
+}
+
+/// The most lines to show between labels before eliding them
+const CONTEXT_LINES: usize = 2;
+
+#[derive(Copy, Clone)]
+enum Role {
+    Header,
+    Gutter,
+    Primary,
+    Secondary,
+    Message,
+    Note,
+    Help,
+}
+
+#[cfg(feature = "color")]
+impl Role {
+    fn style(self) -> anstyle::Style {
+        match self {
+            Self::Header | Self::Message | Self::Note => anstyle::Style::new().bold(),
+            Self::Gutter | Self::Secondary => anstyle::AnsiColor::Blue.on_default().bold(),
+            Self::Primary => anstyle::AnsiColor::Red.on_default().bold(),
+            Self::Help => anstyle::AnsiColor::Cyan.on_default().bold(),
+        }
+    }
+}
+
+/// Convert a byte `index` into a 0-based line and `char` column
+fn translate_position(input: &[u8], index: usize) -> (usize, usize) {
+    if input.is_empty() {
+        return (0, index);
+    }
+
+    let safe_index = index.min(input.len() - 1);
+    let column_offset = index - safe_index;
+    let index = safe_index;
+
+    let nl = input[0..index]
+        .iter()
+        .rev()
+        .enumerate()
+        .find(|(_, b)| **b == b'\n')
+        .map(|(nl, _)| index - nl - 1);
+    let line_start = match nl {
+        Some(nl) => nl + 1,
+        None => 0,
+    };
+    let line = input[0..line_start].iter().filter(|b| **b == b'\n').count();
+
+    let column = core::str::from_utf8(&input[line_start..=index])
+        .map(|s| s.chars().count() - 1)
+        .unwrap_or_else(|_| index - line_start);
+    let column = column + column_offset;
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod test_translate_position {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let input = b"";
+        let index = 0;
+        let position = translate_position(&input[..], index);
+        assert_eq!(position, (0, 0));
+    }
+
+    #[test]
+    fn start() {
+        let input = b"Hello";
+        let index = 0;
+        let position = translate_position(&input[..], index);
+        assert_eq!(position, (0, 0));
+    }
+
+    #[test]
+    fn end() {
+        let input = b"Hello";
+        let index = input.len() - 1;
+        let position = translate_position(&input[..], index);
+        assert_eq!(position, (0, input.len() - 1));
+    }
+
+    #[test]
+    fn after() {
+        let input = b"Hello";
+        let index = input.len();
+        let position = translate_position(&input[..], index);
+        assert_eq!(position, (0, input.len()));
+    }
+
+    #[test]
+    fn first_line() {
+        let input = b"Hello\nWorld\n";
+        let index = 2;
+        let position = translate_position(&input[..], index);
+        assert_eq!(position, (0, 2));
+    }
+
+    #[test]
+    fn end_of_line() {
+        let input = b"Hello\nWorld\n";
+        let index = 5;
+        let position = translate_position(&input[..], index);
+        assert_eq!(position, (0, 5));
+    }
+
+    #[test]
+    fn start_of_second_line() {
+        let input = b"Hello\nWorld\n";
+        let index = 6;
+        let position = translate_position(&input[..], index);
+        assert_eq!(position, (1, 0));
+    }
+
+    #[test]
+    fn second_line() {
+        let input = b"Hello\nWorld\n";
+        let index = 8;
+        let position = translate_position(&input[..], index);
+        assert_eq!(position, (1, 2));
+    }
+}
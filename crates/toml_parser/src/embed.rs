@@ -0,0 +1,254 @@
+//! Locate TOML embedded in other documents
+//!
+//! Supported hosts:
+//! - `+++` front matter, like in Markdown for static site generators, see [`plus_front_matter`]
+//! - `---cargo` front matter in Rust scripts, see [`cargo_front_matter`]
+//! - ```` ```toml ```` fenced code blocks in Markdown, see [`markdown_code_blocks`]
+//!
+//! [`Embedded::source`] reports [`Span`]s relative to the host document.
+//!
+//! # Example
+//!
+//! ```
+//! let host = "+++\ntitle = 'Hello'\n+++\n# Hello\n";
+//! let embedded = toml_parser::embed::plus_front_matter(host).unwrap();
+//! assert_eq!(embedded.input(), "title = 'Hello'\n");
+//!
+//! let source = embedded.source();
+//! let key = source.lex().next().unwrap();
+//! assert_eq!(&host[key.span().start()..key.span().end()], "title");
+//! ```
+
+use crate::Source;
+use crate::Span;
+
+/// TOML embedded within a host document
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Embedded<'i> {
+    input: &'i str,
+    span: Span,
+}
+
+impl<'i> Embedded<'i> {
+    /// Select the TOML at `span` within `host`
+    ///
+    /// Returns `None` if `span` is out of bounds or not on `char` boundaries.
+    pub fn new(host: &'i str, span: Span) -> Option<Self> {
+        let input = host.get(span.start()..span.end())?;
+        Some(Self { input, span })
+    }
+
+    /// The TOML encoded `&str`
+    pub fn input(&self) -> &'i str {
+        self.input
+    }
+
+    /// Location of the TOML within the host document
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Parse the TOML, reporting [`Span`]s relative to the host document
+    pub fn source(&self) -> Source<'i> {
+        Source::new(self.input).with_offset(self.span.start())
+    }
+}
+
+/// Extract TOML front matter delimited by `+++` lines
+///
+/// ```toml
+/// +++
+/// title = "Hello"
+/// +++
+/// ```
+///
+/// The opening delimiter must be the first line of `host`.
+pub fn plus_front_matter(host: &str) -> Option<Embedded<'_>> {
+    let mut lines = Lines::new(host, skip_bom(host));
+    let open = lines.next()?;
+    if open.content.trim_end_matches(is_ws) != PLUS_DELIM {
+        return None;
+    }
+    let close = lines.find(|line| line.content.trim_end_matches(is_ws) == PLUS_DELIM)?;
+    Embedded::new(host, Span::new_unchecked(open.next, close.start))
+}
+
+/// Extract the front matter of a Rust script, delimited by `---` lines
+///
+/// ```rust,ignore
+/// #!/usr/bin/env -S cargo +nightly -Zscript
+/// ---cargo
+/// [dependencies]
+/// clap = "4"
+/// ---
+///
+/// fn main() {}
+/// ```
+///
+/// The front matter may be preceded by a shebang and blank lines.
+/// The opening delimiter is three or more dashes with an optional `cargo` info string, and the
+/// closing delimiter must have the same number of dashes.
+pub fn cargo_front_matter(host: &str) -> Option<Embedded<'_>> {
+    let mut lines = Lines::new(host, skip_bom(host)).peekable();
+    if lines.peek().is_some_and(|line| is_shebang(line.content)) {
+        lines.next();
+    }
+    let open = lines.find(|line| !line.content.trim_matches(is_ws).is_empty())?;
+    let dashes = open.content.len() - open.content.trim_start_matches('-').len();
+    if dashes < DASH_DELIM_MIN {
+        return None;
+    }
+    let info = open.content[dashes..].trim_matches(is_ws);
+    if !(info.is_empty() || info == CARGO_INFO) {
+        return None;
+    }
+    let delim = &open.content[..dashes];
+    let close = lines.find(|line| line.content.trim_end_matches(is_ws) == delim)?;
+    Embedded::new(host, Span::new_unchecked(open.next, close.start))
+}
+
+/// Iterate over the ```` ```toml ```` fenced code blocks in Markdown
+///
+/// Both backtick and tilde fences are supported.
+/// Like in [CommonMark](https://spec.commonmark.org/0.31.2/#fenced-code-blocks), an unclosed code
+/// block runs to the end of `host`.
+///
+/// <div class="warning">
+///
+/// Indentation of the fence is not stripped from the content.
+///
+/// </div>
+pub fn markdown_code_blocks(host: &str) -> MarkdownCodeBlocks<'_> {
+    MarkdownCodeBlocks {
+        host,
+        lines: Lines::new(host, 0),
+    }
+}
+
+/// Iterator over ```` ```toml ```` fenced code blocks, see [`markdown_code_blocks`]
+#[derive(Clone, Debug)]
+pub struct MarkdownCodeBlocks<'i> {
+    host: &'i str,
+    lines: Lines<'i>,
+}
+
+impl<'i> Iterator for MarkdownCodeBlocks<'i> {
+    type Item = Embedded<'i>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let open = self.lines.next()?;
+            let Some(fence) = Fence::open(open.content) else {
+                continue;
+            };
+            let end = self
+                .lines
+                .find(|line| fence.is_close(line.content))
+                .map(|line| line.start)
+                .unwrap_or(self.host.len());
+            if fence.info.split_whitespace().next() == Some(TOML_INFO) {
+                return Embedded::new(self.host, Span::new_unchecked(open.next, end));
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Fence<'i> {
+    marker: char,
+    len: usize,
+    info: &'i str,
+}
+
+impl<'i> Fence<'i> {
+    fn open(line: &'i str) -> Option<Self> {
+        let fence = strip_fence_indent(line)?;
+        let marker = fence.chars().next().filter(|c| FENCE_MARKERS.contains(c))?;
+        let len = fence.len() - fence.trim_start_matches(marker).len();
+        if len < FENCE_MIN {
+            return None;
+        }
+        let info = fence[len..].trim_matches(is_ws);
+        if marker == '`' && info.contains('`') {
+            return None;
+        }
+        Some(Self { marker, len, info })
+    }
+
+    fn is_close(&self, line: &str) -> bool {
+        let Some(fence) = strip_fence_indent(line) else {
+            return false;
+        };
+        let rest = fence.trim_start_matches(self.marker);
+        self.len <= fence.len() - rest.len() && rest.trim_matches(is_ws).is_empty()
+    }
+}
+
+fn strip_fence_indent(line: &str) -> Option<&str> {
+    let fence = line.trim_start_matches(' ');
+    (line.len() - fence.len() <= FENCE_INDENT_MAX).then_some(fence)
+}
+
+#[derive(Clone, Debug)]
+struct Lines<'i> {
+    input: &'i str,
+    offset: usize,
+}
+
+impl<'i> Lines<'i> {
+    fn new(input: &'i str, offset: usize) -> Self {
+        Self { input, offset }
+    }
+}
+
+impl<'i> Iterator for Lines<'i> {
+    type Item = Line<'i>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.offset;
+        let rest = self.input.get(start..).filter(|rest| !rest.is_empty())?;
+        let (content, next) = match rest.find('\n') {
+            Some(newline) => (&rest[..newline], start + newline + 1),
+            None => (rest, self.input.len()),
+        };
+        let content = content.strip_suffix('\r').unwrap_or(content);
+        self.offset = next;
+        Some(Line {
+            start,
+            content,
+            next,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Line<'i> {
+    /// Offset of the start of the line
+    start: usize,
+    /// Line without its line ending
+    content: &'i str,
+    /// Offset of the start of the next line
+    next: usize,
+}
+
+fn skip_bom(host: &str) -> usize {
+    if host.starts_with(BOM) { BOM.len() } else { 0 }
+}
+
+fn is_shebang(line: &str) -> bool {
+    line.strip_prefix("#!")
+        .is_some_and(|rest| !rest.trim_start().starts_with('['))
+}
+
+fn is_ws(c: char) -> bool {
+    matches!(c, ' ' | '\t')
+}
+
+const BOM: &str = "\u{FEFF}";
+const PLUS_DELIM: &str = "+++";
+const DASH_DELIM_MIN: usize = 3;
+const CARGO_INFO: &str = "cargo";
+const TOML_INFO: &str = "toml";
+const FENCE_MARKERS: [char; 2] = ['`', '~'];
+const FENCE_MIN: usize = 3;
+const FENCE_INDENT_MAX: usize = 3;
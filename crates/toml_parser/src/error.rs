@@ -31,13 +31,15 @@ impl ErrorSink for alloc::vec::Vec<ParseError> {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 #[non_exhaustive]
 pub struct ParseError {
     context: Option<Span>,
     description: ErrorStr,
     expected: Option<&'static [Expected]>,
     unexpected: Option<Span>,
+    fix: Option<Fix>,
+    kind: ParseErrorKind,
 }
 
 impl ParseError {
@@ -47,6 +49,8 @@ impl ParseError {
             description: description.into(),
             expected: None,
             unexpected: None,
+            fix: None,
+            kind: ParseErrorKind::Syntax,
         }
     }
 
@@ -65,6 +69,16 @@ impl ParseError {
         self
     }
 
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    pub fn with_kind(mut self, kind: ParseErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
     pub fn context(&self) -> Option<Span> {
         self.context
     }
@@ -77,6 +91,33 @@ impl ParseError {
     pub fn unexpected(&self) -> Option<Span> {
         self.unexpected
     }
+    /// A machine-applicable suggestion for resolving the error
+    pub fn fix(&self) -> Option<&Fix> {
+        self.fix.as_ref()
+    }
+    /// The category of the error, for reporting it without matching on [`ParseError::description`]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+    /// Decode the key at [`ParseError::unexpected`] within the `input` that was parsed
+    ///
+    /// This is the key that errors like [`ParseErrorKind::DuplicateKey`] are about.
+    /// Returns `None` if there is no such span within `input`.
+    #[cfg(feature = "alloc")]
+    #[allow(unused_qualifications)]
+    pub fn key(&self, input: &str) -> Option<alloc::string::String> {
+        let span = self.unexpected?;
+        let raw = input.get(span.start()..span.end())?;
+        let encoding = match raw.as_bytes().first() {
+            Some(b'\'') => Some(crate::decoder::Encoding::LiteralString),
+            Some(b'"') => Some(crate::decoder::Encoding::BasicString),
+            _ => None,
+        };
+        let raw = crate::Raw::new_unchecked(raw, encoding, span);
+        let mut key = alloc::string::String::new();
+        raw.decode_key(&mut key, &mut ());
+        Some(key)
+    }
 
     pub(crate) fn rebase_spans(mut self, offset: usize) -> Self {
         if let Some(context) = self.context.as_mut() {
@@ -85,16 +126,106 @@ impl ParseError {
         if let Some(unexpected) = self.unexpected.as_mut() {
             *unexpected += offset;
         }
+        self.fix = self.fix.map(|fix| fix.rebase_span(offset));
         self
     }
 }
 
+/// The category of a [`ParseError`]
+///
+/// For errors about a key, [`ParseError::unexpected`] is the key and [`ParseError::context`] is
+/// the earlier definition, if any.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The TOML is malformed
+    Syntax,
+    /// A string has an invalid escape sequence
+    InvalidEscape,
+    /// Values are nested deeper than allowed
+    RecursionLimit,
+    /// A key is defined more than once
+    DuplicateKey,
+    /// A table is defined more than once, or is extended after being defined as another value
+    TableRedefinition,
+}
+
+/// Replace a [`Span`] of the [`Source`][crate::Source] to resolve a [`ParseError`]
+///
+/// An empty [`Fix::span`] is an insertion.
+///
+/// See also [`apply_fixes`]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub struct Fix {
+    span: Span,
+    replacement: ErrorStr,
+}
+
+impl Fix {
+    pub fn new(span: Span, replacement: impl Into<ErrorStr>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+        }
+    }
+
+    /// Insert `text` at `offset`
+    pub fn insert(offset: usize, text: impl Into<ErrorStr>) -> Self {
+        Self::new(Span::new_unchecked(offset, offset), text)
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    pub(crate) fn rebase_span(mut self, offset: usize) -> Self {
+        self.span += offset;
+        self
+    }
+}
+
+/// Apply each [`ParseError::fix`] to `input`
+///
+/// Fixes are applied in order of their [`Fix::span`].
+/// When fixes overlap, the first one reported wins and the rest are skipped.
+///
+/// The errors must be relative to `input`, like those from
+/// [`parse_document`][crate::parser::parse_document] and [`Raw::decode_scalar`][crate::Raw::decode_scalar].
+#[cfg(feature = "alloc")]
+#[allow(unused_qualifications)]
+pub fn apply_fixes(input: &str, errors: &[ParseError]) -> alloc::string::String {
+    let mut fixes = errors
+        .iter()
+        .filter_map(|e| e.fix())
+        .filter(|f| input.get(f.span.start()..f.span.end()).is_some())
+        .collect::<alloc::vec::Vec<_>>();
+    // stable, preserving report order for fixes starting at the same offset
+    fixes.sort_by_key(|f| f.span.start());
+
+    let mut output = alloc::string::String::with_capacity(input.len());
+    let mut end = 0;
+    for fix in fixes {
+        if fix.span.start() < end {
+            continue;
+        }
+        output.push_str(&input[end..fix.span.start()]);
+        output.push_str(fix.replacement());
+        end = fix.span.end();
+    }
+    output.push_str(&input[end..]);
+    output
+}
+
 #[cfg(feature = "alloc")]
 type ErrorStr = alloc::borrow::Cow<'static, str>;
 #[cfg(not(feature = "alloc"))]
 type ErrorStr = &'static str;
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[non_exhaustive]
 pub enum Expected {
     Literal(&'static str),
@@ -26,11 +26,12 @@ pub use token::TokenKind;
 /// To get started, see [`Source::lex`][crate::Source::lex]
 pub struct Lexer<'i> {
     stream: Stream<'i>,
+    offset: usize,
     eof: bool,
 }
 
 impl<'i> Lexer<'i> {
-    pub(crate) fn new(input: &'i str) -> Self {
+    pub(crate) fn new(input: &'i str, offset: usize) -> Self {
         let mut stream = Stream::new(input);
         if input.as_bytes().starts_with(BOM) {
             let offset = BOM.len();
@@ -41,7 +42,11 @@ impl<'i> Lexer<'i> {
             #[cfg(not(feature = "unsafe"))]
             stream.next_slice(offset);
         }
-        Lexer { stream, eof: false }
+        Lexer {
+            stream,
+            offset,
+            eof: false,
+        }
     }
 
     #[cfg(feature = "alloc")]
@@ -63,11 +68,13 @@ impl Iterator for Lexer<'_> {
             } else {
                 self.eof = true;
                 let start = self.stream.current_token_start();
-                let span = Span::new_unchecked(start, start);
+                let span = Span::new_unchecked(start, start) + self.offset;
                 return Some(Token::new(TokenKind::Eof, span));
             }
         };
-        Some(process_token(*peek_byte, &mut self.stream))
+        let mut token = process_token(*peek_byte, &mut self.stream);
+        token.span += self.offset;
+        Some(token)
     }
 }
 
@@ -12,6 +12,10 @@
 //! 2. [Parsing tokens][parser] (push parser)
 //! 3. Organizing the physical layout into the logical layout,
 //!    including [decoding keys and values][decoder]
+//!
+//! For syntax highlighting, see [`semantic`].
+//!
+//! For TOML embedded in other documents, like front matter, see [`embed`].
 
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -27,23 +31,46 @@ extern crate alloc;
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "alloc")]
+mod bytes;
 #[cfg(feature = "debug")]
 pub(crate) mod debug;
+#[cfg(feature = "alloc")]
+mod diagnostic;
 mod error;
+#[cfg(feature = "alloc")]
+mod line_index;
 mod source;
 
 pub mod decoder;
+pub mod embed;
 pub mod lexer;
 pub mod parser;
+pub mod semantic;
 
 pub use error::ErrorSink;
 pub use error::Expected;
+pub use error::Fix;
 pub use error::ParseError;
+pub use error::ParseErrorKind;
 pub use source::Raw;
 pub use source::Source;
 pub use source::SourceIndex;
 pub use source::Span;
 
+#[cfg(feature = "alloc")]
+pub use bytes::decode_bytes;
+#[cfg(feature = "alloc")]
+pub use diagnostic::Diagnostic;
+#[cfg(feature = "alloc")]
+pub use diagnostic::Label;
+#[cfg(feature = "alloc")]
+pub use error::apply_fixes;
+#[cfg(feature = "alloc")]
+pub use line_index::LineCol;
+#[cfg(feature = "alloc")]
+pub use line_index::LineIndex;
+
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
 pub struct ReadmeDoctests;
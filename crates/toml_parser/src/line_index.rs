@@ -0,0 +1,290 @@
+use alloc::vec::Vec;
+
+use crate::Span;
+
+/// Convert between byte offsets and line / column positions within a [`Source`][crate::Source]
+///
+/// Lines and columns are 0-based.
+/// Lines are terminated by `\n`, with any preceding `\r` counted as the last column of the line.
+///
+/// Columns may be measured in
+/// - UTF-8 bytes, see [`LineIndex::line_col`]
+/// - UTF-16 code units, like the Language Server Protocol, see [`LineIndex::line_col_utf16`]
+///
+/// For character offsets from the start of the input, see [`LineIndex::char_offset`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineIndex<'i> {
+    input: &'i str,
+    line_starts: Vec<usize>,
+    line_char_starts: Vec<usize>,
+}
+
+impl<'i> LineIndex<'i> {
+    /// Index the lines of `input`
+    pub fn new(input: &'i str) -> Self {
+        let mut line_starts = Vec::with_capacity(input.len() / 32 + 1);
+        let mut line_char_starts = Vec::with_capacity(line_starts.capacity());
+        line_starts.push(0);
+        line_char_starts.push(0);
+        let mut char_offset = 0;
+        let mut line_start = 0;
+        for (i, b) in input.bytes().enumerate() {
+            if b == b'\n' {
+                char_offset += count_chars(&input[line_start..=i]);
+                line_start = i + 1;
+                line_starts.push(line_start);
+                line_char_starts.push(char_offset);
+            }
+        }
+        Self {
+            input,
+            line_starts,
+            line_char_starts,
+        }
+    }
+
+    /// The TOML encoded `&str` being indexed
+    pub fn input(&self) -> &'i str {
+        self.input
+    }
+
+    /// The number of lines, including a trailing empty line
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Byte offsets of `line`, excluding the line ending
+    pub fn line_span(&self, line: usize) -> Option<Span> {
+        let start = *self.line_starts.get(line)?;
+        let end = self.line_end(line);
+        let content = &self.input[start..end];
+        let content = content.strip_suffix('\r').unwrap_or(content);
+        Some(Span::new_unchecked(start, start + content.len()))
+    }
+
+    /// Convert a byte `offset` to a line and UTF-8 byte column
+    ///
+    /// Returns `None` if `offset` is past the end of the input or not on a `char` boundary.
+    pub fn line_col(&self, offset: usize) -> Option<LineCol> {
+        let (line, line_start) = self.line_of(offset)?;
+        Some(LineCol::new(line, offset - line_start))
+    }
+
+    /// Convert a line and UTF-8 byte column to a byte offset
+    ///
+    /// Returns `None` if the position is past the end of its line or not on a `char` boundary.
+    pub fn offset(&self, position: LineCol) -> Option<usize> {
+        let line_start = *self.line_starts.get(position.line)?;
+        let offset = line_start.checked_add(position.column)?;
+        if self.line_end(position.line) < offset || !self.input.is_char_boundary(offset) {
+            return None;
+        }
+        Some(offset)
+    }
+
+    /// Convert a byte `offset` to a line and UTF-16 code unit column
+    ///
+    /// Returns `None` if `offset` is past the end of the input or not on a `char` boundary.
+    pub fn line_col_utf16(&self, offset: usize) -> Option<LineCol> {
+        let (line, line_start) = self.line_of(offset)?;
+        let column = self.input[line_start..offset]
+            .chars()
+            .map(char::len_utf16)
+            .sum();
+        Some(LineCol::new(line, column))
+    }
+
+    /// Convert a line and UTF-16 code unit column to a byte offset
+    ///
+    /// Returns `None` if the position is past the end of its line or splits a surrogate pair.
+    pub fn offset_utf16(&self, position: LineCol) -> Option<usize> {
+        let line_start = *self.line_starts.get(position.line)?;
+        let line_end = self.line_end(position.line);
+        let mut column = 0;
+        for (i, c) in self.input[line_start..line_end].char_indices() {
+            if column == position.column {
+                return Some(line_start + i);
+            } else if position.column < column {
+                return None;
+            }
+            column += c.len_utf16();
+        }
+        (column == position.column).then_some(line_end)
+    }
+
+    /// Convert a byte `offset` to the number of `char`s before it
+    ///
+    /// Returns `None` if `offset` is past the end of the input or not on a `char` boundary.
+    pub fn char_offset(&self, offset: usize) -> Option<usize> {
+        let (line, line_start) = self.line_of(offset)?;
+        Some(self.line_char_starts[line] + count_chars(&self.input[line_start..offset]))
+    }
+
+    /// Convert a number of `char`s from the start of the input to a byte offset
+    ///
+    /// Returns `None` if `char_offset` is past the end of the input.
+    pub fn offset_from_char(&self, char_offset: usize) -> Option<usize> {
+        let line = self
+            .line_char_starts
+            .partition_point(|start| *start <= char_offset)
+            - 1;
+        let line_start = self.line_starts[line];
+        let remaining = char_offset - self.line_char_starts[line];
+        let line_end = self.line_end(line);
+        self.input[line_start..line_end]
+            .char_indices()
+            .map(|(i, _)| line_start + i)
+            .chain(core::iter::once(line_end))
+            .nth(remaining)
+    }
+
+    /// The line containing `offset` and the offset the line starts at
+    fn line_of(&self, offset: usize) -> Option<(usize, usize)> {
+        if !self.input.is_char_boundary(offset) {
+            return None;
+        }
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        Some((line, self.line_starts[line]))
+    }
+
+    /// The offset of the line's `\n` or the end of input
+    fn line_end(&self, line: usize) -> usize {
+        self.line_starts
+            .get(line + 1)
+            .map(|next| next - 1)
+            .unwrap_or(self.input.len())
+    }
+}
+
+/// 0-based position within a [`LineIndex`]
+///
+/// The unit of [`LineCol::column`] depends on how the position was created.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct LineCol {
+    /// 0-based line number
+    pub line: usize,
+    /// 0-based offset within the line
+    pub column: usize,
+}
+
+impl LineCol {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+fn count_chars(s: &str) -> usize {
+    s.bytes().filter(|b| !is_utf8_continuation(*b)).count()
+}
+
+fn is_utf8_continuation(b: u8) -> bool {
+    b & 0b1100_0000 == 0b1000_0000
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "a = 1\r\nb = \"é𝄞\"\n\nc = 3";
+
+    #[test]
+    fn lines() {
+        let index = LineIndex::new(INPUT);
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(index.line_span(0), Some(Span::new_unchecked(0, 5)));
+        assert_eq!(index.line_span(1), Some(Span::new_unchecked(7, 19)));
+        assert_eq!(index.line_span(2), Some(Span::new_unchecked(20, 20)));
+        assert_eq!(index.line_span(3), Some(Span::new_unchecked(21, 26)));
+        assert_eq!(index.line_span(4), None);
+
+        let index = LineIndex::new("");
+        assert_eq!(index.line_count(), 1);
+        assert_eq!(index.line_span(0), Some(Span::new_unchecked(0, 0)));
+    }
+
+    #[test]
+    fn utf8() {
+        let index = LineIndex::new(INPUT);
+        let cases = [
+            (0, Some(LineCol::new(0, 0))),
+            (5, Some(LineCol::new(0, 5))),
+            (6, Some(LineCol::new(0, 6))),
+            (7, Some(LineCol::new(1, 0))),
+            (12, Some(LineCol::new(1, 5))),
+            (13, None),
+            (14, Some(LineCol::new(1, 7))),
+            (19, Some(LineCol::new(1, 12))),
+            (20, Some(LineCol::new(2, 0))),
+            (26, Some(LineCol::new(3, 5))),
+            (27, None),
+        ];
+        for (offset, expected) in cases {
+            let actual = index.line_col(offset);
+            assert_eq!(actual, expected, "offset {offset}");
+            if let Some(position) = actual {
+                assert_eq!(
+                    index.offset(position),
+                    Some(offset),
+                    "position {position:?}"
+                );
+            }
+        }
+        assert_eq!(index.offset(LineCol::new(0, 7)), None);
+        assert_eq!(index.offset(LineCol::new(4, 0)), None);
+    }
+
+    #[test]
+    fn utf16() {
+        let index = LineIndex::new(INPUT);
+        let cases = [
+            (12, Some(LineCol::new(1, 5))),
+            (14, Some(LineCol::new(1, 6))),
+            (18, Some(LineCol::new(1, 8))),
+            (19, Some(LineCol::new(1, 9))),
+            (26, Some(LineCol::new(3, 5))),
+        ];
+        for (offset, expected) in cases {
+            let actual = index.line_col_utf16(offset);
+            assert_eq!(actual, expected, "offset {offset}");
+            if let Some(position) = actual {
+                assert_eq!(
+                    index.offset_utf16(position),
+                    Some(offset),
+                    "position {position:?}"
+                );
+            }
+        }
+        // Within a surrogate pair
+        assert_eq!(index.offset_utf16(LineCol::new(1, 7)), None);
+        assert_eq!(index.offset_utf16(LineCol::new(1, 12)), None);
+    }
+
+    #[test]
+    fn chars() {
+        let index = LineIndex::new(INPUT);
+        let cases = [
+            (0, Some(0)),
+            (12, Some(12)),
+            (13, None),
+            (14, Some(13)),
+            (18, Some(14)),
+            (19, Some(15)),
+            (20, Some(16)),
+            (26, Some(22)),
+            (27, None),
+        ];
+        for (offset, expected) in cases {
+            let actual = index.char_offset(offset);
+            assert_eq!(actual, expected, "offset {offset}");
+            if let Some(char_offset) = actual {
+                assert_eq!(
+                    index.offset_from_char(char_offset),
+                    Some(offset),
+                    "char offset {char_offset}"
+                );
+            }
+        }
+        assert_eq!(index.offset_from_char(23), None);
+    }
+}
@@ -5,6 +5,7 @@ use winnow::stream::TokenSlice;
 use super::EventReceiver;
 use crate::ErrorSink;
 use crate::Expected;
+use crate::Fix;
 use crate::ParseError;
 #[cfg(feature = "debug")]
 use crate::debug::DebugErrorSink;
@@ -258,7 +259,8 @@ fn on_table(
                     ParseError::new("unclosed array table")
                         .with_context(context)
                         .with_expected(&[Expected::Literal("]")])
-                        .with_unexpected(close_token.span().after()),
+                        .with_unexpected(close_token.span().after())
+                        .with_fix(Fix::insert(close_token.span().end(), "]")),
                 );
             }
         } else {
@@ -276,14 +278,16 @@ fn on_table(
                 ParseError::new("unclosed array table")
                     .with_context(context)
                     .with_expected(&[Expected::Literal("]]")])
-                    .with_unexpected(last_key_token.span().after()),
+                    .with_unexpected(last_key_token.span().after())
+                    .with_fix(Fix::insert(last_key_token.span().end(), "]]")),
             );
         } else {
             error.report_error(
                 ParseError::new("unclosed table")
                     .with_context(context)
                     .with_expected(&[Expected::Literal("]")])
-                    .with_unexpected(last_key_token.span().after()),
+                    .with_unexpected(last_key_token.span().after())
+                    .with_fix(Fix::insert(last_key_token.span().end(), "]")),
             );
         }
     }
@@ -944,7 +948,8 @@ fn on_array_open(
         ParseError::new("unclosed array")
             .with_context(array_open.span())
             .with_expected(&[Expected::Literal("]")])
-            .with_unexpected(previous_span.after()),
+            .with_unexpected(previous_span.after())
+            .with_fix(Fix::insert(previous_span.end(), "]")),
     );
     receiver.array_close(previous_span.after(), error);
 }
@@ -1251,7 +1256,8 @@ fn on_inline_table_open(
         ParseError::new("unclosed inline table")
             .with_context(inline_table_open.span())
             .with_expected(&[Expected::Literal("}")])
-            .with_unexpected(previous_span.after()),
+            .with_unexpected(previous_span.after())
+            .with_fix(Fix::insert(previous_span.end(), "}")),
     );
     receiver.inline_table_close(previous_span.after(), error);
 }
@@ -1,5 +1,6 @@
 use crate::ErrorSink;
 use crate::ParseError;
+use crate::ParseErrorKind;
 use crate::Source;
 use crate::Span;
 use crate::decoder::Encoding;
@@ -411,7 +412,8 @@ impl EventReceiver for RecursionGuard<'_> {
         if allowed && !within_depth {
             error.report_error(
                 ParseError::new("cannot recurse further; max recursion depth met")
-                    .with_unexpected(span),
+                    .with_unexpected(span)
+                    .with_kind(ParseErrorKind::RecursionLimit),
             );
         }
         allowed && within_depth
@@ -427,7 +429,8 @@ impl EventReceiver for RecursionGuard<'_> {
         if allowed && !within_depth {
             error.report_error(
                 ParseError::new("cannot recurse further; max recursion depth met")
-                    .with_unexpected(span),
+                    .with_unexpected(span)
+                    .with_kind(ParseErrorKind::RecursionLimit),
             );
         }
         allowed && within_depth
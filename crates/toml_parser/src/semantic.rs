@@ -0,0 +1,457 @@
+//! Classify TOML [events][crate::parser::Event] for syntax highlighting
+//!
+//! This follows the [LSP semantic tokens] model: each [`SemanticToken`] has a [`Span`], a
+//! [`SemanticTokenKind`], and a set of [`SemanticTokenModifiers`], reported in document order.
+//! Whitespace, newlines, and parse errors are not reported.
+//!
+//! To get started, see [`classify_document`] or [`SemanticClassifier`].
+//!
+//! [LSP semantic tokens]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocument_semanticTokens
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::ErrorSink;
+use crate::Source;
+use crate::Span;
+use crate::decoder::Encoding;
+use crate::decoder::ScalarKind;
+use crate::parser::EventReceiver;
+
+/// Classify all of the [`SemanticToken`]s in a TOML document
+///
+/// Parse errors are ignored; content that can't be classified is skipped.
+#[cfg(feature = "alloc")]
+pub fn classify_document(source: Source<'_>) -> Vec<SemanticToken> {
+    let tokens = source.lex().into_vec();
+    let mut semantic_tokens = Vec::with_capacity(tokens.len());
+    let mut output = |token| semantic_tokens.push(token);
+    let mut classifier = SemanticClassifier::new(source, &mut output);
+    crate::parser::parse_document(&tokens, &mut classifier, &mut ());
+    classifier.finish();
+    semantic_tokens
+}
+
+/// A classified region of a [`Source`]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct SemanticToken {
+    span: Span,
+    kind: SemanticTokenKind,
+    modifiers: SemanticTokenModifiers,
+}
+
+impl SemanticToken {
+    pub fn new(span: Span, kind: SemanticTokenKind, modifiers: SemanticTokenModifiers) -> Self {
+        Self {
+            span,
+            kind,
+            modifiers,
+        }
+    }
+
+    #[inline(always)]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    #[inline(always)]
+    pub fn kind(&self) -> SemanticTokenKind {
+        self.kind
+    }
+
+    #[inline(always)]
+    pub fn modifiers(&self) -> SemanticTokenModifiers {
+        self.modifiers
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[non_exhaustive]
+pub enum SemanticTokenKind {
+    /// `# comment`
+    Comment,
+    /// A key within a `[table]` or `[[array-of-tables]]` header
+    TableKey,
+    /// A key within a key-value pair
+    Key,
+    /// `=`
+    Operator,
+    /// `[`, `]`, `[[`, `]]`, `{`, `}`, `.`, and `,`
+    Punctuation,
+    /// The content of a string, excluding [`SemanticTokenKind::EscapeSequence`]s
+    String,
+    /// Escape sequences and line-ending backslashes within basic strings
+    EscapeSequence,
+    Integer,
+    Float,
+    Boolean,
+    Datetime,
+    /// An unquoted value that isn't any other kind of value
+    BareString,
+}
+
+impl SemanticTokenKind {
+    /// All kinds, ordered by [`SemanticTokenKind::index`]
+    ///
+    /// This can be used as the LSP `SemanticTokensLegend::tokenTypes`, via [`SemanticTokenKind::as_str`].
+    pub const ALL: &'static [Self] = &[
+        Self::Comment,
+        Self::TableKey,
+        Self::Key,
+        Self::Operator,
+        Self::Punctuation,
+        Self::String,
+        Self::EscapeSequence,
+        Self::Integer,
+        Self::Float,
+        Self::Boolean,
+        Self::Datetime,
+        Self::BareString,
+    ];
+
+    /// Position within [`SemanticTokenKind::ALL`]
+    pub const fn index(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Name of the kind for a legend
+    ///
+    /// Comments, keys, operators, and strings use LSP's standard token type names.
+    /// The remaining kinds use toml-specific names, like `integer` rather than LSP's `number`, so a
+    /// client needs to map them to its own styles.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Comment => "comment",
+            Self::TableKey => "namespace",
+            Self::Key => "property",
+            Self::Operator => "operator",
+            Self::Punctuation => "punctuation",
+            Self::String => "string",
+            Self::EscapeSequence => "escapeSequence",
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Boolean => "boolean",
+            Self::Datetime => "datetime",
+            Self::BareString => "bareString",
+        }
+    }
+}
+
+/// A set of modifiers on a [`SemanticTokenKind`]
+///
+/// [`SemanticTokenModifiers::bits`] can be used directly as an LSP `tokenModifiers` bit set, with
+/// [`SemanticTokenModifiers::ALL`] as the legend.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SemanticTokenModifiers(u32);
+
+impl SemanticTokenModifiers {
+    pub const NONE: Self = Self(0);
+    /// A segment of a dotted key, like `b` in `a.b.c`
+    pub const DOTTED: Self = Self(1 << 0);
+    /// Within an `[[array-of-tables]]` header
+    pub const ARRAY_TABLE: Self = Self(1 << 1);
+    /// A quoted key or string
+    pub const QUOTED: Self = Self(1 << 2);
+    /// A literal string or key, without escape sequences
+    pub const LITERAL: Self = Self(1 << 3);
+    /// A multi-line string
+    pub const MULTILINE: Self = Self(1 << 4);
+
+    /// All modifiers with their names, ordered by bit position
+    pub const ALL: &'static [(Self, &'static str)] = &[
+        (Self::DOTTED, "dotted"),
+        (Self::ARRAY_TABLE, "arrayTable"),
+        (Self::QUOTED, "quoted"),
+        (Self::LITERAL, "literal"),
+        (Self::MULTILINE, "multiline"),
+    ];
+
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn from_encoding(encoding: Option<Encoding>) -> Self {
+        match encoding {
+            Some(Encoding::LiteralString) => Self::QUOTED.union(Self::LITERAL),
+            Some(Encoding::BasicString) => Self::QUOTED,
+            Some(Encoding::MlLiteralString) => {
+                Self::QUOTED.union(Self::LITERAL).union(Self::MULTILINE)
+            }
+            Some(Encoding::MlBasicString) => Self::QUOTED.union(Self::MULTILINE),
+            None => Self::NONE,
+        }
+    }
+}
+
+impl core::ops::BitOr for SemanticTokenModifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for SemanticTokenModifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl core::fmt::Debug for SemanticTokenModifiers {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut set = f.debug_set();
+        for (modifier, name) in Self::ALL {
+            if self.contains(*modifier) {
+                set.entry(&format_args!("{name}"));
+            }
+        }
+        set.finish()
+    }
+}
+
+/// [`EventReceiver`] that reports [`SemanticToken`]s
+///
+/// Call [`SemanticClassifier::finish`] after parsing to flush the last token.
+pub struct SemanticClassifier<'r, 's> {
+    source: Source<'s>,
+    output: &'r mut dyn FnMut(SemanticToken),
+    header: Option<SemanticTokenModifiers>,
+    pending_key: Option<(Span, Option<Encoding>)>,
+    dotted: bool,
+}
+
+impl<'r, 's> SemanticClassifier<'r, 's> {
+    pub fn new(source: Source<'s>, output: &'r mut dyn FnMut(SemanticToken)) -> Self {
+        Self {
+            source,
+            output,
+            header: None,
+            pending_key: None,
+            dotted: false,
+        }
+    }
+
+    /// Report any buffered token
+    pub fn finish(&mut self) {
+        self.end_key();
+    }
+
+    fn emit(&mut self, span: Span, kind: SemanticTokenKind, modifiers: SemanticTokenModifiers) {
+        if !span.is_empty() {
+            (self.output)(SemanticToken::new(span, kind, modifiers));
+        }
+    }
+
+    fn punctuation(&mut self, span: Span) {
+        self.end_key();
+        let modifiers = self.header.unwrap_or_default();
+        self.emit(span, SemanticTokenKind::Punctuation, modifiers);
+    }
+
+    /// Keys are buffered until we know whether they are part of a dotted key
+    fn emit_key(&mut self, span: Span, encoding: Option<Encoding>, dotted: bool) {
+        let (kind, mut modifiers) = match self.header {
+            Some(header) => (SemanticTokenKind::TableKey, header),
+            None => (SemanticTokenKind::Key, SemanticTokenModifiers::NONE),
+        };
+        if dotted {
+            modifiers |= SemanticTokenModifiers::DOTTED;
+        }
+        self.emit_string(span, encoding, kind, modifiers);
+    }
+
+    fn end_key(&mut self) {
+        if let Some((span, encoding)) = self.pending_key.take() {
+            let dotted = self.dotted;
+            self.emit_key(span, encoding, dotted);
+        }
+        self.dotted = false;
+    }
+
+    /// Split out escape sequences from the rest of the string
+    fn emit_string(
+        &mut self,
+        span: Span,
+        encoding: Option<Encoding>,
+        kind: SemanticTokenKind,
+        modifiers: SemanticTokenModifiers,
+    ) {
+        let modifiers = modifiers | SemanticTokenModifiers::from_encoding(encoding);
+        let is_basic = matches!(
+            encoding,
+            Some(Encoding::BasicString) | Some(Encoding::MlBasicString)
+        );
+        let Some(raw) = self.source.get(span).filter(|_| is_basic) else {
+            self.emit(span, kind, modifiers);
+            return;
+        };
+
+        let bytes = raw.as_bytes();
+        let mut start = 0;
+        let mut current = 0;
+        while current < bytes.len() {
+            if bytes[current] != b'\\' {
+                current += 1;
+                continue;
+            }
+            let escape_len = escape_len(&bytes[current..]);
+            self.emit(
+                Span::new_unchecked(span.start() + start, span.start() + current),
+                kind,
+                modifiers,
+            );
+            self.emit(
+                Span::new_unchecked(span.start() + current, span.start() + current + escape_len),
+                SemanticTokenKind::EscapeSequence,
+                modifiers,
+            );
+            current += escape_len;
+            start = current;
+        }
+        self.emit(
+            Span::new_unchecked(span.start() + start, span.end()),
+            kind,
+            modifiers,
+        );
+    }
+}
+
+/// ```abnf
+/// escaped = escape escape-seq-char
+/// mlb-escaped-nl = escape ws newline *( wschar / newline )
+/// ```
+fn escape_len(escape: &[u8]) -> usize {
+    let hex_len = match escape.get(1) {
+        None => return 1,
+        Some(b'x') => 2,
+        Some(b'u') => 4,
+        Some(b'U') => 8,
+        Some(b' ' | b'\t' | b'\r' | b'\n') => {
+            return 1 + escape[1..]
+                .iter()
+                .position(|b| !matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+                .unwrap_or(escape.len() - 1);
+        }
+        Some(_) => return 2,
+    };
+    2 + escape[2..]
+        .iter()
+        .take(hex_len)
+        .take_while(|b| b.is_ascii_hexdigit())
+        .count()
+}
+
+impl EventReceiver for SemanticClassifier<'_, '_> {
+    fn std_table_open(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.end_key();
+        self.header = Some(SemanticTokenModifiers::NONE);
+        self.punctuation(span);
+    }
+    fn std_table_close(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.punctuation(span);
+        self.header = None;
+    }
+    fn array_table_open(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.end_key();
+        self.header = Some(SemanticTokenModifiers::ARRAY_TABLE);
+        self.punctuation(span);
+    }
+    fn array_table_close(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.punctuation(span);
+        self.header = None;
+    }
+    fn inline_table_open(&mut self, span: Span, _error: &mut dyn ErrorSink) -> bool {
+        self.punctuation(span);
+        true
+    }
+    fn inline_table_close(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.punctuation(span);
+    }
+    fn array_open(&mut self, span: Span, _error: &mut dyn ErrorSink) -> bool {
+        self.punctuation(span);
+        true
+    }
+    fn array_close(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.punctuation(span);
+    }
+    fn simple_key(&mut self, span: Span, encoding: Option<Encoding>, _error: &mut dyn ErrorSink) {
+        if let Some((pending_span, pending_encoding)) = self.pending_key.take() {
+            // Missing a key separator
+            let dotted = self.dotted;
+            self.emit_key(pending_span, pending_encoding, dotted);
+        }
+        self.pending_key = Some((span, encoding));
+    }
+    fn key_sep(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.dotted = true;
+        if let Some((pending_span, pending_encoding)) = self.pending_key.take() {
+            self.emit_key(pending_span, pending_encoding, true);
+        }
+        let modifiers = self.header.unwrap_or_default() | SemanticTokenModifiers::DOTTED;
+        self.emit(span, SemanticTokenKind::Punctuation, modifiers);
+    }
+    fn key_val_sep(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.end_key();
+        self.emit(
+            span,
+            SemanticTokenKind::Operator,
+            SemanticTokenModifiers::NONE,
+        );
+    }
+    fn scalar(&mut self, span: Span, encoding: Option<Encoding>, _error: &mut dyn ErrorSink) {
+        self.end_key();
+        let Some(raw) = self.source.get(span) else {
+            return;
+        };
+        let raw = crate::Raw::new_unchecked(raw.as_str(), encoding, span);
+        let kind = match raw.decode_scalar(&mut (), &mut ()) {
+            ScalarKind::String if encoding.is_none() => SemanticTokenKind::BareString,
+            ScalarKind::String => {
+                self.emit_string(
+                    span,
+                    encoding,
+                    SemanticTokenKind::String,
+                    SemanticTokenModifiers::NONE,
+                );
+                return;
+            }
+            ScalarKind::Boolean(_) => SemanticTokenKind::Boolean,
+            ScalarKind::DateTime => SemanticTokenKind::Datetime,
+            ScalarKind::Float => SemanticTokenKind::Float,
+            ScalarKind::Integer(_) => SemanticTokenKind::Integer,
+        };
+        self.emit(span, kind, SemanticTokenModifiers::NONE);
+    }
+    fn value_sep(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.punctuation(span);
+    }
+    fn whitespace(&mut self, _span: Span, _error: &mut dyn ErrorSink) {}
+    fn comment(&mut self, span: Span, _error: &mut dyn ErrorSink) {
+        self.end_key();
+        self.emit(
+            span,
+            SemanticTokenKind::Comment,
+            SemanticTokenModifiers::NONE,
+        );
+    }
+    fn newline(&mut self, _span: Span, _error: &mut dyn ErrorSink) {
+        self.end_key();
+        self.header = None;
+    }
+    fn error(&mut self, _span: Span, _error: &mut dyn ErrorSink) {
+        self.end_key();
+    }
+}
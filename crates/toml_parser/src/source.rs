@@ -8,16 +8,38 @@ use crate::lexer::Lexer;
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Source<'i> {
     input: &'i str,
+    offset: usize,
 }
 
 impl<'i> Source<'i> {
     pub fn new(input: &'i str) -> Self {
-        Self { input }
+        Self { input, offset: 0 }
+    }
+
+    /// Report [`Span`]s relative to a host document that `input` is embedded in
+    ///
+    /// `offset` is the position of `input` within the host document.
+    /// For locating embedded TOML, see [`embed`][crate::embed].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use toml_parser::Source;
+    /// # use toml_parser::Span;
+    /// let host = "+++\nkey = 1\n+++\n";
+    /// let source = Source::new(&host[4..12]).with_offset(4);
+    /// let key = source.lex().next().unwrap();
+    /// assert_eq!(key.span(), Span::new_unchecked(4, 7));
+    /// assert_eq!(&host[key.span().start()..key.span().end()], "key");
+    /// ```
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
     }
 
     /// Start lexing the TOML encoded data
     pub fn lex(&self) -> Lexer<'i> {
-        Lexer::new(self.input)
+        Lexer::new(self.input, self.offset)
     }
 
     /// Access the TOML encoded `&str`
@@ -25,6 +47,19 @@ impl<'i> Source<'i> {
         self.input
     }
 
+    /// Position of [`Source::input`] within the host document, see [`Source::with_offset`]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Index the input for converting [`Span`]s to line / column positions
+    ///
+    /// Positions are relative to [`Source::input`], ignoring [`Source::offset`].
+    #[cfg(feature = "alloc")]
+    pub fn line_index(&self) -> crate::LineIndex<'i> {
+        crate::LineIndex::new(self.input)
+    }
+
     /// Return a subslice of the input
     pub fn get(&self, span: impl SourceIndex) -> Option<Raw<'i>> {
         span.get(self)
@@ -51,8 +86,9 @@ impl<'i> Source<'i> {
 
     /// Return a subslice of the input
     fn get_raw_str(&self, span: Span) -> Option<&'i str> {
-        let index = span.start()..span.end();
-        self.input.get(index)
+        let start = span.start().checked_sub(self.offset)?;
+        let end = span.end().checked_sub(self.offset)?;
+        self.input.get(start..end)
     }
 
     /// Return an unchecked subslice of the input
@@ -70,7 +106,7 @@ impl<'i> Source<'i> {
     /// Failing any of those, the returned string slice may reference invalid memory or violate the invariants communicated by `str` type.
     #[cfg(feature = "unsafe")]
     unsafe fn get_raw_str_unchecked(&self, span: Span) -> &'i str {
-        let index = span.start()..span.end();
+        let index = span.start() - self.offset..span.end() - self.offset;
         // SAFETY: Same safety guarantees are required
         unsafe { self.input.get_unchecked(index) }
     }
@@ -160,6 +196,16 @@ impl<'i> Raw<'i> {
         }
     }
 
+    /// Suggest zero-padding a [`ScalarKind::DateTime`][crate::decoder::ScalarKind::DateTime]
+    /// that is missing leading zeroes, like `1979-5-27`
+    ///
+    /// [`Raw::decode_scalar`] leaves validating date-times to the caller; attach this to the
+    /// error reported when that fails.
+    pub fn datetime_padding_fix(&self) -> Option<crate::Fix> {
+        crate::decoder::scalar::datetime_padding_fix(*self)
+            .map(|fix| fix.rebase_span(self.span.start))
+    }
+
     pub fn decode_whitespace(&self, _error: &mut dyn ErrorSink) {
         // whitespace is always valid
     }
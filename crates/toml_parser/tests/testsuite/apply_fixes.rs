@@ -0,0 +1,164 @@
+use snapbox::assert_data_eq;
+use snapbox::prelude::*;
+use snapbox::str;
+
+use toml_parser::ParseError;
+use toml_parser::Source;
+use toml_parser::apply_fixes;
+use toml_parser::decoder::ScalarKind;
+use toml_parser::parser::*;
+
+fn parse(input: &str) -> Vec<ParseError> {
+    let doc = Source::new(input);
+    let tokens = doc.lex().into_vec();
+    let mut events = Vec::<Event>::new();
+    let mut errors = Vec::new();
+    parse_document(&tokens, &mut events, &mut errors);
+    for event in &events {
+        let raw = doc.get(event).unwrap();
+        match event.kind() {
+            EventKind::SimpleKey => raw.decode_key(&mut (), &mut errors),
+            EventKind::Scalar => {
+                let kind = raw.decode_scalar(&mut (), &mut errors);
+                // Stand-in for the caller's date-time validation
+                if kind == ScalarKind::DateTime {
+                    if let Some(fix) = raw.datetime_padding_fix() {
+                        errors.push(ParseError::new("invalid date-time").with_fix(fix));
+                    }
+                }
+            }
+            EventKind::Comment => raw.decode_comment(&mut errors),
+            EventKind::Newline => raw.decode_newline(&mut errors),
+            _ => {}
+        }
+    }
+    errors
+}
+
+#[track_caller]
+fn t(input: &str, expected: impl IntoData) {
+    dbg!(input);
+    let errors = parse(input);
+    assert!(!errors.is_empty(), "`{input}` is valid");
+    let actual = apply_fixes(input, &errors);
+    assert_data_eq!(&actual, expected.raw());
+    let errors = parse(&actual);
+    assert_eq!(errors, [], "`{actual}` is invalid");
+}
+
+#[test]
+fn unquoted_key_invalid_chars() {
+    t(
+        r#"hello$world = 1
+"#,
+        str![[r#"
+"hello$world" = 1
+
+"#]],
+    );
+    t(
+        r#"a."b".c\d@e = 1
+"#,
+        str![[r#"
+a."b"."c\\d@e" = 1
+
+"#]],
+    );
+}
+
+#[test]
+fn inline_table_colon_sep() {
+    t(
+        r#"point = {x: 1, y: 2}
+"#,
+        str![[r#"
+point = {x = 1, y = 2}
+
+"#]],
+    );
+}
+
+#[test]
+fn unclosed_table() {
+    t(
+        r#"[server
+port = 80
+"#,
+        str![[r#"
+[server]
+port = 80
+
+"#]],
+    );
+    t(
+        r#"[[server]
+port = 80
+"#,
+        str![[r#"
+[[server]]
+port = 80
+
+"#]],
+    );
+    t(
+        r#"[[server
+port = 80
+"#,
+        str![[r#"
+[[server]]
+port = 80
+
+"#]],
+    );
+}
+
+#[test]
+fn unclosed_array() {
+    t(r#"ports = [80, 443"#, str!["ports = [80, 443]"]);
+}
+
+#[test]
+fn unclosed_inline_table() {
+    t(r#"point = {x = 1, y = 2"#, str!["point = {x = 1, y = 2}"]);
+}
+
+#[test]
+fn invalid_escape() {
+    t(
+        r#"path = "C:\Program Files\app"
+"#,
+        str![[r#"
+path = "C:\\Program Files\\app"
+
+"#]],
+    );
+}
+
+#[test]
+fn unpadded_datetime() {
+    t(
+        r#"date = 1979-5-27T7:32:00.5-7:00
+"#,
+        str![[r#"
+date = 1979-05-27T07:32:00.5-07:00
+
+"#]],
+    );
+    t(
+        r#"time = 7:32:00
+"#,
+        str![[r#"
+time = 07:32:00
+
+"#]],
+    );
+}
+
+#[test]
+fn no_fix() {
+    let input = r#"key = "value" value
+"#;
+    let errors = parse(input);
+    assert!(!errors.is_empty());
+    assert_data_eq!(apply_fixes(input, &errors), input.raw());
+}
@@ -0,0 +1,212 @@
+use snapbox::assert_data_eq;
+use snapbox::prelude::*;
+use snapbox::str;
+
+use toml_parser::Diagnostic;
+use toml_parser::Fix;
+use toml_parser::Label;
+use toml_parser::ParseError;
+use toml_parser::ParseErrorKind;
+use toml_parser::Span;
+
+const INPUT: &str = "\
+[package]
+name = \"foo\"
+version = \"0.1.0\"
+edition = \"2021\"
+authors = []
+description = \"\"
+
+[package]
+";
+
+fn redefinition() -> Diagnostic<'static> {
+    Diagnostic::new(INPUT, "duplicate key")
+        .with_label(Label::primary(Span::new_unchecked(90, 97)))
+        .with_label(
+            Label::secondary(Span::new_unchecked(1, 8)).with_message("originally defined here"),
+        )
+}
+
+#[test]
+fn single_label() {
+    let diagnostic = Diagnostic::new(INPUT, "invalid string")
+        .with_label(Label::primary(Span::new_unchecked(17, 22)));
+    assert_data_eq!(
+        diagnostic.to_string(),
+        str![[r#"
+TOML parse error at line 2, column 8
+  |
+2 | name = "foo"
+  |        ^^^^^
+invalid string
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn elide_distant_lines() {
+    assert_data_eq!(
+        redefinition().to_string(),
+        str![[r#"
+TOML parse error at line 8, column 2
+  |
+1 | [package]
+  |  ------- originally defined here
+...
+8 | [package]
+  |  ^^^^^^^
+duplicate key
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn labels_on_one_line() {
+    let diagnostic = Diagnostic::new(INPUT, "expected a string")
+        .with_label(Label::primary(Span::new_unchecked(68, 70)).with_message("found an array"))
+        .with_label(Label::secondary(Span::new_unchecked(58, 65)).with_message("for this key"));
+    assert_data_eq!(
+        diagnostic.to_string(),
+        str![[r#"
+TOML parse error at line 5, column 11
+  |
+5 | authors = []
+  | ------- for this key
+  |           ^^ found an array
+expected a string
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn notes_and_help() {
+    let diagnostic = redefinition()
+        .with_note("tables may only be defined once")
+        .with_help("merge the tables");
+    assert_data_eq!(
+        diagnostic.to_string(),
+        str![[r#"
+TOML parse error at line 8, column 2
+  |
+1 | [package]
+  |  ------- originally defined here
+...
+8 | [package]
+  |  ^^^^^^^
+duplicate key
+note: tables may only be defined once
+help: merge the tables
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn no_labels() {
+    let diagnostic = Diagnostic::new(INPUT, "empty document").with_help("add a table");
+    assert_data_eq!(
+        diagnostic.to_string(),
+        str![[r#"
+empty document
+help: add a table
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "color")]
+fn styled() {
+    let diagnostic = redefinition();
+    let styled = diagnostic.styled().to_string();
+    assert!(styled.contains('\x1b'), "{styled:?}");
+    assert_eq!(
+        anstream::adapter::strip_str(&styled).to_string(),
+        diagnostic.to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn to_codespan() {
+    use codespan_reporting::diagnostic::LabelStyle;
+
+    let diagnostic = redefinition().with_help("merge the tables");
+    let codespan = diagnostic.to_codespan(());
+    assert_eq!(codespan.message, "duplicate key");
+    let labels = codespan
+        .labels
+        .iter()
+        .map(|label| (label.style, label.range.clone(), label.message.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        labels,
+        [
+            (LabelStyle::Primary, 90..97, ""),
+            (LabelStyle::Secondary, 1..8, "originally defined here"),
+        ]
+    );
+    assert_eq!(codespan.notes, ["help: merge the tables"]);
+}
+
+#[test]
+fn parse_error() {
+    let error = ParseError::new("duplicate key")
+        .with_unexpected(Span::new_unchecked(90, 97))
+        .with_context(Span::new_unchecked(1, 8))
+        .with_kind(ParseErrorKind::TableRedefinition)
+        .with_fix(Fix::new(Span::new_unchecked(90, 97), "package.metadata"));
+    let diagnostic = Diagnostic::new(INPUT, error.description())
+        .with_label(Label::primary(error.unexpected().unwrap()))
+        .with_parse_error(&error);
+    assert_data_eq!(
+        diagnostic.to_string(),
+        str![[r#"
+TOML parse error at line 8, column 2
+  |
+1 | [package]
+  |  ------- originally defined here
+...
+8 | [package]
+  |  ^^^^^^^
+duplicate key
+help: replace `package` with `package.metadata`
+
+"#]]
+    );
+}
+
+#[test]
+fn parse_error_fix() {
+    let help = |fix: Fix| {
+        let error = ParseError::new("invalid").with_fix(fix);
+        Diagnostic::new(INPUT, error.description())
+            .with_parse_error(&error)
+            .help()
+            .to_vec()
+    };
+    assert_eq!(help(Fix::insert(8, "]")), ["insert `]`"]);
+    assert_eq!(
+        help(Fix::new(Span::new_unchecked(0, 1), "")),
+        ["remove `[`"]
+    );
+    assert_eq!(
+        help(Fix::new(Span::new_unchecked(10, 14), "title")),
+        ["replace `name` with `title`"]
+    );
+
+    let error = ParseError::new("invalid")
+        .with_context(Span::new_unchecked(1, 8))
+        .with_kind(ParseErrorKind::Syntax);
+    let diagnostic = Diagnostic::new(INPUT, error.description()).with_parse_error(&error);
+    assert_eq!(diagnostic.labels(), []);
+}
@@ -0,0 +1,208 @@
+use snapbox::assert_data_eq;
+use snapbox::prelude::*;
+use snapbox::str;
+
+use toml_parser::ParseError;
+use toml_parser::Span;
+use toml_parser::embed::*;
+use toml_parser::parser::parse_document;
+
+#[track_caller]
+fn t(host: &str, embedded: Option<Embedded<'_>>, expected: impl IntoData) {
+    let actual = embedded.map(|embedded| {
+        assert_eq!(
+            &host[embedded.span().start()..embedded.span().end()],
+            embedded.input()
+        );
+        embedded.input()
+    });
+    assert_data_eq!(actual.to_debug(), expected.raw());
+}
+
+#[test]
+fn plus_front_matter_basic() {
+    let host = "+++\ntitle = 'Hello'\n+++\n# Hello\n";
+    t(
+        host,
+        plus_front_matter(host),
+        str![[r#"
+Some(
+    "title = 'Hello'\n",
+)
+
+"#]],
+    );
+}
+
+#[test]
+fn plus_front_matter_crlf() {
+    let host = "\u{FEFF}+++ \r\ntitle = 'Hello'\r\n+++\r\n";
+    t(
+        host,
+        plus_front_matter(host),
+        str![[r#"
+Some(
+    "title = 'Hello'\r\n",
+)
+
+"#]],
+    );
+}
+
+#[test]
+fn plus_front_matter_missing() {
+    let host = "# Hello\n+++\ntitle = 'Hello'\n+++\n";
+    t(
+        host,
+        plus_front_matter(host),
+        str![[r#"
+None
+
+"#]],
+    );
+
+    let host = "+++\ntitle = 'Hello'\n";
+    t(
+        host,
+        plus_front_matter(host),
+        str![[r#"
+None
+
+"#]],
+    );
+}
+
+#[test]
+fn cargo_front_matter_basic() {
+    let host =
+        "#!/usr/bin/env cargo\n\n---cargo\n[dependencies]\nclap = '4'\n---\n\nfn main() {}\n";
+    t(
+        host,
+        cargo_front_matter(host),
+        str![[r#"
+Some(
+    "[dependencies]\nclap = '4'\n",
+)
+
+"#]],
+    );
+}
+
+#[test]
+fn cargo_front_matter_dashes() {
+    let host = "-----\npackage.edition = '2024'\n---\n-----\n";
+    t(
+        host,
+        cargo_front_matter(host),
+        str![[r#"
+Some(
+    "package.edition = '2024'\n---\n",
+)
+
+"#]],
+    );
+}
+
+#[test]
+fn cargo_front_matter_missing() {
+    let host = "#![allow(dead_code)]\n---\n[dependencies]\n---\n";
+    t(
+        host,
+        cargo_front_matter(host),
+        str![[r#"
+None
+
+"#]],
+    );
+
+    let host = "---json\n{}\n---\n";
+    t(
+        host,
+        cargo_front_matter(host),
+        str![[r#"
+None
+
+"#]],
+    );
+
+    let host = "fn main() {}\n---\n[dependencies]\n---\n";
+    t(
+        host,
+        cargo_front_matter(host),
+        str![[r#"
+None
+
+"#]],
+    );
+}
+
+#[test]
+fn code_blocks() {
+    let host = r#"# Config
+
+```toml
+a = 1
+```
+
+````markdown
+```toml
+b = 2
+```
+````
+
+~~~ toml title="Cargo.toml"
+c = 3
+~~~
+
+```toml
+d = 4
+"#;
+    let actual = markdown_code_blocks(host)
+        .inspect(|embedded| {
+            assert_eq!(
+                &host[embedded.span().start()..embedded.span().end()],
+                embedded.input()
+            );
+        })
+        .map(|embedded| embedded.input())
+        .collect::<Vec<_>>();
+    assert_data_eq!(
+        actual.to_debug(),
+        str![[r#"
+[
+    "a = 1\n",
+    "c = 3\n",
+    "d = 4\n",
+]
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn host_relative_spans() {
+    let host = "+++\ntitle = 'Hello'\nbad = ]\n+++\n";
+    let embedded = plus_front_matter(host).unwrap();
+    let source = embedded.source();
+    let tokens = source.lex().into_vec();
+    let mut events = Vec::new();
+    let mut errors = Vec::<ParseError>::new();
+    parse_document(&tokens, &mut events, &mut errors);
+
+    assert_eq!(events.first().unwrap().span().start(), 4);
+    assert_eq!(events.last().unwrap().span().end(), host.len() - 4);
+    for event in &events {
+        let raw = source.get(event).unwrap();
+        assert_eq!(
+            &host[event.span().start()..event.span().end()],
+            raw.as_str()
+        );
+    }
+    let unexpected = errors
+        .iter()
+        .filter_map(|e| e.unexpected())
+        .collect::<Vec<_>>();
+    assert_eq!(unexpected, [Span::new_unchecked(26, 26)]);
+    assert_eq!(&host[26..], "]\n+++\n");
+}
@@ -11,9 +11,13 @@ use toml_parser::Span;
 use toml_parser::decoder::ScalarKind;
 use toml_parser::parser::*;
 
+mod apply_fixes;
+mod diagnostic;
+mod embed;
 mod parse_document;
 mod parse_simple_key;
 mod parse_value;
+mod semantic;
 
 #[derive(Debug)]
 pub struct EventResults<'i> {
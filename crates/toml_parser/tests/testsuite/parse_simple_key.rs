@@ -34,3 +34,26 @@ fn simple_key_string() {
 fn simple_key_string_literal() {
     t(r"'hello\n '", file![_].raw());
 }
+
+#[test]
+fn error_key() {
+    let input = r#"a = 1
+"a" = 2
+'\n' = 3
+"é" = 4
+"#;
+    let key = |raw: &str| {
+        let start = input.find(raw).unwrap();
+        toml_parser::ParseError::new("duplicate key")
+            .with_unexpected(toml_parser::Span::new_unchecked(start, start + raw.len()))
+            .key(input)
+    };
+    assert_eq!(key("a").as_deref(), Some("a"));
+    assert_eq!(key(r#""a""#).as_deref(), Some("a"));
+    assert_eq!(key(r"'\n'").as_deref(), Some(r"\n"));
+    assert_eq!(key(r#""é""#).as_deref(), Some("é"));
+    let out_of_bounds = toml_parser::ParseError::new("duplicate key")
+        .with_unexpected(toml_parser::Span::new_unchecked(0, input.len() + 1));
+    assert_eq!(out_of_bounds.key(input), None);
+    assert_eq!(toml_parser::ParseError::new("syntax").key(input), None);
+}
@@ -0,0 +1,176 @@
+use std::fmt::Write as _;
+
+use snapbox::assert_data_eq;
+use snapbox::prelude::*;
+use snapbox::str;
+
+use toml_parser::Source;
+use toml_parser::semantic::*;
+
+#[track_caller]
+fn t(input: &str, expected: impl IntoData) {
+    dbg!(input);
+    let source = Source::new(input);
+    let tokens = classify_document(source);
+
+    let mut actual = String::new();
+    let mut previous_end = 0;
+    for token in &tokens {
+        assert!(
+            previous_end <= token.span().start(),
+            "tokens must be in document order"
+        );
+        assert!(!token.span().is_empty(), "tokens must not be empty");
+        previous_end = token.span().end();
+
+        let raw = source.get(token.span()).unwrap();
+        write!(&mut actual, "{:?} {:?}", raw.as_str(), token.kind()).unwrap();
+        if !token.modifiers().is_empty() {
+            write!(&mut actual, " {:?}", token.modifiers()).unwrap();
+        }
+        writeln!(&mut actual).unwrap();
+    }
+    assert_data_eq!(actual, expected.raw());
+}
+
+#[test]
+fn document() {
+    t(
+        r#"# comment
+title = "TOML"
+
+[owner.info]
+dob = 1979-05-27T07:32:00-08:00
+enabled = true
+
+[[products]]
+ports = [ 8000, 8001 ]
+point = { x = 1.5, "y" = -inf }
+"#,
+        str![[r##"
+"# comment" Comment
+"title" Key
+"=" Operator
+"\"TOML\"" String {quoted}
+"[" Punctuation
+"owner" TableKey {dotted}
+"." Punctuation {dotted}
+"info" TableKey {dotted}
+"]" Punctuation
+"dob" Key
+"=" Operator
+"1979-05-27T07:32:00-08:00" Datetime
+"enabled" Key
+"=" Operator
+"true" Boolean
+"[[" Punctuation {arrayTable}
+"products" TableKey {arrayTable}
+"]]" Punctuation {arrayTable}
+"ports" Key
+"=" Operator
+"[" Punctuation
+"8000" Integer
+"," Punctuation
+"8001" Integer
+"]" Punctuation
+"point" Key
+"=" Operator
+"{" Punctuation
+"x" Key
+"=" Operator
+"1.5" Float
+"," Punctuation
+"\"y\"" Key {quoted}
+"=" Operator
+"-inf" Float
+"}" Punctuation
+
+"##]],
+    );
+}
+
+#[test]
+fn dotted_keys() {
+    t(
+        r#"a . 'b' . c = 1
+d = 2
+"#,
+        str![[r#"
+"a" Key {dotted}
+"." Punctuation {dotted}
+"'b'" Key {dotted, quoted, literal}
+"." Punctuation {dotted}
+"c" Key {dotted}
+"=" Operator
+"1" Integer
+"d" Key
+"=" Operator
+"2" Integer
+
+"#]],
+    );
+}
+
+#[test]
+fn escape_sequences() {
+    t(
+        r#"a = "tab\there\u00E9"
+"key\"quote" = '\n'
+b = """
+one \
+  two\x41"""
+"#,
+        str![[r#"
+"a" Key
+"=" Operator
+"\"tab" String {quoted}
+"\\t" EscapeSequence {quoted}
+"here" String {quoted}
+"\\u00E9" EscapeSequence {quoted}
+"\"" String {quoted}
+"\"key" Key {quoted}
+"\\\"" EscapeSequence {quoted}
+"quote\"" Key {quoted}
+"=" Operator
+"'\\n'" String {quoted, literal}
+"b" Key
+"=" Operator
+"\"\"\"\none " String {quoted, multiline}
+"\\\n  " EscapeSequence {quoted, multiline}
+"two" String {quoted, multiline}
+"\\x41" EscapeSequence {quoted, multiline}
+"\"\"\"" String {quoted, multiline}
+
+"#]],
+    );
+}
+
+#[test]
+fn errors() {
+    t(
+        r#"a = bare
+[table
+b = = 1
+c = [1, {x = 2
+"#,
+        str![[r#"
+"a" Key
+"=" Operator
+"bare" BareString
+"[" Punctuation
+"table" TableKey
+"b" Key
+"=" Operator
+"c" Key
+"=" Operator
+"[" Punctuation
+"1" Integer
+"," Punctuation
+"{" Punctuation
+"x" Key
+"=" Operator
+"2" Integer
+
+"#]],
+    );
+}
@@ -0,0 +1,364 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::TomlWrite;
+use crate::WriteTomlValue;
+
+/// Write a TOML document incrementally, enforcing TOML's rules for defining tables and keys
+///
+/// Unlike [`TomlWrite`], this tracks the current table and every key and table defined so far,
+/// reporting a [`DocumentError`] rather than writing an invalid document, for example when
+/// - A key is defined twice in the same table
+/// - A table header is written twice
+/// - A table header is written for a key that is already a value
+/// - A table and an array of tables share the same name
+///
+/// Only the keys are tracked, not the values, so large documents can be written without first
+/// building the whole document in memory.
+/// Starting a new element of an array of tables forgets the keys of the previous element.
+///
+/// # Example
+///
+/// ```rust
+/// use toml_writer::DocumentWriter;
+///
+/// # fn main() -> Result<(), toml_writer::DocumentError> {
+/// let mut output = String::new();
+/// let mut doc = DocumentWriter::new(&mut output);
+/// doc.keyval("title", "Example")?;
+/// doc.begin_table(&["owner"])?;
+/// doc.keyval("name", "Tom")?;
+/// doc.begin_array_of_tables(&["servers"])?;
+/// doc.comment("primary")?;
+/// doc.keyval("port", 8080)?;
+/// doc.begin_array_of_tables(&["servers"])?;
+/// doc.keyval("port", 8081)?;
+/// assert!(doc.keyval("port", 8082).is_err());
+///
+/// assert_eq!(output, r#"title = "Example"
+///
+/// [owner]
+/// name = "Tom"
+///
+/// [[servers]]
+/// ## primary
+/// port = 8080
+///
+/// [[servers]]
+/// port = 8081
+/// "#);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct DocumentWriter<W> {
+    writer: W,
+    root: Node,
+    current: Vec<String>,
+    is_empty: bool,
+}
+
+impl<W: TomlWrite> DocumentWriter<W> {
+    /// Start writing a document at the root table
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            root: Node::new(Kind::Table { header: true }),
+            current: Vec::new(),
+            is_empty: true,
+        }
+    }
+
+    /// Write a `[path]` table header, making it the current table
+    ///
+    /// Parent tables that have not been defined yet are created implicitly and may have their
+    /// own header written later.
+    /// When a parent is an array of tables, the table is nested in its most recent element.
+    pub fn begin_table(&mut self, path: &[&str]) -> Result<(), DocumentError> {
+        self.check(path, false)?;
+        self.write_header(path, false)?;
+        self.define(path, false);
+        self.set_current(path);
+        Ok(())
+    }
+
+    /// Write a `[[path]]` header, starting a new element of the array of tables and making it
+    /// the current table
+    pub fn begin_array_of_tables(&mut self, path: &[&str]) -> Result<(), DocumentError> {
+        self.check(path, true)?;
+        self.write_header(path, true)?;
+        self.define(path, true);
+        self.set_current(path);
+        Ok(())
+    }
+
+    /// Write a `key = value` line to the current table
+    pub fn keyval(&mut self, key: &str, value: impl WriteTomlValue) -> Result<(), DocumentError> {
+        let mut table = &mut self.root;
+        for segment in &self.current {
+            table = table
+                .children
+                .get_mut(segment)
+                .expect("current table is always defined");
+        }
+        if table.children.contains_key(key) {
+            let mut path = self.current.clone();
+            path.push(String::from(key));
+            return Err(DocumentError::new(DocumentErrorKind::DuplicateKey, &path));
+        }
+
+        self.writer.key(key)?;
+        self.writer.space()?;
+        self.writer.keyval_sep()?;
+        self.writer.space()?;
+        self.writer.value(value)?;
+        self.writer.newline()?;
+        self.is_empty = false;
+
+        table
+            .children
+            .insert(String::from(key), Node::new(Kind::Value));
+        Ok(())
+    }
+
+    /// Write a `# comment` line
+    ///
+    /// The comment may not contain newlines or other control characters besides tabs.
+    pub fn comment(&mut self, comment: &str) -> Result<(), DocumentError> {
+        if comment.chars().any(|c| c.is_control() && c != '\t') {
+            return Err(DocumentError::new(
+                DocumentErrorKind::InvalidComment,
+                &self.current,
+            ));
+        }
+
+        self.writer.open_comment()?;
+        if !comment.is_empty() {
+            self.writer.space()?;
+            write!(self.writer, "{comment}")?;
+        }
+        self.writer.newline()?;
+        self.is_empty = false;
+        Ok(())
+    }
+
+    /// The path of the current table, empty for the root table
+    pub fn current_table(&self) -> impl Iterator<Item = &str> {
+        self.current.iter().map(|s| s.as_str())
+    }
+
+    /// Get a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Unwrap the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Check that `path` can be defined, without changing what is defined
+    fn check(&self, path: &[&str], array: bool) -> Result<(), DocumentError> {
+        if path.is_empty() {
+            return Err(DocumentError::new(DocumentErrorKind::EmptyPath, path));
+        }
+
+        let mut table = Some(&self.root);
+        for (i, segment) in path.iter().enumerate() {
+            let Some(node) = table.and_then(|t| t.children.get(*segment)) else {
+                break;
+            };
+            let is_last = i + 1 == path.len();
+            let kind = match node.kind {
+                Kind::Value => Some(DocumentErrorKind::DuplicateKey),
+                Kind::Table { header: true } if is_last && !array => {
+                    Some(DocumentErrorKind::DuplicateTable)
+                }
+                Kind::Table { .. } if is_last && array => Some(DocumentErrorKind::ExistingTable),
+                Kind::ArrayOfTables if is_last && !array => {
+                    Some(DocumentErrorKind::ExistingArrayOfTables)
+                }
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                return Err(DocumentError::new(kind, &path[..=i]));
+            }
+            table = Some(node);
+        }
+        Ok(())
+    }
+
+    /// Define `path`, after [`Self::check`] passed and its header was written
+    fn define(&mut self, path: &[&str], array: bool) {
+        let (last, parents) = path.split_last().expect("checked path is non-empty");
+        let mut table = &mut self.root;
+        for segment in parents {
+            table = table
+                .children
+                .entry(String::from(*segment))
+                .or_insert_with(|| Node::new(Kind::Table { header: false }));
+        }
+        let kind = if array {
+            Kind::ArrayOfTables
+        } else {
+            Kind::Table { header: true }
+        };
+        let node = table
+            .children
+            .entry(String::from(*last))
+            .or_insert_with(|| Node::new(kind));
+        node.kind = kind;
+        if array {
+            // Start a new element
+            node.children.clear();
+        }
+    }
+
+    fn write_header(&mut self, path: &[&str], array: bool) -> Result<(), DocumentError> {
+        if !self.is_empty {
+            self.writer.newline()?;
+        }
+        if array {
+            self.writer.open_array_of_tables_header()?;
+        } else {
+            self.writer.open_table_header()?;
+        }
+        for (i, segment) in path.iter().enumerate() {
+            if i != 0 {
+                self.writer.key_sep()?;
+            }
+            self.writer.key(*segment)?;
+        }
+        if array {
+            self.writer.close_array_of_tables_header()?;
+        } else {
+            self.writer.close_table_header()?;
+        }
+        self.writer.newline()?;
+        self.is_empty = false;
+        Ok(())
+    }
+
+    fn set_current(&mut self, path: &[&str]) {
+        self.current.clear();
+        self.current.extend(path.iter().map(|s| String::from(*s)));
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    kind: Kind,
+    /// For an array of tables, the keys of its most recent element
+    children: BTreeMap<String, Self>,
+}
+
+impl Node {
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    /// `header` is `false` for tables only implicitly created as the parent of another table
+    Table {
+        header: bool,
+    },
+    ArrayOfTables,
+    Value,
+}
+
+/// Error returned by [`DocumentWriter`]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DocumentError {
+    kind: DocumentErrorKind,
+    path: String,
+}
+
+impl DocumentError {
+    /// What went wrong
+    pub fn kind(&self) -> DocumentErrorKind {
+        self.kind
+    }
+
+    /// The dotted path of the offending key or table, empty when there is none
+    ///
+    /// Keys are quoted as needed, like they would be written in the document.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn new(kind: DocumentErrorKind, path: &[impl AsRef<str>]) -> Self {
+        let mut rendered = String::new();
+        for (i, segment) in path.iter().enumerate() {
+            if i != 0 {
+                let _ = rendered.key_sep();
+            }
+            let _ = rendered.key(segment.as_ref());
+        }
+        Self {
+            kind,
+            path: rendered,
+        }
+    }
+}
+
+impl From<core::fmt::Error> for DocumentError {
+    fn from(_: core::fmt::Error) -> Self {
+        Self {
+            kind: DocumentErrorKind::Fmt,
+            path: String::new(),
+        }
+    }
+}
+
+impl core::fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let path = &self.path;
+        match self.kind {
+            DocumentErrorKind::Fmt => write!(f, "failed to write document"),
+            DocumentErrorKind::EmptyPath => write!(f, "table path must not be empty"),
+            DocumentErrorKind::DuplicateKey => write!(f, "duplicate key `{path}`"),
+            DocumentErrorKind::DuplicateTable => write!(f, "duplicate table `{path}`"),
+            DocumentErrorKind::ExistingArrayOfTables => {
+                write!(f, "`{path}` is already defined as an array of tables")
+            }
+            DocumentErrorKind::ExistingTable => {
+                write!(f, "`{path}` is already defined as a table")
+            }
+            DocumentErrorKind::InvalidComment => {
+                write!(
+                    f,
+                    "comment may not contain control characters other than tab"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for DocumentError {}
+
+/// The kind of [`DocumentError`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DocumentErrorKind {
+    /// The underlying writer failed
+    Fmt,
+    /// A table header was requested for the root table
+    EmptyPath,
+    /// The key is already defined in its table
+    DuplicateKey,
+    /// The table header was already written
+    DuplicateTable,
+    /// A table header was written for an array of tables
+    ExistingArrayOfTables,
+    /// An array of tables header was written for a table
+    ExistingTable,
+    /// The comment contains control characters
+    InvalidComment,
+}
@@ -0,0 +1,241 @@
+use core::fmt::{self, Display, LowerExp};
+
+use crate::grouping::{Grouping, write_grouped};
+
+/// Describes how a TOML float should be formatted.
+///
+/// Output always parses as a TOML float, e.g. integral values get a `.0`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// # use toml_writer::ToTomlValue as _;
+/// let format = toml_writer::TomlFloatFormat::new().as_fixed(2);
+/// assert_eq!(format.format(10.0).to_toml_value(), "10.00");
+///
+/// let format = toml_writer::TomlFloatFormat::new().with_exponent_above(1e6);
+/// assert_eq!(format.format(6.02214076e23).to_toml_value(), "6.02214076e23");
+/// assert_eq!(format.format(1000.0).to_toml_value(), "1000.0");
+///
+/// let format = toml_writer::TomlFloatFormat::new().with_plus_sign(true);
+/// assert_eq!(format.format(f64::INFINITY).to_toml_value(), "+inf");
+///
+/// let format = toml_writer::TomlFloatFormat::new().with_grouping(true);
+/// assert_eq!(format.format(1234567.125).to_toml_value(), "1_234_567.125");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct TomlFloatFormat {
+    precision: Option<usize>,
+    exponent_above: Option<f64>,
+    exponent_below: Option<f64>,
+    plus_sign: bool,
+    nan_sign: bool,
+    grouping: Grouping,
+}
+
+impl TomlFloatFormat {
+    /// Creates a new float format (shortest decimal that round-trips).
+    pub fn new() -> Self {
+        Self {
+            precision: None,
+            exponent_above: None,
+            exponent_below: None,
+            plus_sign: false,
+            nan_sign: true,
+            grouping: Grouping::None,
+        }
+    }
+
+    /// Sets the format to the shortest digits that parse back to the same value.
+    pub fn as_shortest(mut self) -> Self {
+        self.precision = None;
+        self
+    }
+
+    /// Sets the format to a fixed number of digits after the decimal point, rounding the value.
+    ///
+    /// This is also the number of digits after the decimal point of the mantissa in exponent
+    /// notation.
+    ///
+    /// A precision of `0` is written with a `.0` so the value remains a float.
+    pub fn as_fixed(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the format to use exponent notation for values with a magnitude of at least
+    /// `threshold`.
+    pub fn with_exponent_above(mut self, threshold: f64) -> Self {
+        self.exponent_above = Some(threshold);
+        self
+    }
+
+    /// Sets the format to use exponent notation for non-zero values with a magnitude of less
+    /// than `threshold`.
+    pub fn with_exponent_below(mut self, threshold: f64) -> Self {
+        self.exponent_below = Some(threshold);
+        self
+    }
+
+    /// Sets whether to write a `+` for positive values, including `+inf` and `+nan`.
+    pub fn with_plus_sign(mut self, yes: bool) -> Self {
+        self.plus_sign = yes;
+        self
+    }
+
+    /// Sets whether to write the sign bit of NaN, as `-nan` (default `true`).
+    ///
+    /// See the warning on [`TomlWrite::value`][crate::TomlWrite::value].
+    pub fn with_nan_sign(mut self, yes: bool) -> Self {
+        self.nan_sign = yes;
+        self
+    }
+
+    /// Sets whether to separate groups of 3 digits in the integer part with `_`, e.g.
+    /// `1_000_000.5`.
+    pub fn with_grouping(mut self, yes: bool) -> Self {
+        self.grouping = if yes {
+            Grouping::Default
+        } else {
+            Grouping::None
+        };
+        self
+    }
+
+    /// Sets the number of integer digits between each `_`, overriding the default of
+    /// [`TomlFloatFormat::with_grouping`].
+    ///
+    /// A `size` of `0` disables grouping.
+    pub fn with_group_size(mut self, size: usize) -> Self {
+        self.grouping = Grouping::Size(size);
+        self
+    }
+
+    /// Formats `value` as a TOML float.
+    pub fn format<N>(self, value: N) -> TomlFloat<N>
+    where
+        TomlFloat<N>: crate::WriteTomlValue,
+    {
+        TomlFloat {
+            value,
+            format: self,
+        }
+    }
+}
+
+impl Default for TomlFloatFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Helper struct for formatting TOML floats.
+///
+/// This may be constructed by calling [`TomlFloatFormat::format()`].
+#[derive(Copy, Clone, Debug)]
+pub struct TomlFloat<N> {
+    value: N,
+    format: TomlFloatFormat,
+}
+
+impl<N> TomlFloat<N>
+where
+    Self: crate::WriteTomlValue,
+{
+    /// Apply default formatting
+    pub fn new(value: N) -> Self {
+        Self {
+            value,
+            format: TomlFloatFormat::new(),
+        }
+    }
+}
+
+impl crate::WriteTomlValue for TomlFloat<f32> {
+    fn write_toml_value<W: crate::TomlWrite + ?Sized>(&self, writer: &mut W) -> fmt::Result {
+        let magnitude = if self.value.is_sign_negative() {
+            -self.value
+        } else {
+            self.value
+        };
+        let float = Float {
+            is_negative: self.value.is_sign_negative(),
+            is_nan: self.value.is_nan(),
+            is_infinite: self.value.is_infinite(),
+            is_integral: magnitude % 1.0 == 0.0,
+            magnitude,
+            magnitude_f64: f64::from(magnitude),
+        };
+        write_toml_value(float, &self.format, writer)
+    }
+}
+
+impl crate::WriteTomlValue for TomlFloat<f64> {
+    fn write_toml_value<W: crate::TomlWrite + ?Sized>(&self, writer: &mut W) -> fmt::Result {
+        let magnitude = if self.value.is_sign_negative() {
+            -self.value
+        } else {
+            self.value
+        };
+        let float = Float {
+            is_negative: self.value.is_sign_negative(),
+            is_nan: self.value.is_nan(),
+            is_infinite: self.value.is_infinite(),
+            is_integral: magnitude % 1.0 == 0.0,
+            magnitude,
+            magnitude_f64: magnitude,
+        };
+        write_toml_value(float, &self.format, writer)
+    }
+}
+
+struct Float<N> {
+    is_negative: bool,
+    is_nan: bool,
+    is_infinite: bool,
+    is_integral: bool,
+    magnitude: N,
+    magnitude_f64: f64,
+}
+
+fn write_toml_value<N: Display + LowerExp, W: crate::TomlWrite + ?Sized>(
+    value: Float<N>,
+    format: &TomlFloatFormat,
+    writer: &mut W,
+) -> fmt::Result {
+    let show_sign = !value.is_nan || format.nan_sign;
+    if value.is_negative && show_sign {
+        write!(writer, "-")?;
+    } else if format.plus_sign {
+        write!(writer, "+")?;
+    }
+
+    if value.is_nan {
+        return write!(writer, "nan");
+    }
+    if value.is_infinite {
+        return write!(writer, "inf");
+    }
+
+    let exponent = format
+        .exponent_above
+        .is_some_and(|threshold| threshold <= value.magnitude_f64)
+        || format
+            .exponent_below
+            .is_some_and(|threshold| value.magnitude_f64 != 0.0 && value.magnitude_f64 < threshold);
+    let magnitude = value.magnitude;
+    let size = format.grouping.size(3);
+    let digits = |writer: &mut W, digits| write_grouped(writer, digits, size, true);
+    match (exponent, format.precision) {
+        // Exponent notation is always a valid float, e.g. `1e10`
+        (true, None) => digits(writer, format_args!("{magnitude:e}")),
+        (true, Some(precision)) => digits(writer, format_args!("{magnitude:.precision$e}")),
+        // Decimal notation needs a fractional part
+        (false, None) if value.is_integral => digits(writer, format_args!("{magnitude}.0")),
+        (false, None) => digits(writer, format_args!("{magnitude}")),
+        (false, Some(0)) => digits(writer, format_args!("{magnitude:.0}.0")),
+        (false, Some(precision)) => digits(writer, format_args!("{magnitude:.precision$}")),
+    }
+}
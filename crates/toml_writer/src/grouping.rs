@@ -0,0 +1,114 @@
+use core::fmt;
+
+/// How digits are separated with `_`, e.g. `1_000_000`
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Grouping {
+    None,
+    /// Use the group size natural to the radix
+    Default,
+    Size(usize),
+}
+
+impl Grouping {
+    pub(crate) fn size(self, default: usize) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Default => default,
+            Self::Size(size) => size,
+        }
+    }
+}
+
+/// Write `digits`, separating the integer digits into groups of `size`, counting from the right
+///
+/// For floats, the integer digits end at the `.` or exponent.
+pub(crate) fn write_grouped<W: fmt::Write + ?Sized>(
+    writer: &mut W,
+    digits: fmt::Arguments<'_>,
+    size: usize,
+    is_float: bool,
+) -> fmt::Result {
+    if size == 0 {
+        return writer.write_fmt(digits);
+    }
+
+    let mut counter = Counter {
+        digits: IntegerDigits::new(is_float),
+        count: 0,
+    };
+    fmt::write(&mut counter, digits)?;
+
+    let mut grouper = Grouper {
+        writer,
+        digits: IntegerDigits::new(is_float),
+        size,
+        remaining: counter.count,
+        written: false,
+    };
+    fmt::write(&mut grouper, digits)
+}
+
+struct IntegerDigits {
+    is_float: bool,
+    done: bool,
+}
+
+impl IntegerDigits {
+    fn new(is_float: bool) -> Self {
+        Self {
+            is_float,
+            done: false,
+        }
+    }
+
+    fn is_digit(&mut self, c: char) -> bool {
+        if self.done {
+            return false;
+        }
+        if self.is_float && matches!(c, '.' | 'e' | 'E') {
+            self.done = true;
+            return false;
+        }
+        c.is_ascii_alphanumeric()
+    }
+}
+
+struct Counter {
+    digits: IntegerDigits,
+    count: usize,
+}
+
+impl fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.digits.is_digit(c) {
+                self.count += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Grouper<'w, W: ?Sized> {
+    writer: &'w mut W,
+    digits: IntegerDigits,
+    size: usize,
+    remaining: usize,
+    written: bool,
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for Grouper<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.digits.is_digit(c) {
+                if self.written && self.remaining % self.size == 0 {
+                    self.writer.write_char('_')?;
+                }
+                self.remaining -= 1;
+                self.written = true;
+            }
+            self.writer.write_char(c)?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display};
 
+use crate::grouping::{Grouping, write_grouped};
+
 /// Describes how a TOML integer should be formatted.
 ///
 /// # Example
@@ -12,11 +14,18 @@ use core::fmt::{self, Display};
 /// let number = format.format(number).unwrap_or(toml_writer::TomlInteger::new(number));
 /// let number = number.to_toml_value();
 /// assert_eq!(number, "0xa");
+///
+/// let format = toml_writer::TomlIntegerFormat::new().with_grouping(true);
+/// assert_eq!(format.format(1_000_000).unwrap().to_toml_value(), "1_000_000");
+///
+/// let format = format.as_hex_lower();
+/// assert_eq!(format.format(0xdeadbeef_u32).unwrap().to_toml_value(), "0xdead_beef");
 /// # }
 /// ```
 #[derive(Copy, Clone, Debug)]
 pub struct TomlIntegerFormat {
     radix: Radix,
+    grouping: Grouping,
 }
 
 impl TomlIntegerFormat {
@@ -24,6 +33,7 @@ impl TomlIntegerFormat {
     pub fn new() -> Self {
         Self {
             radix: Radix::Decimal,
+            grouping: Grouping::None,
         }
     }
 
@@ -61,18 +71,39 @@ impl TomlIntegerFormat {
         self
     }
 
+    /// Sets whether to separate groups of digits with `_`, e.g. `1_000_000` or `0xdead_beef`.
+    ///
+    /// Decimal and octal digits are grouped by 3, hexadecimal and binary digits by 4.
+    pub fn with_grouping(mut self, yes: bool) -> Self {
+        self.grouping = if yes {
+            Grouping::Default
+        } else {
+            Grouping::None
+        };
+        self
+    }
+
+    /// Sets the number of digits between each `_`, overriding the default of
+    /// [`TomlIntegerFormat::with_grouping`].
+    ///
+    /// A `size` of `0` disables grouping.
+    pub fn with_group_size(mut self, size: usize) -> Self {
+        self.grouping = Grouping::Size(size);
+        self
+    }
+
     /// Formats `value` as a TOML integer.
     ///
     /// Returns `None` if the value cannot be formatted
     /// (e.g. value is negative and the radix is not decimal).
-    pub fn format<N: PartialOrd<i32>>(self, value: N) -> Option<TomlInteger<N>>
+    pub fn format<N: PartialOrd + Default>(self, value: N) -> Option<TomlInteger<N>>
     where
         TomlInteger<N>: crate::WriteTomlValue,
     {
         match self.radix {
             Radix::Decimal => (),
             Radix::Hexadecimal { .. } | Radix::Octal | Radix::Binary => {
-                if value < 0 {
+                if value < N::default() {
                     return None;
                 }
             }
@@ -208,13 +239,28 @@ fn write_toml_value<
     writer: &mut W,
 ) -> fmt::Result {
     match format.radix {
-        Radix::Decimal => write!(writer, "{value}")?,
-        Radix::Hexadecimal { case } => match case {
-            HexCase::Upper => write!(writer, "0x{value:X}")?,
-            HexCase::Lower => write!(writer, "0x{value:x}")?,
-        },
-        Radix::Octal => write!(writer, "0o{value:o}")?,
-        Radix::Binary => write!(writer, "0b{value:b}")?,
+        Radix::Decimal => {
+            let size = format.grouping.size(3);
+            write_grouped(writer, format_args!("{value}"), size, false)?;
+        }
+        Radix::Hexadecimal { case } => {
+            let size = format.grouping.size(4);
+            write!(writer, "0x")?;
+            match case {
+                HexCase::Upper => write_grouped(writer, format_args!("{value:X}"), size, false)?,
+                HexCase::Lower => write_grouped(writer, format_args!("{value:x}"), size, false)?,
+            }
+        }
+        Radix::Octal => {
+            let size = format.grouping.size(3);
+            write!(writer, "0o")?;
+            write_grouped(writer, format_args!("{value:o}"), size, false)?;
+        }
+        Radix::Binary => {
+            let size = format.grouping.size(4);
+            write!(writer, "0b")?;
+            write_grouped(writer, format_args!("{value:b}"), size, false)?;
+        }
     }
     Ok(())
 }
@@ -0,0 +1,280 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ToTomlKey as _;
+use crate::ToTomlValue as _;
+use crate::TomlWrite;
+use crate::WriteTomlKey;
+use crate::WriteTomlValue;
+
+/// Lay out arrays and inline tables to fit within a line width
+///
+/// In the spirit of Wadler's "prettier printer", each array or inline table is kept on one line
+/// when it fits within [`max_width`][TomlLayout::with_max_width].
+/// Otherwise it is broken into one element per line, indented by
+/// [`indent`][TomlLayout::with_indent] and with a trailing comma, before trying again to fit each
+/// element.
+///
+/// Breaking inline tables across lines requires TOML 1.1, so they are always kept on one line
+/// unless TOML 1.1 is opted into with [`with_version`][TomlLayout::with_version].
+///
+/// # Example
+///
+/// ```rust
+/// use toml_writer::LayoutValue;
+/// use toml_writer::TomlLayout;
+/// use toml_writer::TomlWrite as _;
+///
+/// # fn main() -> std::fmt::Result {
+/// let value = LayoutValue::Array(vec![
+///     LayoutValue::scalar("first"),
+///     LayoutValue::scalar("second"),
+///     LayoutValue::Array(vec![LayoutValue::scalar(1), LayoutValue::scalar(2)]),
+/// ]);
+///
+/// let mut output = String::new();
+/// output.push_str("short = ");
+/// TomlLayout::new().write(&mut output, &value, 8)?;
+/// output.newline()?;
+/// output.push_str("narrow = ");
+/// TomlLayout::new().with_max_width(20).write(&mut output, &value, 9)?;
+/// output.newline()?;
+///
+/// assert_eq!(output, r#"short = ["first", "second", [1, 2]]
+/// narrow = [
+///     "first",
+///     "second",
+///     [1, 2],
+/// ]
+/// "#);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TomlLayout {
+    max_width: usize,
+    indent: usize,
+    version: crate::TomlVersion,
+}
+
+impl TomlLayout {
+    /// Lay out values within 80 columns, indenting by 4 spaces, for TOML 1.0
+    pub fn new() -> Self {
+        Self {
+            max_width: 80,
+            indent: 4,
+            version: crate::TomlVersion::V1_0,
+        }
+    }
+
+    /// Sets the column that values should not extend beyond
+    ///
+    /// Scalars are never broken, so a line may still be wider than this when a single scalar
+    /// does not fit.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the number of spaces each level of a broken array or inline table is indented by
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the TOML version whose syntax may be used
+    ///
+    /// TOML 1.1 allows inline tables to be broken across lines.
+    pub fn with_version(mut self, version: crate::TomlVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// The column that values should not extend beyond
+    pub fn max_width(&self) -> usize {
+        self.max_width
+    }
+
+    /// The number of spaces each level of a broken array or inline table is indented by
+    pub fn indent(&self) -> usize {
+        self.indent
+    }
+
+    /// The TOML version whose syntax may be used
+    pub fn version(&self) -> crate::TomlVersion {
+        self.version
+    }
+
+    /// Whether `value` fits on one line when starting at `column`, leaving room for `trailing`
+    /// characters after it
+    pub fn fits(&self, value: &LayoutValue, column: usize, trailing: usize) -> bool {
+        value
+            .flat_width()
+            .is_some_and(|width| column + width + trailing <= self.max_width)
+    }
+
+    /// Write `value`, starting at `column` of a line that is not indented
+    pub fn write<W: TomlWrite + ?Sized>(
+        &self,
+        writer: &mut W,
+        value: &LayoutValue,
+        column: usize,
+    ) -> core::fmt::Result {
+        self.write_value(writer, value, 0, column, 0)
+    }
+
+    fn write_value<W: TomlWrite + ?Sized>(
+        &self,
+        writer: &mut W,
+        value: &LayoutValue,
+        indent: usize,
+        column: usize,
+        trailing: usize,
+    ) -> core::fmt::Result {
+        if !self.can_break(value) || self.fits(value, column, trailing) {
+            return writer.value(value);
+        }
+
+        let inner_indent = indent + self.indent;
+        match value {
+            LayoutValue::Scalar(value) => writer.write_str(value),
+            LayoutValue::Array(values) => {
+                writer.open_array()?;
+                writer.newline()?;
+                for value in values {
+                    write_indent(writer, inner_indent)?;
+                    self.write_value(writer, value, inner_indent, inner_indent, 1)?;
+                    writer.val_sep()?;
+                    writer.newline()?;
+                }
+                write_indent(writer, indent)?;
+                writer.close_array()
+            }
+            LayoutValue::InlineTable(entries) => {
+                writer.open_inline_table()?;
+                writer.newline()?;
+                for (key, value) in entries {
+                    write_indent(writer, inner_indent)?;
+                    writer.write_str(key)?;
+                    writer.space()?;
+                    writer.keyval_sep()?;
+                    writer.space()?;
+                    let column = inner_indent + key.chars().count() + 3;
+                    self.write_value(writer, value, inner_indent, column, 1)?;
+                    writer.val_sep()?;
+                    writer.newline()?;
+                }
+                write_indent(writer, indent)?;
+                writer.close_inline_table()
+            }
+        }
+    }
+}
+
+impl TomlLayout {
+    fn can_break(&self, value: &LayoutValue) -> bool {
+        match value {
+            LayoutValue::Scalar(_) => false,
+            LayoutValue::Array(values) => !values.is_empty(),
+            // Newlines within inline tables require TOML 1.1
+            LayoutValue::InlineTable(entries) => {
+                !entries.is_empty() && crate::TomlVersion::V1_1 <= self.version
+            }
+        }
+    }
+}
+
+impl Default for TomlLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_indent<W: TomlWrite + ?Sized>(writer: &mut W, indent: usize) -> core::fmt::Result {
+    write!(writer, "{:indent$}", "")
+}
+
+/// A TOML value for [`TomlLayout`] to fit within a line width
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayoutValue {
+    /// An encoded value that is never broken, like a string or an integer
+    ///
+    /// A scalar containing a newline, like a multi-line string, never fits on one line, so any
+    /// array containing it is always broken, as is any inline table when targeting TOML 1.1.
+    Scalar(String),
+    /// An array of values
+    Array(Vec<Self>),
+    /// Encoded keys, possibly dotted, and their values
+    InlineTable(Vec<(String, Self)>),
+}
+
+impl LayoutValue {
+    /// Encode a scalar value
+    pub fn scalar(value: impl WriteTomlValue) -> Self {
+        Self::Scalar(value.to_toml_value())
+    }
+
+    /// Encode the keys of an inline table
+    pub fn inline_table<K: WriteTomlKey>(entries: impl IntoIterator<Item = (K, Self)>) -> Self {
+        Self::InlineTable(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_toml_key(), value))
+                .collect(),
+        )
+    }
+
+    /// The number of characters needed to write this value on one line
+    ///
+    /// Returns `None` if it cannot be written on one line.
+    pub fn flat_width(&self) -> Option<usize> {
+        match self {
+            Self::Scalar(value) => (!value.contains('\n')).then(|| value.chars().count()),
+            Self::Array(values) => {
+                // `[` and `]`, with `, ` between values
+                let mut width = 2 + values.len().saturating_sub(1) * 2;
+                for value in values {
+                    width += value.flat_width()?;
+                }
+                Some(width)
+            }
+            Self::InlineTable(entries) if entries.is_empty() => Some(2),
+            Self::InlineTable(entries) => {
+                // `{ ` and ` }`, with `, ` between entries and ` = ` within them
+                let mut width = 4 + (entries.len() - 1) * 2;
+                for (key, value) in entries {
+                    width += key.chars().count() + 3 + value.flat_width()?;
+                }
+                Some(width)
+            }
+        }
+    }
+}
+
+/// Writes the value on one line
+impl WriteTomlValue for LayoutValue {
+    fn write_toml_value<W: TomlWrite + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
+        match self {
+            Self::Scalar(value) => writer.write_str(value),
+            Self::Array(values) => values.write_toml_value(writer),
+            Self::InlineTable(entries) => {
+                writer.open_inline_table()?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i != 0 {
+                        writer.val_sep()?;
+                    }
+                    writer.space()?;
+                    writer.write_str(key)?;
+                    writer.space()?;
+                    writer.keyval_sep()?;
+                    writer.space()?;
+                    writer.value(value)?;
+                }
+                if !entries.is_empty() {
+                    writer.space()?;
+                }
+                writer.close_inline_table()
+            }
+        }
+    }
+}
@@ -10,6 +10,9 @@
 //! - Standard tables and inline tables may need separate implementations of corner cases,
 //!   requiring verifying them both
 //!
+//! [`DocumentWriter`] can take care of the bookkeeping for tables and keys, reporting attempts
+//! to write an invalid document.
+//!
 //! When serializing Rust data structures
 //! - `Option`: Skip key-value pairs with a value of `None`, otherwise error when seeing `None`
 //!   - When skipping key-value pairs, be careful that a deeply nested `None` doesn't get skipped
@@ -59,17 +62,37 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+mod document;
+mod float;
+mod grouping;
 mod integer;
 mod key;
+#[cfg(feature = "alloc")]
+mod layout;
 mod string;
 mod value;
+mod version;
 mod write;
 
+#[cfg(feature = "alloc")]
+pub use document::DocumentError;
+#[cfg(feature = "alloc")]
+pub use document::DocumentErrorKind;
+#[cfg(feature = "alloc")]
+pub use document::DocumentWriter;
+pub use float::TomlFloat;
+pub use float::TomlFloatFormat;
 pub use integer::TomlInteger;
 pub use integer::TomlIntegerFormat;
 #[cfg(feature = "alloc")]
 pub use key::ToTomlKey;
 pub use key::WriteTomlKey;
+#[cfg(feature = "alloc")]
+pub use layout::LayoutValue;
+#[cfg(feature = "alloc")]
+pub use layout::TomlLayout;
+pub use string::TomlEscapes;
 pub use string::TomlKey;
 pub use string::TomlKeyBuilder;
 pub use string::TomlString;
@@ -77,6 +100,7 @@ pub use string::TomlStringBuilder;
 #[cfg(feature = "alloc")]
 pub use value::ToTomlValue;
 pub use value::WriteTomlValue;
+pub use version::TomlVersion;
 pub use write::TomlWrite;
 
 #[doc = include_str!("../README.md")]
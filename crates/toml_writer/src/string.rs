@@ -22,6 +22,7 @@ use core::num::Saturating;
 pub struct TomlStringBuilder<'s> {
     decoded: &'s str,
     metrics: ValueMetrics,
+    escapes: TomlEscapes,
 }
 
 impl<'s> TomlStringBuilder<'s> {
@@ -29,9 +30,20 @@ impl<'s> TomlStringBuilder<'s> {
         Self {
             decoded,
             metrics: ValueMetrics::calculate(decoded),
+            escapes: TomlEscapes::new(),
         }
     }
 
+    /// Select the escape sequences that may be used
+    pub fn with_escapes(mut self, escapes: TomlEscapes) -> Self {
+        self.escapes = escapes;
+        self
+    }
+
+    fn escape_codes(&self) -> bool {
+        self.metrics.escape_codes || (self.escapes.ascii_only && self.metrics.non_ascii)
+    }
+
     pub fn as_default(&self) -> TomlString<'s> {
         self.as_basic_pretty()
             .or_else(|| self.as_literal())
@@ -47,34 +59,35 @@ impl<'s> TomlStringBuilder<'s> {
     }
 
     pub fn as_literal(&self) -> Option<TomlString<'s>> {
-        if self.metrics.escape_codes
-            || 0 < self.metrics.max_seq_single_quotes
-            || self.metrics.newline
-        {
+        if self.escape_codes() || 0 < self.metrics.max_seq_single_quotes || self.metrics.newline {
             None
         } else {
             Some(TomlString {
                 decoded: self.decoded,
                 encoding: Encoding::LiteralString,
                 newline: self.metrics.newline,
+                fold_width: None,
+                escapes: self.escapes,
             })
         }
     }
 
     pub fn as_ml_literal(&self) -> Option<TomlString<'s>> {
-        if self.metrics.escape_codes || 2 < self.metrics.max_seq_single_quotes {
+        if self.escape_codes() || 2 < self.metrics.max_seq_single_quotes {
             None
         } else {
             Some(TomlString {
                 decoded: self.decoded,
                 encoding: Encoding::MlLiteralString,
                 newline: self.metrics.newline,
+                fold_width: None,
+                escapes: self.escapes,
             })
         }
     }
 
     pub fn as_basic_pretty(&self) -> Option<TomlString<'s>> {
-        if self.metrics.escape_codes
+        if self.escape_codes()
             || self.metrics.escape
             || 0 < self.metrics.max_seq_double_quotes
             || self.metrics.newline
@@ -86,10 +99,7 @@ impl<'s> TomlStringBuilder<'s> {
     }
 
     pub fn as_ml_basic_pretty(&self) -> Option<TomlString<'s>> {
-        if self.metrics.escape_codes
-            || self.metrics.escape
-            || 2 < self.metrics.max_seq_double_quotes
-        {
+        if self.escape_codes() || self.metrics.escape || 2 < self.metrics.max_seq_double_quotes {
             None
         } else {
             Some(self.as_ml_basic())
@@ -101,6 +111,8 @@ impl<'s> TomlStringBuilder<'s> {
             decoded: self.decoded,
             encoding: Encoding::BasicString,
             newline: self.metrics.newline,
+            fold_width: None,
+            escapes: self.escapes,
         }
     }
 
@@ -109,6 +121,44 @@ impl<'s> TomlStringBuilder<'s> {
             decoded: self.decoded,
             encoding: Encoding::MlBasicString,
             newline: self.metrics.newline,
+            fold_width: None,
+            escapes: self.escapes,
+        }
+    }
+
+    /// Multi-line basic string, folding lines longer than `width` with line-ending backslashes
+    ///
+    /// Lines are only folded between words, so a word longer than `width` is kept whole.
+    ///
+    /// Returns `None` if no line is longer than `width`.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// # use toml_writer::ToTomlValue as _;
+    /// let string = "The quick brown fox jumps over the lazy dog.";
+    /// let string = toml_writer::TomlStringBuilder::new(string).as_ml_basic_folded(20);
+    /// let string = string.unwrap().to_toml_value();
+    /// assert_eq!(string, r#""""
+    /// The quick brown \
+    /// fox jumps over the \
+    /// lazy dog.""""#);
+    /// # }
+    /// ```
+    pub fn as_ml_basic_folded(&self, width: usize) -> Option<TomlString<'s>> {
+        if self
+            .decoded
+            .split('\n')
+            .all(|line| line.chars().count() <= width)
+        {
+            None
+        } else {
+            Some(TomlString {
+                decoded: self.decoded,
+                encoding: Encoding::MlBasicString,
+                newline: true,
+                fold_width: Some(width),
+                escapes: self.escapes,
+            })
         }
     }
 }
@@ -118,11 +168,23 @@ pub struct TomlString<'s> {
     decoded: &'s str,
     encoding: Encoding,
     newline: bool,
+    fold_width: Option<usize>,
+    escapes: TomlEscapes,
 }
 
 impl crate::WriteTomlValue for TomlString<'_> {
     fn write_toml_value<W: crate::TomlWrite + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
-        write_toml_value(self.decoded, Some(self.encoding), self.newline, writer)
+        if let Some(width) = self.fold_width {
+            write_folded(self.decoded, width, self.escapes, writer)
+        } else {
+            write_toml_value(
+                self.decoded,
+                Some(self.encoding),
+                self.newline,
+                self.escapes,
+                writer,
+            )
+        }
     }
 }
 
@@ -130,6 +192,7 @@ impl crate::WriteTomlValue for TomlString<'_> {
 pub struct TomlKeyBuilder<'s> {
     decoded: &'s str,
     metrics: KeyMetrics,
+    escapes: TomlEscapes,
 }
 
 impl<'s> TomlKeyBuilder<'s> {
@@ -137,9 +200,20 @@ impl<'s> TomlKeyBuilder<'s> {
         Self {
             decoded,
             metrics: KeyMetrics::calculate(decoded),
+            escapes: TomlEscapes::new(),
         }
     }
 
+    /// Select the escape sequences that may be used
+    pub fn with_escapes(mut self, escapes: TomlEscapes) -> Self {
+        self.escapes = escapes;
+        self
+    }
+
+    fn escape_codes(&self) -> bool {
+        self.metrics.escape_codes || (self.escapes.ascii_only && self.metrics.non_ascii)
+    }
+
     pub fn as_default(&self) -> TomlKey<'s> {
         self.as_unquoted()
             .or_else(|| self.as_basic_pretty())
@@ -152,6 +226,7 @@ impl<'s> TomlKeyBuilder<'s> {
             Some(TomlKey {
                 decoded: self.decoded,
                 encoding: None,
+                escapes: self.escapes,
             })
         } else {
             None
@@ -159,18 +234,19 @@ impl<'s> TomlKeyBuilder<'s> {
     }
 
     pub fn as_literal(&self) -> Option<TomlKey<'s>> {
-        if self.metrics.escape_codes || self.metrics.single_quotes {
+        if self.escape_codes() || self.metrics.single_quotes {
             None
         } else {
             Some(TomlKey {
                 decoded: self.decoded,
                 encoding: Some(Encoding::LiteralString),
+                escapes: self.escapes,
             })
         }
     }
 
     pub fn as_basic_pretty(&self) -> Option<TomlKey<'s>> {
-        if self.metrics.escape_codes || self.metrics.escape || self.metrics.double_quotes {
+        if self.escape_codes() || self.metrics.escape || self.metrics.double_quotes {
             None
         } else {
             Some(self.as_basic())
@@ -181,6 +257,7 @@ impl<'s> TomlKeyBuilder<'s> {
         TomlKey {
             decoded: self.decoded,
             encoding: Some(Encoding::BasicString),
+            escapes: self.escapes,
         }
     }
 }
@@ -189,12 +266,75 @@ impl<'s> TomlKeyBuilder<'s> {
 pub struct TomlKey<'s> {
     decoded: &'s str,
     encoding: Option<Encoding>,
+    escapes: TomlEscapes,
 }
 
 impl crate::WriteTomlKey for TomlKey<'_> {
     fn write_toml_key<W: crate::TomlWrite + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
         let newline = false;
-        write_toml_value(self.decoded, self.encoding, newline, writer)
+        write_toml_value(self.decoded, self.encoding, newline, self.escapes, writer)
+    }
+}
+
+/// Describes which escape sequences may be used when writing strings (keys or values)
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// # use toml_writer::ToTomlValue as _;
+/// let escapes = toml_writer::TomlEscapes::new()
+///     .with_version(toml_writer::TomlVersion::V1_1)
+///     .with_ascii_only(true);
+/// let string = toml_writer::TomlStringBuilder::new("\u{1b}[1mcafé\u{1b}[0m").with_escapes(escapes);
+/// let string = string.as_default().to_toml_value();
+/// assert_eq!(string, r#""\e[1mcaf\xE9\e[0m""#);
+/// # }
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct TomlEscapes {
+    version: crate::TomlVersion,
+    ascii_only: bool,
+}
+
+impl TomlEscapes {
+    /// Escapes compatible with TOML 1.0, writing non-ASCII characters literally
+    pub fn new() -> Self {
+        Self {
+            version: crate::TomlVersion::V1_0,
+            ascii_only: false,
+        }
+    }
+
+    /// Sets the TOML version whose escape sequences may be used
+    ///
+    /// TOML 1.1 adds `\e` and `\xHH`, which are used in place of `\uHHHH` where possible.
+    pub fn with_version(mut self, version: crate::TomlVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets whether non-ASCII characters are escaped, rather than written literally
+    ///
+    /// This rules out literal strings for values containing non-ASCII characters.
+    pub fn with_ascii_only(mut self, yes: bool) -> Self {
+        self.ascii_only = yes;
+        self
+    }
+
+    fn write_escape<W: crate::TomlWrite + ?Sized>(
+        &self,
+        c: char,
+        writer: &mut W,
+    ) -> core::fmt::Result {
+        let v1_1 = crate::TomlVersion::V1_1 <= self.version;
+        let code = c as u32;
+        match c {
+            '\u{1b}' if v1_1 => write!(writer, "\\e"),
+            _ if v1_1 && code <= 0xff => write!(writer, "\\x{code:02X}"),
+            _ if code <= 0xffff => write!(writer, "\\u{code:04X}"),
+            _ => write!(writer, "\\U{code:08X}"),
+        }
     }
 }
 
@@ -214,6 +354,7 @@ fn write_toml_value<W: crate::TomlWrite + ?Sized>(
     decoded: &str,
     encoding: Option<Encoding>,
     newline: bool,
+    escapes: TomlEscapes,
     writer: &mut W,
 ) -> core::fmt::Result {
     let delimiter = match encoding {
@@ -240,83 +381,161 @@ fn write_toml_value<W: crate::TomlWrite + ?Sized>(
         writer.newline()?;
     }
     if escaped {
-        // ```bnf
-        // basic-unescaped = wschar / %x21 / %x23-5B / %x5D-7E / non-ascii
-        // wschar =  %x20  ; Space
-        // wschar =/ %x09  ; Horizontal tab
-        // escape = %x5C                   ; \
-        // ```
-        let max_seq_double_quotes = if is_ml { 2 } else { 0 };
-        let mut stream = decoded;
-        while !stream.is_empty() {
-            let mut unescaped_end = 0;
-            let mut escaped = None;
-            let mut seq_double_quotes = 0;
-            for (i, b) in stream.as_bytes().iter().enumerate() {
-                if *b == b'"' {
-                    seq_double_quotes += 1;
-                    if max_seq_double_quotes < seq_double_quotes {
-                        escaped = Some(r#"\""#);
-                        break;
-                    }
-                } else {
-                    seq_double_quotes = 0;
+        write_escaped(decoded, is_ml, escapes, writer)?;
+    } else {
+        write!(writer, "{decoded}")?;
+    }
+    write!(writer, "{delimiter}")?;
+    Ok(())
+}
+
+fn write_escaped<W: crate::TomlWrite + ?Sized>(
+    decoded: &str,
+    is_ml: bool,
+    escapes: TomlEscapes,
+    writer: &mut W,
+) -> core::fmt::Result {
+    // ```bnf
+    // basic-unescaped = wschar / %x21 / %x23-5B / %x5D-7E / non-ascii
+    // wschar =  %x20  ; Space
+    // wschar =/ %x09  ; Horizontal tab
+    // escape = %x5C                   ; \
+    // ```
+    let max_seq_double_quotes = if is_ml { 2 } else { 0 };
+    let mut stream = decoded;
+    while !stream.is_empty() {
+        let mut unescaped_end = 0;
+        let mut escaped = None;
+        let mut seq_double_quotes = 0;
+        for (i, b) in stream.as_bytes().iter().enumerate() {
+            if *b == b'"' {
+                seq_double_quotes += 1;
+                if max_seq_double_quotes < seq_double_quotes {
+                    escaped = Some(r#"\""#);
+                    break;
                 }
+            } else {
+                seq_double_quotes = 0;
+            }
 
-                match *b {
-                    0x8 => {
-                        escaped = Some(r#"\b"#);
-                        break;
-                    }
-                    0x9 => {
-                        escaped = Some(r#"\t"#);
-                        break;
-                    }
-                    0xa => {
-                        if !is_ml {
-                            escaped = Some(r#"\n"#);
-                            break;
-                        }
-                    }
-                    0xc => {
-                        escaped = Some(r#"\f"#);
-                        break;
-                    }
-                    0xd => {
-                        escaped = Some(r#"\r"#);
-                        break;
-                    }
-                    0x22 => {} // double quote handled earlier
-                    0x5c => {
-                        escaped = Some(r#"\\"#);
-                        break;
-                    }
-                    c if c <= 0x1f || c == 0x7f => {
+            match *b {
+                0x8 => {
+                    escaped = Some(r#"\b"#);
+                    break;
+                }
+                0x9 => {
+                    escaped = Some(r#"\t"#);
+                    break;
+                }
+                0xa => {
+                    if !is_ml {
+                        escaped = Some(r#"\n"#);
                         break;
                     }
-                    _ => {}
                 }
-
-                unescaped_end = i + 1;
+                0xc => {
+                    escaped = Some(r#"\f"#);
+                    break;
+                }
+                0xd => {
+                    escaped = Some(r#"\r"#);
+                    break;
+                }
+                0x22 => {} // double quote handled earlier
+                0x5c => {
+                    escaped = Some(r#"\\"#);
+                    break;
+                }
+                c if c <= 0x1f || c == 0x7f => {
+                    break;
+                }
+                c if escapes.ascii_only && 0x80 <= c => {
+                    break;
+                }
+                _ => {}
             }
-            let unescaped = &stream[0..unescaped_end];
-            let escaped_str = escaped.unwrap_or("");
-            let end = unescaped_end + if escaped.is_some() { 1 } else { 0 };
-            stream = &stream[end..];
-            write!(writer, "{unescaped}{escaped_str}")?;
-            if escaped.is_none() && !stream.is_empty() {
-                let b = stream.as_bytes().first().unwrap();
-                write!(writer, "\\u{:04X}", *b as u32)?;
-                stream = &stream[1..];
+
+            unescaped_end = i + 1;
+        }
+        let unescaped = &stream[0..unescaped_end];
+        let escaped_str = escaped.unwrap_or("");
+        let end = unescaped_end + if escaped.is_some() { 1 } else { 0 };
+        stream = &stream[end..];
+        write!(writer, "{unescaped}{escaped_str}")?;
+        if escaped.is_none() && !stream.is_empty() {
+            let c = stream.chars().next().unwrap();
+            escapes.write_escape(c, writer)?;
+            stream = &stream[c.len_utf8()..];
+        }
+    }
+    Ok(())
+}
+
+/// Write a multi-line basic string, folding lines at word boundaries with line-ending backslashes
+///
+/// A line-ending backslash trims all whitespace up to the next non-whitespace character, so
+/// lines are only folded after a run of whitespace, keeping that whitespace on the folded line.
+fn write_folded<W: crate::TomlWrite + ?Sized>(
+    decoded: &str,
+    width: usize,
+    escapes: TomlEscapes,
+    writer: &mut W,
+) -> core::fmt::Result {
+    let delimiter = "\"\"\"";
+    write!(writer, "{delimiter}")?;
+    writer.newline()?;
+    for (i, line) in decoded.split('\n').enumerate() {
+        if i != 0 {
+            writer.newline()?;
+        }
+        let mut column = 0;
+        for word in words(line) {
+            let mut word_width = Width(0);
+            write_escaped(word, true, escapes, &mut word_width)?;
+            let word_width = word_width.0;
+            // Leave room for the `\`
+            if 0 < column && width < column + word_width + 1 {
+                write!(writer, "\\")?;
+                writer.newline()?;
+                column = 0;
             }
+            write_escaped(word, true, escapes, writer)?;
+            column += word_width;
         }
-    } else {
-        write!(writer, "{decoded}")?;
     }
     write!(writer, "{delimiter}")?;
     Ok(())
 }
 
+/// Split `line` into words, each including its trailing whitespace
+fn words(line: &str) -> impl Iterator<Item = &str> {
+    let is_space = |c: char| c == ' ' || c == '\t';
+    let mut rest = line;
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let word_end = rest.find(is_space).unwrap_or(rest.len());
+        let space_end = rest[word_end..]
+            .find(|c| !is_space(c))
+            .map(|i| word_end + i)
+            .unwrap_or(rest.len());
+        let (word, tail) = rest.split_at(space_end);
+        rest = tail;
+        Some(word)
+    })
+}
+
+/// Measures the width of written text
+struct Width(usize);
+
+impl core::fmt::Write for Width {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 struct ValueMetrics {
     max_seq_single_quotes: u8,
@@ -324,6 +543,7 @@ struct ValueMetrics {
     escape_codes: bool,
     escape: bool,
     newline: bool,
+    non_ascii: bool,
 }
 
 impl ValueMetrics {
@@ -334,6 +554,7 @@ impl ValueMetrics {
             escape_codes: false,
             escape: false,
             newline: false,
+            non_ascii: false,
         }
     }
 
@@ -373,6 +594,7 @@ impl ValueMetrics {
                 b'\t' => {} // always allowed; remaining neutral on this
                 b'\n' => metrics.newline = true,
                 c if c <= 0x1f || c == 0x7f => metrics.escape_codes = true,
+                c if 0x80 <= c => metrics.non_ascii = true,
                 _ => {}
             }
         }
@@ -388,6 +610,7 @@ struct KeyMetrics {
     double_quotes: bool,
     escape_codes: bool,
     escape: bool,
+    non_ascii: bool,
 }
 
 impl KeyMetrics {
@@ -398,6 +621,7 @@ impl KeyMetrics {
             double_quotes: false,
             escape_codes: false,
             escape: false,
+            non_ascii: false,
         }
     }
 
@@ -429,6 +653,7 @@ impl KeyMetrics {
                 // characters are present, including \b \f \r.
                 b'\t' => {} // always allowed
                 c if c <= 0x1f || c == 0x7f => metrics.escape_codes = true,
+                c if 0x80 <= c => metrics.non_ascii = true,
                 _ => {}
             }
         }
@@ -97,37 +97,37 @@ impl WriteTomlValue for i128 {
 
 impl WriteTomlValue for f32 {
     fn write_toml_value<W: TomlWrite + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
-        match (self.is_sign_negative(), self.is_nan(), *self == 0.0) {
-            (true, true, _) => write!(writer, "-nan"),
-            (false, true, _) => write!(writer, "nan"),
-            (true, false, true) => write!(writer, "-0.0"),
-            (false, false, true) => write!(writer, "0.0"),
-            (_, false, false) => {
-                if self % 1.0 == 0.0 {
-                    write!(writer, "{self}.0")
-                } else {
-                    write!(writer, "{self}")
-                }
-            }
-        }
+        crate::TomlFloat::new(*self).write_toml_value(writer)
     }
 }
 
 impl WriteTomlValue for f64 {
     fn write_toml_value<W: TomlWrite + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
-        match (self.is_sign_negative(), self.is_nan(), *self == 0.0) {
-            (true, true, _) => write!(writer, "-nan"),
-            (false, true, _) => write!(writer, "nan"),
-            (true, false, true) => write!(writer, "-0.0"),
-            (false, false, true) => write!(writer, "0.0"),
-            (_, false, false) => {
-                if self % 1.0 == 0.0 {
-                    write!(writer, "{self}.0")
-                } else {
-                    write!(writer, "{self}")
-                }
-            }
-        }
+        crate::TomlFloat::new(*self).write_toml_value(writer)
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl WriteTomlValue for toml_datetime::Datetime {
+    fn write_toml_value<W: TomlWrite + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
+        write!(writer, "{self}")
+    }
+}
+
+/// Write a datetime with a [`DatetimeFormat`][toml_datetime::DatetimeFormat]
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// # use toml_writer::ToTomlValue as _;
+/// let datetime: toml_datetime::Datetime = "1979-05-27T07:32:00Z".parse().unwrap();
+/// let format = toml_datetime::DatetimeFormat::new().with_space_separator();
+/// assert_eq!(format.format(datetime).to_toml_value(), "1979-05-27 07:32:00Z");
+/// # }
+/// ```
+#[cfg(feature = "datetime")]
+impl WriteTomlValue for toml_datetime::FormattedDatetime<'_> {
+    fn write_toml_value<W: TomlWrite + ?Sized>(&self, writer: &mut W) -> core::fmt::Result {
+        write!(writer, "{self}")
     }
 }
 
@@ -0,0 +1,12 @@
+/// A version of the TOML specification to target
+///
+/// Output is compatible with parsers for the selected version and any later versions.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[non_exhaustive]
+pub enum TomlVersion {
+    /// [TOML 1.0.0](https://toml.io/en/v1.0.0)
+    #[default]
+    V1_0,
+    /// [TOML 1.1.0](https://toml.io/en/v1.1.0)
+    V1_1,
+}
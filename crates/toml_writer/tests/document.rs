@@ -0,0 +1,169 @@
+#![cfg(feature = "alloc")]
+
+use snapbox::assert_data_eq;
+use snapbox::str;
+
+use toml_writer::DocumentErrorKind;
+use toml_writer::DocumentWriter;
+
+#[test]
+fn nested_tables() {
+    let mut output = String::new();
+    let mut doc = DocumentWriter::new(&mut output);
+    doc.comment("generated").unwrap();
+    doc.keyval("name", "demo").unwrap();
+    doc.begin_table(&["a", "b"]).unwrap();
+    doc.keyval("key", 1).unwrap();
+    doc.begin_table(&["a"]).unwrap();
+    doc.keyval("other key", true).unwrap();
+    doc.begin_array_of_tables(&["a", "list"]).unwrap();
+    doc.keyval("id", 1).unwrap();
+    doc.begin_table(&["a", "list", "nested"]).unwrap();
+    doc.keyval("id", 2).unwrap();
+    doc.begin_array_of_tables(&["a", "list"]).unwrap();
+    doc.keyval("id", 3).unwrap();
+    doc.begin_table(&["a", "list", "nested"]).unwrap();
+    assert_eq!(
+        doc.current_table().collect::<Vec<_>>(),
+        ["a", "list", "nested"]
+    );
+
+    assert_data_eq!(
+        &output,
+        str![[r#"
+# generated
+name = "demo"
+
+[a.b]
+key = 1
+
+[a]
+"other key" = true
+
+[[a.list]]
+id = 1
+
+[a.list.nested]
+id = 2
+
+[[a.list]]
+id = 3
+
+[a.list.nested]
+
+"#]]
+    );
+    toml_old::from_str::<toml_old::Value>(&output).unwrap();
+}
+
+#[test]
+fn invalid() {
+    let mut output = String::new();
+    let mut doc = DocumentWriter::new(&mut output);
+    doc.keyval("value", 1).unwrap();
+    doc.begin_table(&["table"]).unwrap();
+    doc.begin_array_of_tables(&["array"]).unwrap();
+    doc.keyval("key", 1).unwrap();
+
+    let errors = [
+        doc.keyval("key", 2),
+        doc.begin_table(&[]),
+        doc.begin_table(&["value"]),
+        doc.begin_table(&["value", "child"]),
+        doc.begin_table(&["table"]),
+        doc.begin_table(&["array"]),
+        doc.begin_array_of_tables(&["table"]),
+        doc.begin_table(&["array", "key"]),
+        doc.comment("multi\nline"),
+    ]
+    .map(|result| result.unwrap_err());
+    assert_eq!(errors[4].kind(), DocumentErrorKind::DuplicateTable);
+    assert_eq!(errors[4].path(), "table");
+    assert_eq!(errors[7].kind(), DocumentErrorKind::DuplicateKey);
+    assert_eq!(errors[7].path(), "array.key");
+    let errors = errors.map(|error| error.to_string());
+    assert_data_eq!(
+        errors.join("\n"),
+        str![[r#"
+duplicate key `array.key`
+table path must not be empty
+duplicate key `value`
+duplicate key `value`
+duplicate table `table`
+`array` is already defined as an array of tables
+`table` is already defined as a table
+duplicate key `array.key`
+comment may not contain control characters other than tab
+"#]]
+    );
+
+    // Failures don't write anything or change what is defined
+    doc.keyval("other", 2).unwrap();
+    assert_data_eq!(
+        &output,
+        str![[r#"
+value = 1
+
+[table]
+
+[[array]]
+key = 1
+other = 2
+
+"#]]
+    );
+}
+
+/// Accepts writes until its budget runs out
+struct Limited<'b> {
+    output: String,
+    budget: &'b std::cell::Cell<usize>,
+}
+
+impl std::fmt::Write for Limited<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let budget = self
+            .budget
+            .get()
+            .checked_sub(s.len())
+            .ok_or(std::fmt::Error)?;
+        self.budget.set(budget);
+        self.output.push_str(s);
+        Ok(())
+    }
+}
+
+#[test]
+fn write_failure() {
+    let budget = std::cell::Cell::new(0);
+    let mut doc = DocumentWriter::new(Limited {
+        output: String::new(),
+        budget: &budget,
+    });
+
+    let error = doc.keyval("key", 1).unwrap_err();
+    assert_eq!(error.kind(), DocumentErrorKind::Fmt);
+    assert_eq!(error.path(), "");
+    let error = doc.begin_array_of_tables(&["array"]).unwrap_err();
+    assert_eq!(error.kind(), DocumentErrorKind::Fmt);
+    let error = doc.begin_table(&["table"]).unwrap_err();
+    assert_eq!(error.kind(), DocumentErrorKind::Fmt);
+    assert_eq!(doc.current_table().count(), 0);
+
+    // Failed writes don't define anything
+    budget.set(usize::MAX);
+    doc.keyval("key", 1).unwrap();
+    doc.begin_table(&["array"]).unwrap();
+    doc.begin_table(&["table"]).unwrap();
+    assert_data_eq!(
+        &doc.get_ref().output,
+        str![[r#"
+key = 1
+
+[array]
+
+[table]
+
+"#]]
+    );
+}
@@ -1,10 +1,12 @@
 #![cfg(feature = "alloc")]
 #![allow(clippy::dbg_macro)] // unsure why config isn't working
 
+use proptest::prelude::*;
 use snapbox::prelude::*;
 use snapbox::str;
 
 use toml_writer::ToTomlValue;
+use toml_writer::TomlFloatFormat;
 
 #[track_caller]
 fn t(decoded: impl ToTomlValue, expected: impl IntoData) {
@@ -12,6 +14,17 @@ fn t(decoded: impl ToTomlValue, expected: impl IntoData) {
     snapbox::assert_data_eq!(value, expected.raw());
 }
 
+#[track_caller]
+fn t_format(format: TomlFloatFormat, decoded: f64, expected: impl IntoData) {
+    let value = format.format(decoded).to_toml_value();
+    let toml = format!("value = {value}");
+    let parsed = toml
+        .parse::<toml_old::Value>()
+        .unwrap_or_else(|err| panic!("`{value}` is not a valid float: {err}"));
+    assert!(parsed["value"].is_float(), "`{value}` is not a float");
+    snapbox::assert_data_eq!(value, expected.raw());
+}
+
 #[test]
 fn zero() {
     t(0.0f64, str!["0.0"]);
@@ -41,3 +54,128 @@ fn nan() {
 fn neg_nan() {
     t(f64::NAN.copysign(-1.0), str!["-nan"]);
 }
+
+#[test]
+fn integral() {
+    t(42.0f64, str!["42.0"]);
+    t(-42.0f32, str!["-42.0"]);
+}
+
+#[test]
+fn fraction() {
+    t(0.1f64, str!["0.1"]);
+    t(0.1f32, str!["0.1"]);
+}
+
+#[test]
+fn format_shortest() {
+    let format = TomlFloatFormat::new().as_fixed(3).as_shortest();
+    t_format(format, 1.0, str!["1.0"]);
+    t_format(format, 3.25, str!["3.25"]);
+    t_format(format, 1e20, str!["100000000000000000000.0"]);
+}
+
+#[test]
+fn format_fixed() {
+    let format = TomlFloatFormat::new().as_fixed(2);
+    t_format(format, 1.0, str!["1.00"]);
+    t_format(format, 1.23456, str!["1.23"]);
+    t_format(format, -0.005, str!["-0.01"]);
+    t_format(format, -0.0, str!["-0.00"]);
+}
+
+#[test]
+fn format_fixed_zero() {
+    let format = TomlFloatFormat::new().as_fixed(0);
+    t_format(format, 2.5, str!["2.0"]);
+    t_format(format, 2.6, str!["3.0"]);
+}
+
+#[test]
+fn format_exponent_above() {
+    let format = TomlFloatFormat::new().with_exponent_above(1e6);
+    t_format(format, 999_999.0, str!["999999.0"]);
+    t_format(format, 1e6, str!["1e6"]);
+    t_format(format, -6.02214076e23, str!["-6.02214076e23"]);
+    t_format(format, 1e-10, str!["0.0000000001"]);
+}
+
+#[test]
+fn format_exponent_below() {
+    let format = TomlFloatFormat::new().with_exponent_below(1e-3);
+    t_format(format, 0.001, str!["0.001"]);
+    t_format(format, 0.000_25, str!["2.5e-4"]);
+    t_format(format, 0.0, str!["0.0"]);
+}
+
+#[test]
+fn format_exponent_fixed() {
+    let format = TomlFloatFormat::new().with_exponent_above(1e3).as_fixed(2);
+    t_format(format, 123_456.0, str!["1.23e5"]);
+    t_format(format, 1e3, str!["1.00e3"]);
+    t_format(format, 12.0, str!["12.00"]);
+}
+
+#[test]
+fn format_plus_sign() {
+    let format = TomlFloatFormat::new().with_plus_sign(true);
+    t_format(format, 1.5, str!["+1.5"]);
+    t_format(format, -1.5, str!["-1.5"]);
+    t_format(format, f64::INFINITY, str!["+inf"]);
+    t_format(format, f64::NEG_INFINITY, str!["-inf"]);
+    t_format(format, f64::NAN.copysign(1.0), str!["+nan"]);
+}
+
+#[test]
+fn format_nan_sign() {
+    let format = TomlFloatFormat::new().with_nan_sign(false);
+    t_format(format, f64::NAN.copysign(-1.0), str!["nan"]);
+    t_format(format, f64::NEG_INFINITY, str!["-inf"]);
+
+    let format = format.with_plus_sign(true);
+    t_format(format, f64::NAN.copysign(-1.0), str!["+nan"]);
+}
+
+#[test]
+fn format_f32() {
+    let format = TomlFloatFormat::new().with_exponent_above(1e6);
+    t(format.format(0.1f32), str!["0.1"]);
+    t(format.format(1.5e7f32), str!["1.5e7"]);
+}
+
+#[test]
+fn format_grouping() {
+    let format = TomlFloatFormat::new().with_grouping(true);
+    t_format(format, 999.5, str!["999.5"]);
+    t_format(format, -1_234_567.0, str!["-1_234_567.0"]);
+    t_format(format, 0.000_001, str!["0.000001"]);
+
+    let format = format.as_fixed(4);
+    t_format(format, 12_345.678_9, str!["12_345.6789"]);
+
+    let format = format.with_exponent_above(1e6);
+    t_format(format, 12_345_678.0, str!["1.2346e7"]);
+
+    let format = TomlFloatFormat::new().with_group_size(2);
+    t_format(format, 12_345.0, str!["1_23_45.0"]);
+}
+
+proptest! {
+    #[test]
+    fn shortest_parses(value in proptest::num::f64::ANY, above in 1e-3..1e20f64, below in 1e-20..1e-3f64) {
+        let format = TomlFloatFormat::new()
+            .with_grouping(true)
+            .with_exponent_above(above)
+            .with_exponent_below(below);
+        let encoded = format.format(value).to_toml_value();
+        let toml = format!("value = {encoded}");
+        let parsed = toml.parse::<toml_old::Value>();
+        let parsed = parsed.unwrap_or_else(|err| panic!("`{encoded}` is not a valid float: {err}"));
+        let parsed = parsed["value"].as_float().unwrap();
+        if value.is_nan() {
+            assert!(parsed.is_nan());
+        } else {
+            assert_eq!(parsed, value);
+        }
+    }
+}
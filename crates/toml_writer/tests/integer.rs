@@ -1,5 +1,6 @@
 #![cfg(feature = "alloc")]
 
+use proptest::prelude::*;
 use snapbox::prelude::*;
 use snapbox::str;
 
@@ -9,34 +10,44 @@ use toml_writer::TomlIntegerFormat;
 use toml_writer::WriteTomlValue;
 
 #[track_caller]
-fn t<N: Copy + core::fmt::Debug + PartialOrd<i32>>(value: N, expected: impl IntoData)
+fn t<N: Copy + core::fmt::Debug + PartialOrd + Default>(value: N, expected: impl IntoData)
 where
     TomlInteger<N>: WriteTomlValue,
 {
-    let results = IntegerResults {
+    let results = integer_results(value, TomlIntegerFormat::new());
+    snapbox::assert_data_eq!(results.to_debug(), expected.raw());
+}
+
+#[track_caller]
+fn t_grouped<N: Copy + core::fmt::Debug + PartialOrd + Default>(value: N, expected: impl IntoData)
+where
+    TomlInteger<N>: WriteTomlValue,
+{
+    let results = integer_results(value, TomlIntegerFormat::new().with_grouping(true));
+    snapbox::assert_data_eq!(results.to_debug(), expected.raw());
+}
+
+fn integer_results<N: Copy + core::fmt::Debug + PartialOrd + Default>(
+    value: N,
+    format: TomlIntegerFormat,
+) -> IntegerResults<N>
+where
+    TomlInteger<N>: WriteTomlValue,
+{
+    IntegerResults {
         value,
-        decimal: TomlIntegerFormat::new()
-            .as_decimal()
-            .format(value)
-            .map(|i| i.to_toml_value()),
-        hex_upper: TomlIntegerFormat::new()
+        decimal: format.as_decimal().format(value).map(|i| i.to_toml_value()),
+        hex_upper: format
             .as_hex_upper()
             .format(value)
             .map(|i| i.to_toml_value()),
-        hex_lower: TomlIntegerFormat::new()
+        hex_lower: format
             .as_hex_lower()
             .format(value)
             .map(|i| i.to_toml_value()),
-        octal: TomlIntegerFormat::new()
-            .as_octal()
-            .format(value)
-            .map(|i| i.to_toml_value()),
-        binary: TomlIntegerFormat::new()
-            .as_binary()
-            .format(value)
-            .map(|i| i.to_toml_value()),
-    };
-    snapbox::assert_data_eq!(results.to_debug(), expected.raw());
+        octal: format.as_octal().format(value).map(|i| i.to_toml_value()),
+        binary: format.as_binary().format(value).map(|i| i.to_toml_value()),
+    }
 }
 
 #[derive(Debug)]
@@ -97,3 +108,144 @@ IntegerResults {
 "#]],
     );
 }
+
+#[test]
+fn i64_max() {
+    t(
+        i64::MAX,
+        str![[r#"
+IntegerResults {
+    value: 9223372036854775807,
+    decimal: Some(
+        "9223372036854775807",
+    ),
+    hex_upper: Some(
+        "0x7FFFFFFFFFFFFFFF",
+    ),
+    hex_lower: Some(
+        "0x7fffffffffffffff",
+    ),
+    octal: Some(
+        "0o777777777777777777777",
+    ),
+    binary: Some(
+        "0b111111111111111111111111111111111111111111111111111111111111111",
+    ),
+}
+
+"#]],
+    );
+}
+
+#[test]
+fn grouped_short() {
+    t_grouped(
+        42,
+        str![[r#"
+IntegerResults {
+    value: 42,
+    decimal: Some(
+        "42",
+    ),
+    hex_upper: Some(
+        "0x2A",
+    ),
+    hex_lower: Some(
+        "0x2a",
+    ),
+    octal: Some(
+        "0o52",
+    ),
+    binary: Some(
+        "0b10_1010",
+    ),
+}
+
+"#]],
+    );
+}
+
+#[test]
+fn grouped_positive() {
+    t_grouped(
+        3_735_928_559_u32,
+        str![[r#"
+IntegerResults {
+    value: 3735928559,
+    decimal: Some(
+        "3_735_928_559",
+    ),
+    hex_upper: Some(
+        "0xDEAD_BEEF",
+    ),
+    hex_lower: Some(
+        "0xdead_beef",
+    ),
+    octal: Some(
+        "0o33_653_337_357",
+    ),
+    binary: Some(
+        "0b1101_1110_1010_1101_1011_1110_1110_1111",
+    ),
+}
+
+"#]],
+    );
+}
+
+#[test]
+fn grouped_negative() {
+    t_grouped(
+        -1_000_000_i64,
+        str![[r#"
+IntegerResults {
+    value: -1000000,
+    decimal: Some(
+        "-1_000_000",
+    ),
+    hex_upper: None,
+    hex_lower: None,
+    octal: None,
+    binary: None,
+}
+
+"#]],
+    );
+}
+
+#[test]
+fn group_size() {
+    let format = TomlIntegerFormat::new().with_group_size(2);
+    let value = format.as_binary().format(13_u8).unwrap().to_toml_value();
+    snapbox::assert_data_eq!(value, str!["0b11_01"]);
+
+    let format = TomlIntegerFormat::new()
+        .with_grouping(true)
+        .with_group_size(0);
+    let value = format.format(1_000_000).unwrap().to_toml_value();
+    snapbox::assert_data_eq!(value, str!["1000000"]);
+}
+
+proptest! {
+    #[test]
+    fn grouped_parses(value in proptest::num::i64::ANY, size in 0..8_usize) {
+        let format = TomlIntegerFormat::new().with_group_size(size);
+        let formats = [
+            format.as_decimal(),
+            format.as_hex_upper(),
+            format.as_hex_lower(),
+            format.as_octal(),
+            format.as_binary(),
+        ];
+        for format in formats {
+            let Some(encoded) = format.format(value) else {
+                continue;
+            };
+            let encoded = encoded.to_toml_value();
+            let toml = format!("value = {encoded}");
+            let parsed = toml.parse::<toml_old::Value>();
+            let parsed = parsed.unwrap_or_else(|err| panic!("`{encoded}` is not a valid integer: {err}"));
+            assert_eq!(parsed["value"].as_integer(), Some(value), "{encoded}");
+        }
+    }
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "alloc")]
+
+use snapbox::assert_data_eq;
+use snapbox::str;
+
+use toml_writer::LayoutValue;
+use toml_writer::TomlLayout;
+use toml_writer::TomlVersion;
+
+#[track_caller]
+fn t(layout: TomlLayout, value: &LayoutValue) -> String {
+    let mut output = String::from("key = ");
+    let column = output.len();
+    layout.write(&mut output, value, column).unwrap();
+    output.push('\n');
+    toml_old::from_str::<toml_old::Value>(&output).unwrap();
+    output
+}
+
+fn nested() -> LayoutValue {
+    LayoutValue::Array(vec![
+        LayoutValue::scalar(1),
+        LayoutValue::Array(vec![LayoutValue::scalar("short")]),
+        LayoutValue::Array(vec![
+            LayoutValue::scalar("a longer string"),
+            LayoutValue::scalar("another longer string"),
+        ]),
+        LayoutValue::Array(vec![]),
+    ])
+}
+
+#[test]
+fn fits() {
+    assert_data_eq!(
+        t(TomlLayout::new(), &nested()),
+        str![[r#"
+key = [1, ["short"], ["a longer string", "another longer string"], []]
+
+"#]]
+    );
+}
+
+#[test]
+fn break_outer() {
+    assert_data_eq!(
+        t(TomlLayout::new().with_max_width(60), &nested()),
+        str![[r#"
+key = [
+    1,
+    ["short"],
+    ["a longer string", "another longer string"],
+    [],
+]
+
+"#]]
+    );
+}
+
+#[test]
+fn break_nested() {
+    assert_data_eq!(
+        t(
+            TomlLayout::new().with_max_width(40).with_indent(2),
+            &nested()
+        ),
+        str![[r#"
+key = [
+  1,
+  ["short"],
+  [
+    "a longer string",
+    "another longer string",
+  ],
+  [],
+]
+
+"#]]
+    );
+}
+
+#[test]
+fn trailing_comma_counts() {
+    // `["short"]` is exactly 9 wide, leaving no room for the comma at width 13
+    let value = LayoutValue::Array(vec![LayoutValue::Array(vec![LayoutValue::scalar("short")])]);
+    let layout = TomlLayout::new().with_max_width(13);
+    assert_data_eq!(
+        t(layout, &value),
+        str![[r#"
+key = [
+    [
+        "short",
+    ],
+]
+
+"#]]
+    );
+}
+
+#[test]
+fn inline_table() {
+    let value = LayoutValue::inline_table([
+        ("name", LayoutValue::scalar("toml")),
+        (
+            "features",
+            LayoutValue::Array(vec![
+                LayoutValue::scalar("display"),
+                LayoutValue::scalar("parse"),
+            ]),
+        ),
+        ("empty", LayoutValue::inline_table::<&str>([])),
+    ]);
+    let flat = TomlLayout::new();
+    let narrow = TomlLayout::new().with_max_width(30);
+    let narrow_v1_1 = narrow.with_version(TomlVersion::V1_1);
+    let mut output = String::new();
+    flat.write(&mut output, &value, 0).unwrap();
+    output.push('\n');
+    narrow.write(&mut output, &value, 0).unwrap();
+    output.push('\n');
+    narrow_v1_1.write(&mut output, &value, 0).unwrap();
+    assert_data_eq!(
+        output,
+        str![[r#"
+{ name = "toml", features = ["display", "parse"], empty = {} }
+{ name = "toml", features = ["display", "parse"], empty = {} }
+{
+    name = "toml",
+    features = [
+        "display",
+        "parse",
+    ],
+    empty = {},
+}
+"#]]
+    );
+}
+
+#[test]
+fn multiline_scalar() {
+    let value = LayoutValue::Array(vec![LayoutValue::Scalar("'''\nline\n'''".to_owned())]);
+    assert_eq!(value.flat_width(), None);
+    assert_data_eq!(
+        t(TomlLayout::new(), &value),
+        str![[r#"
+key = [
+    '''
+line
+''',
+]
+
+"#]]
+    );
+}
+
+#[test]
+fn inline_table_in_array() {
+    let value = LayoutValue::Array(vec![
+        LayoutValue::inline_table([
+            ("name", LayoutValue::scalar("toml")),
+            ("version", LayoutValue::scalar("1.0")),
+        ]),
+        LayoutValue::inline_table([(
+            "lines",
+            LayoutValue::Array(vec![LayoutValue::Scalar("'''\nline\n'''".to_owned())]),
+        )]),
+    ]);
+    assert_data_eq!(
+        t(TomlLayout::new().with_max_width(20), &value),
+        str![[r#"
+key = [
+    { name = "toml", version = "1.0" },
+    { lines = ['''
+line
+'''] },
+]
+
+"#]]
+    );
+}
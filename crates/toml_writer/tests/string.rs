@@ -7,8 +7,10 @@ use snapbox::str;
 
 use toml_writer::ToTomlKey;
 use toml_writer::ToTomlValue;
+use toml_writer::TomlEscapes;
 use toml_writer::TomlKeyBuilder;
 use toml_writer::TomlStringBuilder;
+use toml_writer::TomlVersion;
 
 #[track_caller]
 fn t(decoded: &str, expected: impl IntoData) {
@@ -633,3 +635,244 @@ proptest! {
         assert_eq!(value.as_str().unwrap(), decoded);
     }
 }
+
+#[track_caller]
+fn t_folded(decoded: &str, width: usize, expected: impl IntoData) {
+    let string = TomlStringBuilder::new(decoded)
+        .as_ml_basic_folded(width)
+        .map(|s| s.to_toml_value());
+    let Some(string) = string else {
+        snapbox::assert_data_eq!("None", expected.raw());
+        return;
+    };
+    snapbox::assert_data_eq!(&string, expected.raw());
+
+    let toml = format!("value = {string}");
+    let value = toml.parse::<toml_old::Value>();
+    let value = match value {
+        Ok(value) => value,
+        Err(err) => panic!("could not parse: {err}"),
+    };
+    assert_eq!(value["value"].as_str().unwrap(), decoded);
+}
+
+#[test]
+fn folded_short() {
+    t_folded("The quick brown fox", 20, str!["None"]);
+}
+
+#[test]
+fn folded_words() {
+    t_folded(
+        "The quick brown fox jumps over the lazy dog.",
+        16,
+        str![[r#"
+"""
+The quick \
+brown fox \
+jumps over the \
+lazy dog."""
+"#]],
+    );
+}
+
+#[test]
+fn folded_long_word() {
+    t_folded(
+        "a supercalifragilisticexpialidocious word",
+        10,
+        str![[r#"
+"""
+a \
+supercalifragilisticexpialidocious \
+word"""
+"#]],
+    );
+}
+
+#[test]
+fn folded_whitespace() {
+    t_folded(
+        "  leading and  repeated   spaces stay put  ",
+        12,
+        str![[r#"
+"""
+  leading \
+and  \
+repeated   \
+spaces \
+stay put  """
+"#]],
+    );
+}
+
+#[test]
+fn folded_newlines() {
+    t_folded(
+        "SELECT name, version\nFROM packages WHERE name = \"toml\"\n\nORDER BY version",
+        20,
+        str![[r#"
+"""
+SELECT name, \
+version
+FROM packages \
+WHERE name = "toml"
+
+ORDER BY version"""
+"#]],
+    );
+}
+
+#[test]
+fn folded_escapes() {
+    t_folded(
+        "C:\\Program Files\\toml\ttab \"\"\" quotes \u{7f} trailing\\",
+        12,
+        str![[r#"
+"""
+C:\\Program \
+Files\\toml\t\
+tab ""\" \
+quotes \
+\u007F \
+trailing\\"""
+"#]],
+    );
+}
+
+proptest! {
+    #[test]
+    fn folded_parseable(decoded in "[ \t\na-z\"'\\\\]*|\\PC*", width in 0..20_usize) {
+        let Some(string) = TomlStringBuilder::new(&decoded).as_ml_basic_folded(width) else {
+            assert!(decoded.split('\n').all(|line| line.chars().count() <= width));
+            return Ok(());
+        };
+        let string = string.to_toml_value();
+        let toml = format!("value = {string}");
+        dbg!(&toml);
+        let value = toml.parse::<toml_old::Value>();
+        let value = match value {
+            Ok(value) => value,
+            Err(err) => panic!("could not parse: {err}"),
+        };
+        assert_eq!(value["value"].as_str().unwrap(), decoded);
+    }
+}
+
+#[track_caller]
+fn t_escapes(decoded: &str, expected: impl IntoData) {
+    let v1_1 = TomlEscapes::new().with_version(TomlVersion::V1_1);
+    let results = EscapeResults {
+        decoded,
+        v1_0: TomlStringBuilder::new(decoded).as_default().to_toml_value(),
+        v1_0_ascii: TomlStringBuilder::new(decoded)
+            .with_escapes(TomlEscapes::new().with_ascii_only(true))
+            .as_default()
+            .to_toml_value(),
+        v1_1: TomlStringBuilder::new(decoded)
+            .with_escapes(v1_1)
+            .as_default()
+            .to_toml_value(),
+        v1_1_ascii: TomlStringBuilder::new(decoded)
+            .with_escapes(v1_1.with_ascii_only(true))
+            .as_default()
+            .to_toml_value(),
+        key_v1_1_ascii: TomlKeyBuilder::new(decoded)
+            .with_escapes(v1_1.with_ascii_only(true))
+            .as_default()
+            .to_toml_key(),
+    };
+    snapbox::assert_data_eq!(results.to_debug(), expected.raw());
+}
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct EscapeResults<'i> {
+    decoded: &'i str,
+    v1_0: String,
+    v1_0_ascii: String,
+    v1_1: String,
+    v1_1_ascii: String,
+    key_v1_1_ascii: String,
+}
+
+#[test]
+fn escapes_control() {
+    t_escapes(
+        "\u{1b}[1mbold\u{1b}[0m\u{0}\u{7f}\t",
+        str![[r#"
+EscapeResults {
+    decoded: "\u{1b}[1mbold\u{1b}[0m\0\u{7f}\t",
+    v1_0: "\"\\u001B[1mbold\\u001B[0m\\u0000\\u007F\\t\"",
+    v1_0_ascii: "\"\\u001B[1mbold\\u001B[0m\\u0000\\u007F\\t\"",
+    v1_1: "\"\\e[1mbold\\e[0m\\x00\\x7F\\t\"",
+    v1_1_ascii: "\"\\e[1mbold\\e[0m\\x00\\x7F\\t\"",
+    key_v1_1_ascii: "\"\\e[1mbold\\e[0m\\x00\\x7F\\t\"",
+}
+
+"#]],
+    );
+}
+
+#[test]
+fn escapes_non_ascii() {
+    t_escapes(
+        "café ☕ 🦀",
+        str![[r#"
+EscapeResults {
+    decoded: "café ☕ 🦀",
+    v1_0: "\"café ☕ 🦀\"",
+    v1_0_ascii: "\"caf\\u00E9 \\u2615 \\U0001F980\"",
+    v1_1: "\"café ☕ 🦀\"",
+    v1_1_ascii: "\"caf\\xE9 \\u2615 \\U0001F980\"",
+    key_v1_1_ascii: "\"caf\\xE9 \\u2615 \\U0001F980\"",
+}
+
+"#]],
+    );
+}
+
+#[test]
+fn escapes_non_ascii_multiline() {
+    t_escapes(
+        "naïve\nrésumé's",
+        str![[r#"
+EscapeResults {
+    decoded: "naïve\nrésumé's",
+    v1_0: "\"\"\"\nnaïve\nrésumé's\"\"\"",
+    v1_0_ascii: "\"\"\"\nna\\u00EFve\nr\\u00E9sum\\u00E9's\"\"\"",
+    v1_1: "\"\"\"\nnaïve\nrésumé's\"\"\"",
+    v1_1_ascii: "\"\"\"\nna\\xEFve\nr\\xE9sum\\xE9's\"\"\"",
+    key_v1_1_ascii: "\"na\\xEFve\\nr\\xE9sum\\xE9's\"",
+}
+
+"#]],
+    );
+}
+
+proptest! {
+    /// ASCII-only output that targets TOML 1.0 stays compatible with the old TOML parser
+    #[test]
+    fn ascii_only_parseable(decoded in any::<String>()) {
+        let escapes = TomlEscapes::new().with_ascii_only(true);
+        let key = TomlKeyBuilder::new(&decoded).with_escapes(escapes).as_default().to_toml_key();
+        let string = TomlStringBuilder::new(&decoded)
+            .with_escapes(escapes)
+            .as_default()
+            .to_toml_value();
+        assert!(key.is_ascii(), "{key}");
+        assert!(string.is_ascii(), "{string}");
+
+        let toml = format!("{key} = {string}");
+        dbg!(&toml);
+        let value = toml.parse::<toml_old::Value>();
+        let value = match value {
+            Ok(value) => value,
+            Err(err) => panic!("could not parse: {err}"),
+        };
+        let table = value.as_table().unwrap();
+        let (key, value) = table.iter().next().unwrap();
+        assert_eq!(*key, decoded);
+        assert_eq!(value.as_str().unwrap(), decoded);
+    }
+}